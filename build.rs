@@ -1,4 +1,5 @@
 fn main() {
     dioxus_docs_kit_build::generate_content_map("docs/_nav.json");
     dioxus_docs_kit_build::generate_blog_content_map("blog/_blog.json");
+    dioxus_docs_kit_build::validate_openapi_examples("docs/api-reference/petstore.yaml");
 }
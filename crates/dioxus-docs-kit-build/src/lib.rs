@@ -1,3 +1,7 @@
+mod openapi;
+
+pub use openapi::{bundle_openapi, validate_openapi_examples};
+
 use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 use std::env;
@@ -7,6 +11,9 @@ use std::path::Path;
 #[derive(Deserialize)]
 struct NavConfig {
     groups: Vec<NavGroup>,
+    /// Page generated by the kit from `DocsConfig::with_glossary`.
+    #[serde(default)]
+    glossary: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -25,15 +32,19 @@ fn include_path(manifest_dir: &str, relative: &str) -> String {
 
 /// Emits a `map.insert(...)` line for `relative` if the file exists.
 ///
-/// Missing files are skipped with a warning, but still registered via
+/// Missing files are skipped with a warning (unless `generated`, i.e. the
+/// kit renders the page without a file), but still registered via
 /// `rerun-if-changed` so the build script re-runs once the file is created
 /// (cargo re-runs when a watched path does not exist).
-fn emit_entry(code: &mut String, manifest_dir: &str, key: &str, relative: &str) {
+fn emit_entry(code: &mut String, manifest_dir: &str, key: &str, relative: &str, generated: bool) {
     let full_path = include_path(manifest_dir, relative);
 
     println!("cargo:rerun-if-changed={relative}");
 
     if !Path::new(&full_path).exists() {
+        if generated {
+            return;
+        }
         println!(
             "cargo:warning=\"{key}\" is listed in the nav/manifest but {full_path} does not exist — the page will 404. Create the file or remove the entry."
         );
@@ -80,13 +91,48 @@ pub fn generate_content_map(nav_json_path: &str) {
     let mut code = String::from("// Auto-generated by dioxus-docs-kit-build — do not edit\n{\n");
     code.push_str("    let mut map = std::collections::HashMap::new();\n");
 
+    let mut data_files: Vec<(String, String)> = Vec::new();
     for group in &nav.groups {
         for page in &group.pages {
+            let generated = nav.glossary.as_ref() == Some(page);
             let mdx_path = format!("{docs_dir}/{page}.mdx");
-            emit_entry(&mut code, &manifest_dir, page, &mdx_path);
+            emit_entry(&mut code, &manifest_dir, page, &mdx_path, generated);
+
+            if let Ok(content) = fs::read_to_string(include_path(&manifest_dir, &mdx_path)) {
+                for src in data_table_sources(&content) {
+                    if !data_files.iter().any(|(s, _)| *s == src) {
+                        data_files.push((src, mdx_path.clone()));
+                    }
+                }
+            }
         }
     }
 
+    // The glossary page's own `.mdx` is optional and may be left out of
+    // the groups (the kit still serves it).
+    if let Some(page) = &nav.glossary
+        && !nav.groups.iter().any(|g| g.pages.contains(page))
+    {
+        let mdx_path = format!("{docs_dir}/{page}.mdx");
+        emit_entry(&mut code, &manifest_dir, page, &mdx_path, true);
+    }
+
+    // Data files behind `<DataTable src="...">`, keyed `__data__/<src>`.
+    for (src, page_path) in &data_files {
+        let data_path = format!("{docs_dir}/{src}");
+        println!("cargo:rerun-if-changed={data_path}");
+        let full_path = include_path(&manifest_dir, &data_path);
+        if !Path::new(&full_path).exists() {
+            println!(
+                "cargo:warning={page_path}: <DataTable src=\"{src}\"> but {full_path} does not exist"
+            );
+            continue;
+        }
+        code.push_str(&format!(
+            "    map.insert(\"__data__/{src}\", include_str!(\"{full_path}\"));\n"
+        ));
+    }
+
     code.push_str("    map\n}\n");
 
     let out_dir = env::var("OUT_DIR").unwrap();
@@ -103,6 +149,25 @@ pub fn generate_content_map(nav_json_path: &str) {
     validate_docs(&manifest_dir, docs_dir, &pages);
 }
 
+/// `src` attributes of a page's `<DataTable>` tags, relative to the docs
+/// directory. Tags inside fenced code samples are ignored.
+fn data_table_sources(content: &str) -> Vec<String> {
+    let body = strip_code_fences(content);
+    let mut sources = Vec::new();
+    let mut rest = body.as_str();
+    while let Some(start) = rest.find("<DataTable") {
+        rest = &rest[start + "<DataTable".len()..];
+        let tag = &rest[..rest.find('>').unwrap_or(rest.len())];
+        if let Some(value) = tag
+            .split_once("src=\"")
+            .and_then(|(_, v)| v.split_once('"'))
+        {
+            sources.push(value.0.trim_start_matches("./").to_string());
+        }
+    }
+    sources
+}
+
 // ============================================================================
 // Blog content map generation
 // ============================================================================
@@ -157,7 +222,7 @@ pub fn generate_blog_content_map(manifest_path: &str) {
 
     for slug in &manifest.posts {
         let mdx_path = format!("{blog_dir}/{slug}.mdx");
-        emit_entry(&mut code, &manifest_dir, slug, &mdx_path);
+        emit_entry(&mut code, &manifest_dir, slug, &mdx_path, false);
 
         // Fail the build on malformed frontmatter instead of letting the post
         // silently vanish from the site at runtime.
@@ -181,9 +246,9 @@ pub fn generate_blog_content_map(manifest_path: &str) {
 /// Convert a heading title to a URL anchor slug.
 ///
 /// Mirrors `dioxus_mdx`'s `slugify` (in `components/toc.rs`) exactly, so
-/// build-time anchor checks resolve to the same ids the renderer injects. The
-/// build crate cannot depend on `dioxus-mdx` (that would pull `dioxus` into
-/// every consumer's build-dependencies), so this small function is duplicated.
+/// build-time anchor checks resolve to the same ids the renderer injects.
+/// That function is part of dioxus-mdx's `components` feature, which this
+/// crate turns off to keep `dioxus` out of build scripts, hence the copy.
 fn slugify(text: &str) -> String {
     let text = text
         .replace("&lt;", "<")
@@ -638,9 +703,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn data_table_sources_skip_code_samples() {
+        let md = "<DataTable src=\"data/errors.csv\" />\n```mdx\n<DataTable src=\"data/example.csv\" />\n```\n<DataTable filter=\"false\" src=\"./data/regions.json\"/>";
+        assert_eq!(
+            data_table_sources(md),
+            vec![
+                "data/errors.csv".to_string(),
+                "data/regions.json".to_string()
+            ]
+        );
+    }
+
     // ---- link validation ---------------------------------------------------
 
     // Mirrors `dioxus_mdx`'s own `slugify` test cases.
+    #[test]
+    fn generated_pages_embed_their_file_only_when_it_exists() {
+        let manifest_dir = env!("CARGO_MANIFEST_DIR");
+        let mut code = String::new();
+        emit_entry(&mut code, manifest_dir, "glossary", "glossary.mdx", true);
+        assert!(code.is_empty(), "got: {code}");
+
+        emit_entry(&mut code, manifest_dir, "readme", "README.md", true);
+        assert!(
+            code.contains("map.insert(\"readme\", include_str!("),
+            "got: {code}"
+        );
+    }
+
     #[test]
     fn slugify_matches_mdx() {
         assert_eq!(slugify("Hello World"), "hello-world");
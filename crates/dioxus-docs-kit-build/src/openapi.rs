@@ -0,0 +1,129 @@
+//! Build-time helpers for OpenAPI specs.
+//!
+//! Bundling runs `dioxus_mdx`'s bundler ([`dioxus_mdx::bundle_openapi_files`])
+//! on the files on disk: relative `$ref`s are followed and their targets
+//! hoisted into the root document's `components` (`definitions`/`parameters`/
+//! `responses` for Swagger 2.0), path items and other targets are inlined, and
+//! remote `http(s)://` refs are left alone.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Bundles a multi-file OpenAPI (or Swagger 2.0) spec into a single JSON
+/// document in `OUT_DIR`.
+///
+/// Call this from your `build.rs`:
+///
+/// ```rust,ignore
+/// fn main() {
+///     dioxus_docs_kit_build::bundle_openapi("api/petstore.yaml");
+/// }
+/// ```
+///
+/// The output is written to `OUT_DIR/<stem>.bundled.json` (here
+/// `petstore.bundled.json`) and is embedded with
+/// `dioxus_docs_kit::openapi_bundle!("petstore")`. Every file the spec pulls
+/// in is registered with `rerun-if-changed`. A `$ref` to a missing file or
+/// pointer fails the build.
+pub fn bundle_openapi(spec_path: &str) {
+    let bundled = dioxus_mdx::bundle_openapi_files(spec_path, &mut |path| {
+        println!("cargo:rerun-if-changed={path}");
+        fs::read_to_string(path).map_err(|e| e.to_string())
+    })
+    .unwrap_or_else(|e| panic!("Failed to bundle {spec_path}: {e}"));
+
+    let file_name = spec_path.rsplit('/').next().unwrap_or(spec_path);
+    let stem = file_name
+        .split_once('.')
+        .map_or(file_name, |(stem, _)| stem);
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest = Path::new(&out_dir).join(format!("{stem}.bundled.json"));
+    let json = serde_json::to_string_pretty(&bundled).expect("bundled spec serializes");
+    fs::write(&dest, json).expect("Failed to write bundled spec");
+}
+
+/// Checks the examples in an OpenAPI (or Swagger 2.0) spec against their
+/// schemas and prints a `cargo:warning` per mismatch.
+///
+/// Call this from your `build.rs`:
+///
+/// ```rust,ignore
+/// fn main() {
+///     dioxus_docs_kit_build::validate_openapi_examples("docs/api-reference/petstore.yaml");
+/// }
+/// ```
+///
+/// Each warning names the spec, the operation (or component schema), the
+/// example and the JSON path of the bad value, e.g.
+/// `docs/api-reference/petstore.yaml: GET /pets: 200 response example "ok" at $[0].id: expected integer, found string`.
+/// Multi-file specs are bundled first, and every file read gets a
+/// `rerun-if-changed`. Mismatches never fail the build; a spec that doesn't
+/// parse does.
+pub fn validate_openapi_examples(spec_path: &str) {
+    for warning in example_warnings(spec_path) {
+        println!("cargo:warning={warning}");
+    }
+}
+
+/// `<spec>: <violation>` for each example in the spec that doesn't match its
+/// schema.
+fn example_warnings(spec_path: &str) -> Vec<String> {
+    let bundled = dioxus_mdx::bundle_openapi_files(spec_path, &mut |path| {
+        println!("cargo:rerun-if-changed={path}");
+        fs::read_to_string(path).map_err(|e| e.to_string())
+    })
+    .unwrap_or_else(|e| panic!("Failed to read {spec_path}: {e}"));
+    let spec = dioxus_mdx::parse_openapi(&bundled.to_string())
+        .unwrap_or_else(|e| panic!("Failed to parse {spec_path}: {e}"));
+
+    spec.validate_examples()
+        .into_iter()
+        .map(|violation| format!("{spec_path}: {violation}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_warnings_name_the_spec_operation_and_path() {
+        let dir = env::temp_dir().join(format!("dk-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let spec_path = dir.join("pets.yaml");
+        fs::write(
+            &spec_path,
+            r#"
+openapi: 3.0.3
+info: {title: Pets, version: "1"}
+paths:
+  /pets:
+    get:
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  type: object
+                  properties:
+                    id: {type: integer}
+              example: [{id: "one"}]
+"#,
+        )
+        .unwrap();
+        let spec_path = spec_path.to_str().unwrap().replace('\\', "/");
+
+        let warnings = example_warnings(&spec_path);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            warnings,
+            [format!(
+                "{spec_path}: GET /pets: 200 response example at $[0].id: expected integer, found string"
+            )]
+        );
+    }
+}
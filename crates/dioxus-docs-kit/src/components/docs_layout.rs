@@ -1,10 +1,13 @@
+use std::sync::Arc;
+
 use dioxus::prelude::*;
 #[cfg(feature = "highlight")]
 use dioxus_code::CodeTheme;
 use dioxus_free_icons::Icon;
 use dioxus_free_icons::icons::ld_icons::LdMenu;
 #[cfg(feature = "highlight")]
-use dioxus_mdx::CodeThemeOverride;
+use dioxus_mdx::{CodeThemeOverride, LanguageAliases};
+use dioxus_mdx::{GlossaryContext, SchemaLinks};
 
 use crate::DocsContext;
 #[cfg(feature = "highlight")]
@@ -181,8 +184,28 @@ pub fn DocsLayout(
             },
         });
         use_context_provider(|| CodeThemeOverride(code_theme.into()));
+        use_context_provider(|| LanguageAliases(Arc::new(registry.language_aliases.clone())));
     }
 
+    // Glossary definitions for `<Term>` tooltips in page content.
+    use_context_provider(|| GlossaryContext {
+        entries: Arc::new(registry.get_glossary().to_vec()),
+        href: registry
+            .get_glossary_path()
+            .map(|path| format!("{}/{path}", ctx.base_path)),
+    });
+
+    // Schema pages for `$ref` type links in API reference pages.
+    use_context_provider(|| SchemaLinks {
+        hrefs: Arc::new(
+            registry
+                .get_api_schema_paths()
+                .into_iter()
+                .map(|(name, path)| (name, format!("{}/{path}", ctx.base_path)))
+                .collect(),
+        ),
+    });
+
     let mut active_tab = use_signal(|| initial_tab(registry, &ctx.current_path.peek()));
     use_context_provider(|| ActiveTab(active_tab));
 
@@ -1,12 +1,15 @@
 use dioxus::prelude::*;
+use dioxus_mdx::{AccordionNode, DocNode, ParsedDoc, StepsNode, get_raw_markdown};
 
 use super::seo::{join_site_url, jsonld_to_string};
 use crate::DocsContext;
 use crate::registry::DocsRegistry;
+use crate::search::clean_markdown;
 
 /// Build a schema.org JSON-LD `@graph` for a docs page: a `TechArticle` plus an
-/// optional `BreadcrumbList`. `</` is escaped to `<\/` so the payload cannot
-/// break out of its `<script>` container.
+/// optional `BreadcrumbList`, followed by any `extra` nodes (see
+/// [`content_jsonld`]). `</` is escaped to `<\/` so the payload cannot break out
+/// of its `<script>` container.
 ///
 /// `breadcrumbs` is an ordered `(name, url)` list, root first; a `None` url
 /// emits a name-only `ListItem` (used for the current page as the last crumb).
@@ -15,6 +18,7 @@ fn build_docs_jsonld(
     description: &str,
     canonical: Option<&str>,
     breadcrumbs: &[(String, Option<String>)],
+    extra: Vec<serde_json::Value>,
 ) -> String {
     let mut tech_article = serde_json::json!({
         "@type": "TechArticle",
@@ -52,6 +56,8 @@ fn build_docs_jsonld(
         }));
     }
 
+    graph.extend(extra);
+
     let payload = serde_json::json!({
         "@context": "https://schema.org",
         "@graph": graph,
@@ -60,13 +66,109 @@ fn build_docs_jsonld(
     jsonld_to_string(&payload)
 }
 
+/// Content-derived schema.org nodes a page opted into via its
+/// `structuredData` frontmatter list:
+///
+/// - `faq` — a `FAQPage` with one `Question` per `<Accordion>` on the page.
+/// - `howto` — a `HowTo` built from the page's first `<Steps>`.
+///
+/// A requested type whose components are missing from the page is skipped.
+fn content_jsonld(doc: &ParsedDoc) -> Vec<serde_json::Value> {
+    let wants = |kind: &str| {
+        doc.frontmatter
+            .structured_data
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(kind))
+    };
+    let mut nodes = Vec::new();
+
+    if wants("faq") {
+        let mut items = Vec::new();
+        collect_accordion_items(&doc.content, &mut items);
+        let questions: Vec<serde_json::Value> = items
+            .iter()
+            .filter_map(|item| {
+                let answer = clean_markdown(&get_raw_markdown(&item.content));
+                (!item.title.is_empty() && !answer.is_empty()).then(|| {
+                    serde_json::json!({
+                        "@type": "Question",
+                        "name": item.title,
+                        "acceptedAnswer": { "@type": "Answer", "text": answer },
+                    })
+                })
+            })
+            .collect();
+        if !questions.is_empty() {
+            nodes.push(serde_json::json!({
+                "@type": "FAQPage",
+                "mainEntity": questions,
+            }));
+        }
+    }
+
+    if wants("howto")
+        && let Some(steps) = find_steps(&doc.content)
+        && !steps.steps.is_empty()
+    {
+        let steps: Vec<serde_json::Value> = steps
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| {
+                serde_json::json!({
+                    "@type": "HowToStep",
+                    "position": i + 1,
+                    "name": step.title,
+                    "text": clean_markdown(&get_raw_markdown(&step.content)),
+                })
+            })
+            .collect();
+        let mut howto = serde_json::json!({
+            "@type": "HowTo",
+            "name": doc.frontmatter.title,
+            "step": steps,
+        });
+        if let Some(desc) = &doc.frontmatter.description {
+            howto["description"] = serde_json::Value::String(desc.clone());
+        }
+        nodes.push(howto);
+    }
+
+    nodes
+}
+
+/// Every accordion item on the page, in document order (including ones nested
+/// in tabs or steps).
+fn collect_accordion_items<'a>(nodes: &'a [DocNode], out: &mut Vec<&'a AccordionNode>) {
+    for node in nodes {
+        match node {
+            DocNode::AccordionGroup(group) => out.extend(&group.items),
+            other => {
+                for children in other.children() {
+                    collect_accordion_items(children, out);
+                }
+            }
+        }
+    }
+}
+
+/// The first `<Steps>` on the page, searching nested content too.
+fn find_steps(nodes: &[DocNode]) -> Option<&StepsNode> {
+    nodes.iter().find_map(|node| match node {
+        DocNode::Steps(steps) => Some(steps),
+        other => other.children().into_iter().find_map(|c| find_steps(c)),
+    })
+}
+
 /// Injects SEO meta tags and document title for a single docs page (MDX or API endpoint).
 ///
 /// Reads `auto_meta` and `site_url` from [`DocsContext`]. When `auto_meta` is
 /// off, emits nothing. Otherwise pulls title/description from the registry —
 /// frontmatter for MDX pages, the OpenAPI operation's `summary`/`description`
-/// for API endpoint pages — and emits `<title>`, `<meta name="description">`,
-/// Open Graph, Twitter Card, and schema.org `TechArticle` JSON-LD tags.
+/// for API endpoint and webhook pages, the schema's name and description for
+/// schema pages — and emits `<title>`, `<meta name="description">`,
+/// Open Graph, Twitter Card, and schema.org `TechArticle` JSON-LD tags, plus
+/// `FAQPage`/`HowTo` nodes when the page's frontmatter opts in.
 /// Canonical, `og:url`, the JSON-LD `@id`, and a `BreadcrumbList` are only
 /// emitted when `site_url` is also set.
 #[component]
@@ -80,17 +182,44 @@ pub fn DocsPageMeta(path: String) -> Element {
 
     // `is_mdx` gates the raw-Markdown alternate link: OpenAPI endpoint pages are
     // rendered dynamically and have no `.md` source.
-    let (title, description, is_mdx) = if let Some(op) = registry.get_api_operation(&path) {
+    let (title, description, is_mdx, extra) = if let Some(op) = registry.get_api_operation(&path) {
         let title = op
             .summary
             .clone()
             .unwrap_or_else(|| op.slug().replace('-', " "));
-        (title, op.description.clone().unwrap_or_default(), false)
+        (
+            title,
+            op.description.clone().unwrap_or_default(),
+            false,
+            Vec::new(),
+        )
+    } else if let Some(webhook) = registry.get_api_webhook(&path) {
+        (
+            webhook.title(),
+            webhook.description().unwrap_or_default().to_string(),
+            false,
+            Vec::new(),
+        )
+    } else if let Some((name, schema)) = registry.get_api_schema(&path) {
+        (
+            name.to_string(),
+            schema.description.clone().unwrap_or_default(),
+            false,
+            Vec::new(),
+        )
+    } else if let Some(channel) = registry.get_asyncapi_channel(&path) {
+        (
+            channel.title(),
+            channel.summary().unwrap_or_default().to_string(),
+            false,
+            Vec::new(),
+        )
     } else if let Some(doc) = registry.get_parsed_doc(&path) {
         (
             doc.frontmatter.title.clone(),
             doc.frontmatter.description.clone().unwrap_or_default(),
             true,
+            content_jsonld(doc),
         )
     } else {
         return rsx! {};
@@ -137,7 +266,13 @@ pub fn DocsPageMeta(path: String) -> Element {
         None => Vec::new(),
     };
 
-    let json_ld = build_docs_jsonld(&title, &description, canonical.as_deref(), &breadcrumbs);
+    let json_ld = build_docs_jsonld(
+        &title,
+        &description,
+        canonical.as_deref(),
+        &breadcrumbs,
+        extra,
+    );
 
     rsx! {
         document::Title { "{title}" }
@@ -162,14 +297,16 @@ pub fn DocsPageMeta(path: String) -> Element {
         document::Meta { name: "twitter:title", content: "{title}" }
         document::Meta { name: "twitter:description", content: "{description}" }
 
-        // schema.org TechArticle (+ BreadcrumbList) JSON-LD for rich results.
+        // schema.org TechArticle (+ BreadcrumbList, FAQPage, HowTo) JSON-LD for
+        // rich results.
         document::Script { r#type: "application/ld+json", "{json_ld}" }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::build_docs_jsonld;
+    use super::{build_docs_jsonld, content_jsonld};
+    use dioxus_mdx::parse_document;
 
     #[test]
     fn jsonld_emits_techarticle_with_id_when_canonical_present() {
@@ -178,6 +315,7 @@ mod tests {
             "Get started",
             Some("https://example.com/docs/getting-started/intro"),
             &[],
+            Vec::new(),
         );
         let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
         assert_eq!(parsed["@context"], "https://schema.org");
@@ -193,7 +331,7 @@ mod tests {
 
     #[test]
     fn jsonld_omits_id_without_canonical_and_breadcrumb_when_empty() {
-        let out = build_docs_jsonld("Introduction", "Get started", None, &[]);
+        let out = build_docs_jsonld("Introduction", "Get started", None, &[], Vec::new());
         let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
         let graph = parsed["@graph"].as_array().unwrap();
         assert_eq!(graph.len(), 1);
@@ -213,7 +351,7 @@ mod tests {
             ),
             ("Introduction".to_string(), None),
         ];
-        let out = build_docs_jsonld("Introduction", "Get started", None, &crumbs);
+        let out = build_docs_jsonld("Introduction", "Get started", None, &crumbs, Vec::new());
         let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
         let graph = parsed["@graph"].as_array().unwrap();
         assert_eq!(graph.len(), 2);
@@ -235,6 +373,7 @@ mod tests {
             "",
             Some("https://example.com/"),
             &[],
+            Vec::new(),
         );
         assert!(
             !out.contains("</script"),
@@ -242,4 +381,51 @@ mod tests {
         );
         assert!(out.contains("<\\/script"));
     }
+
+    const FAQ_AND_STEPS: &str = "---\ntitle: Setup\ndescription: Set it up\nstructuredData: [faq, HowTo]\n---\n\n<Steps>\n<Step title=\"Install\">\nRun `cargo add dioxus-docs-kit`.\n</Step>\n<Step title=\"Configure\">\nEdit **_nav.json**.\n</Step>\n</Steps>\n\n<AccordionGroup>\n<Accordion title=\"Is it free?\">\nYes, MIT licensed.\n</Accordion>\n<Accordion title=\"Empty\">\n</Accordion>\n</AccordionGroup>\n";
+
+    #[test]
+    fn content_jsonld_builds_faq_and_howto_when_opted_in() {
+        let doc = parse_document(FAQ_AND_STEPS);
+        let nodes = content_jsonld(&doc);
+        assert_eq!(nodes.len(), 2);
+
+        let faq = &nodes[0];
+        assert_eq!(faq["@type"], "FAQPage");
+        let questions = faq["mainEntity"].as_array().unwrap();
+        // The accordion without an answer is skipped.
+        assert_eq!(questions.len(), 1);
+        assert_eq!(questions[0]["name"], "Is it free?");
+        assert_eq!(questions[0]["acceptedAnswer"]["text"], "Yes, MIT licensed.");
+
+        let howto = &nodes[1];
+        assert_eq!(howto["@type"], "HowTo");
+        assert_eq!(howto["name"], "Setup");
+        assert_eq!(howto["description"], "Set it up");
+        let steps = howto["step"].as_array().unwrap();
+        assert_eq!(steps[1]["position"], 2);
+        assert_eq!(steps[1]["name"], "Configure");
+        assert!(!steps[1]["text"].as_str().unwrap().contains("**"));
+    }
+
+    #[test]
+    fn content_jsonld_is_opt_in() {
+        let doc = parse_document(&FAQ_AND_STEPS.replace("structuredData: [faq, HowTo]\n", ""));
+        assert!(content_jsonld(&doc).is_empty());
+
+        let faq_only = parse_document(&FAQ_AND_STEPS.replace("[faq, HowTo]", "[faq]"));
+        let nodes = content_jsonld(&faq_only);
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0]["@type"], "FAQPage");
+    }
+
+    #[test]
+    fn jsonld_appends_extra_nodes_after_article() {
+        let extra = vec![serde_json::json!({ "@type": "FAQPage" })];
+        let out = build_docs_jsonld("Introduction", "", None, &[], extra);
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        let graph = parsed["@graph"].as_array().unwrap();
+        assert_eq!(graph[0]["@type"], "TechArticle");
+        assert_eq!(graph[1]["@type"], "FAQPage");
+    }
 }
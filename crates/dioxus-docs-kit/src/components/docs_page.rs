@@ -1,5 +1,8 @@
 use dioxus::prelude::*;
-use dioxus_mdx::{DocContent, DocTableOfContents, EndpointPage, extract_headers};
+use dioxus_mdx::{
+    ChannelPage, DocContent, DocTableOfContents, EndpointPage, SchemaPage, WebhookPage,
+    extract_headers,
+};
 
 use crate::DocsContext;
 use crate::registry::DocsRegistry;
@@ -11,7 +14,8 @@ use super::page_nav::DocsPageNav;
 
 /// Documentation page content renderer.
 ///
-/// Checks if the path is an API endpoint page or a regular MDX page and renders accordingly.
+/// Checks if the path is an API endpoint, webhook, schema or AsyncAPI channel
+/// page or a regular MDX page and renders accordingly.
 ///
 /// # Props
 ///
@@ -28,12 +32,86 @@ pub fn DocsPageContent(path: String, article_footer: Option<Element>) -> Element
     let registry = use_context::<&'static DocsRegistry>();
     let ctx = use_context::<DocsContext>();
 
+    // A previous URL of an endpoint: move on to its current page
+    if let Some(target) = registry.get_api_redirect(&path) {
+        return rsx! {
+            ApiRedirect { href: format!("{}/{target}", ctx.base_path) }
+        };
+    }
+
     // Check if this is an API endpoint page
     if let Some((operation, spec)) = registry.get_api_operation_with_spec(&path) {
         return rsx! {
             DocsPageMeta { path: path.clone() }
             div { class: "dk-endpoint flex flex-col",
-                EndpointPage { operation: operation.clone(), spec: spec.clone() }
+                EndpointPage {
+                    operation: operation.clone(),
+                    spec: spec.clone(),
+                    playground: registry.api_playground,
+                }
+                main { class: "px-8 lg:px-12 pb-12",
+                    div { class: "max-w-2xl",
+                        if let Some(ft) = article_footer {
+                            div { class: "dk-article-footer-slot mb-6", {ft} }
+                        }
+                        DocsPageNav { current_path: path.clone() }
+                    }
+                }
+            }
+        };
+    }
+
+    if let Some(webhook) = registry.get_api_webhook(&path) {
+        return rsx! {
+            DocsPageMeta { path: path.clone() }
+            div { class: "dk-endpoint dk-webhook flex flex-col",
+                WebhookPage { webhook: webhook.clone() }
+                main { class: "px-8 lg:px-12 pb-12",
+                    div { class: "max-w-2xl",
+                        if let Some(ft) = article_footer {
+                            div { class: "dk-article-footer-slot mb-6", {ft} }
+                        }
+                        DocsPageNav { current_path: path.clone() }
+                    }
+                }
+            }
+        };
+    }
+
+    if let Some((name, schema)) = registry.get_api_schema(&path) {
+        let used_by = registry
+            .get_api_schema_users(&path)
+            .into_iter()
+            .map(|(op, op_path)| (op.clone(), format!("{}/{op_path}", ctx.base_path)))
+            .collect();
+        return rsx! {
+            DocsPageMeta { path: path.clone() }
+            div { class: "dk-endpoint dk-model flex flex-col",
+                SchemaPage {
+                    name: name.to_string(),
+                    schema: schema.clone(),
+                    used_by,
+                }
+                main { class: "px-8 lg:px-12 pb-12",
+                    div { class: "max-w-2xl",
+                        if let Some(ft) = article_footer {
+                            div { class: "dk-article-footer-slot mb-6", {ft} }
+                        }
+                        DocsPageNav { current_path: path.clone() }
+                    }
+                }
+            }
+        };
+    }
+
+    if let Some((channel, spec)) = registry.get_asyncapi_channel_with_spec(&path) {
+        return rsx! {
+            DocsPageMeta { path: path.clone() }
+            div { class: "dk-endpoint dk-channel flex flex-col",
+                ChannelPage {
+                    channel: channel.clone(),
+                    servers: spec.servers.clone(),
+                }
                 main { class: "px-8 lg:px-12 pb-12",
                     div { class: "max-w-2xl",
                         if let Some(ft) = article_footer {
@@ -131,3 +209,26 @@ pub fn DocsPageContent(path: String, article_footer: Option<Element>) -> Element
         }
     }
 }
+
+/// Replaces the current history entry with `href`, the endpoint's current
+/// page. The link covers the moment before the effect runs.
+#[component]
+fn ApiRedirect(href: String) -> Element {
+    let target = href.clone();
+    use_effect(move || {
+        navigator().replace(NavigationTarget::Internal(target.clone()));
+    });
+
+    rsx! {
+        div { class: "container mx-auto px-8 py-12 max-w-4xl",
+            div { class: "text-center",
+                p { class: "text-base-content/70 mb-8", "This page has moved." }
+                Link {
+                    to: NavigationTarget::Internal(href.clone()),
+                    class: "btn btn-primary",
+                    "Go to the new page"
+                }
+            }
+        }
+    }
+}
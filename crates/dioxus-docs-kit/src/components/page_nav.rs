@@ -5,10 +5,12 @@ use crate::registry::DocsRegistry;
 
 /// Page navigation (previous/next).
 ///
-/// Page order follows `_nav.json`. API endpoint pages are included in the
-/// ordering only if the owning spec's nav group contains a page named
-/// `<prefix>/overview` — endpoints are inserted right after it. Without an
-/// overview page, endpoint pages render without prev/next links.
+/// Page order follows `_nav.json`. API endpoint, webhook and schema pages are
+/// included in the ordering only if the owning spec's nav group contains a page
+/// named `<prefix>/overview` — endpoints, webhooks, then schemas are inserted
+/// right after it. AsyncAPI channel pages follow their spec's overview the
+/// same way. Without an overview page, endpoint pages render without
+/// prev/next links.
 #[component]
 pub fn DocsPageNav(current_path: String) -> Element {
     let registry = use_context::<&'static DocsRegistry>();
@@ -39,6 +41,25 @@ pub fn DocsPageNav(current_path: String) -> Element {
                         .iter()
                         .map(|op| format!("{prefix}/{}", op.slug())),
                 );
+                all_pages.extend(
+                    spec.webhooks
+                        .iter()
+                        .map(|hook| format!("{prefix}/webhooks/{}", hook.slug())),
+                );
+                all_pages.extend(
+                    spec.schemas
+                        .keys()
+                        .map(|name| format!("{prefix}/schemas/{name}")),
+                );
+            }
+            if let Some(prefix) = page.strip_suffix("/overview")
+                && let Some(spec) = registry.get_asyncapi_spec(prefix)
+            {
+                all_pages.extend(
+                    spec.channels
+                        .iter()
+                        .map(|channel| format!("{prefix}/{}", channel.slug())),
+                );
             }
         }
     }
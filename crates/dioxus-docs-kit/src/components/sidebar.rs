@@ -1,5 +1,5 @@
 use dioxus::prelude::*;
-use dioxus_mdx::HttpMethod;
+use dioxus_mdx::{HttpMethod, OperationAction};
 
 use crate::DocsContext;
 use crate::components::docs_layout::ActiveTab;
@@ -27,53 +27,87 @@ pub fn DocsSidebar() -> Element {
     }
 }
 
-/// A single sidebar group (normal or API Reference).
+/// A single sidebar group, with API endpoints or AsyncAPI channels injected
+/// when it is the API Reference or Events group.
 #[component]
 fn SidebarGroup(group: NavGroup) -> Element {
     let registry = use_context::<&'static DocsRegistry>();
-    let api_entries = registry.get_api_sidebar_entries();
+    let api_sections = registry.get_api_sidebar_sections();
+    let model_entries = registry.get_api_model_entries();
+    let channel_groups = registry.get_asyncapi_sidebar_entries();
     let is_api_group = group.group == registry.api_group_name;
+    let is_asyncapi_group = group.group == registry.asyncapi_group_name;
 
-    if is_api_group {
-        rsx! {
-            div { class: "dk-nav-group space-y-2",
-                h3 { class: "dk-nav-group-title font-semibold text-sm text-base-content/70 uppercase tracking-wider px-3",
-                    "{group.group}"
+    rsx! {
+        div { class: "dk-nav-group space-y-2",
+            h3 { class: "dk-nav-group-title font-semibold text-sm text-base-content/70 uppercase tracking-wider px-3",
+                "{group.group}"
+            }
+            ul { class: "space-y-1",
+                for page in group.pages.iter() {
+                    SidebarLink { path: page.clone() }
                 }
-                ul { class: "space-y-1",
-                    for page in group.pages.iter() {
-                        SidebarLink { path: page.clone() }
+            }
+            if is_api_group {
+                // Dynamic API endpoints grouped by tag, under `x-tagGroups` headings
+                for section in api_sections.iter() {
+                    if let Some(heading) = &section.heading {
+                        h4 { class: "dk-nav-section-title text-xs font-semibold text-base-content/70 uppercase tracking-wider px-3 mt-5",
+                            "{heading}"
+                        }
+                    }
+                    for (tag, entries) in section.groups.iter() {
+                        div { class: "dk-nav-subgroup mt-3",
+                            h4 { class: "dk-nav-subgroup-title text-xs font-medium text-base-content/50 uppercase tracking-wider px-3 mb-1",
+                                "{tag.name}"
+                            }
+                            ul { class: "space-y-0.5",
+                                for entry in entries.iter() {
+                                    ApiSidebarLink {
+                                        prefix: entry.prefix.clone(),
+                                        slug: entry.slug.clone(),
+                                        title: entry.title.clone(),
+                                        method: entry.method,
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
-                // Dynamic API endpoints grouped by tag
-                for (tag, entries) in api_entries.iter() {
+                // Component schema pages
+                if !model_entries.is_empty() {
                     div { class: "dk-nav-subgroup mt-3",
                         h4 { class: "dk-nav-subgroup-title text-xs font-medium text-base-content/50 uppercase tracking-wider px-3 mb-1",
-                            "{tag.name}"
+                            "Models"
                         }
                         ul { class: "space-y-0.5",
-                            for entry in entries.iter() {
-                                ApiSidebarLink {
+                            for entry in model_entries.iter() {
+                                ModelSidebarLink {
                                     prefix: entry.prefix.clone(),
-                                    slug: entry.slug.clone(),
-                                    title: entry.title.clone(),
-                                    method: entry.method,
+                                    name: entry.name.clone(),
                                 }
                             }
                         }
                     }
                 }
             }
-        }
-    } else {
-        rsx! {
-            div { class: "dk-nav-group space-y-2",
-                h3 { class: "dk-nav-group-title font-semibold text-sm text-base-content/70 uppercase tracking-wider px-3",
-                    "{group.group}"
-                }
-                ul { class: "space-y-1",
-                    for page in group.pages.iter() {
-                        SidebarLink { path: page.clone() }
+            if is_asyncapi_group {
+                // AsyncAPI channels, one subgroup per spec
+                for (title, entries) in channel_groups.iter() {
+                    div { class: "dk-nav-subgroup mt-3",
+                        h4 { class: "dk-nav-subgroup-title text-xs font-medium text-base-content/50 uppercase tracking-wider px-3 mb-1",
+                            "{title}"
+                        }
+                        ul { class: "space-y-0.5",
+                            for entry in entries.iter() {
+                                ChannelSidebarLink {
+                                    prefix: entry.prefix.clone(),
+                                    slug: entry.slug.clone(),
+                                    title: entry.title.clone(),
+                                    actions: entry.actions.clone(),
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -119,6 +153,74 @@ fn ApiSidebarLink(prefix: String, slug: String, title: String, method: HttpMetho
     }
 }
 
+/// Sidebar link for an AsyncAPI channel, with a badge per action.
+#[component]
+fn ChannelSidebarLink(
+    prefix: String,
+    slug: String,
+    title: String,
+    actions: Vec<OperationAction>,
+) -> Element {
+    let ctx = use_context::<DocsContext>();
+
+    let path = format!("{prefix}/{slug}");
+
+    let is_active = (ctx.current_path)() == path;
+
+    let active_class = if is_active {
+        "dk-nav-item-active bg-primary/10 text-primary font-medium border-l-2 border-primary"
+    } else {
+        "text-base-content/70 hover:text-base-content hover:bg-base-200"
+    };
+
+    let href = format!("{}/{}", ctx.base_path, path);
+
+    rsx! {
+        li {
+            Link {
+                to: NavigationTarget::Internal(href),
+                class: "dk-nav-item dk-nav-item-channel flex items-center gap-2 px-3 py-1.5 text-sm rounded-lg transition-colors {active_class}",
+                for action in actions.iter() {
+                    span {
+                        key: "{action.as_str()}",
+                        class: "badge badge-xs font-mono font-bold {action.badge_class()} shrink-0",
+                        "{action.as_str()}"
+                    }
+                }
+                span { class: "truncate", "{title}" }
+            }
+        }
+    }
+}
+
+/// Sidebar link for a component schema page.
+#[component]
+fn ModelSidebarLink(prefix: String, name: String) -> Element {
+    let ctx = use_context::<DocsContext>();
+
+    let path = format!("{prefix}/schemas/{name}");
+
+    let is_active = (ctx.current_path)() == path;
+
+    let active_class = if is_active {
+        "dk-nav-item-active bg-primary/10 text-primary font-medium border-l-2 border-primary"
+    } else {
+        "text-base-content/70 hover:text-base-content hover:bg-base-200"
+    };
+
+    let href = format!("{}/{}", ctx.base_path, path);
+
+    rsx! {
+        li {
+            Link {
+                to: NavigationTarget::Internal(href),
+                class: "dk-nav-item dk-nav-item-model block px-3 py-1.5 text-sm font-mono rounded-lg transition-colors truncate {active_class}",
+                "{name}"
+            }
+        }
+    }
+}
+
 /// Individual sidebar link.
 #[component]
 fn SidebarLink(path: String) -> Element {
@@ -2,7 +2,7 @@
 
 use crate::registry::DocsRegistry;
 #[cfg(feature = "highlight")]
-use dioxus_code::Theme;
+use dioxus_code::{Language, Theme};
 use std::collections::HashMap;
 
 /// Theme configuration for the documentation site.
@@ -52,6 +52,17 @@ impl Default for CodeThemeConfig {
     }
 }
 
+/// Where an OpenAPI spec registered on [`DocsConfig`] comes from.
+pub(crate) enum OpenApiSource {
+    /// A single YAML/JSON document.
+    Document(String),
+    /// A multi-file spec: the entry document's path and every `(path, content)`.
+    Bundle {
+        root: String,
+        documents: Vec<(String, String)>,
+    },
+}
+
 /// Builder for constructing a [`DocsRegistry`].
 ///
 /// # Example
@@ -65,12 +76,20 @@ impl Default for CodeThemeConfig {
 pub struct DocsConfig {
     nav_json: String,
     content_map: HashMap<&'static str, &'static str>,
-    openapi_specs: Vec<(String, String)>,
+    openapi_specs: Vec<(String, OpenApiSource)>,
+    asyncapi_specs: Vec<(String, String)>,
     default_path: Option<String>,
     api_group_name: Option<String>,
+    asyncapi_group_name: Option<String>,
     theme: Option<ThemeConfig>,
+    glossary: Option<String>,
+    glossary_auto_link: bool,
+    api_playground: bool,
+    api_slug_redirects: bool,
     #[cfg(feature = "highlight")]
     code_theme: CodeThemeConfig,
+    #[cfg(feature = "highlight")]
+    language_aliases: HashMap<String, Language>,
 }
 
 impl DocsConfig {
@@ -82,29 +101,131 @@ impl DocsConfig {
             nav_json: nav_json.to_string(),
             content_map,
             openapi_specs: Vec::new(),
+            asyncapi_specs: Vec::new(),
             default_path: None,
             api_group_name: None,
+            asyncapi_group_name: None,
             theme: None,
+            glossary: None,
+            glossary_auto_link: false,
+            api_playground: false,
+            api_slug_redirects: false,
             #[cfg(feature = "highlight")]
             code_theme: CodeThemeConfig::default(),
+            #[cfg(feature = "highlight")]
+            language_aliases: HashMap::new(),
         }
     }
 
     /// Add an OpenAPI specification.
     ///
     /// - `prefix`: The URL prefix for this spec's endpoints (e.g. "api-reference").
-    /// - `yaml`: The raw YAML string of the OpenAPI spec.
+    /// - `yaml`: The raw YAML (or JSON) string of the spec. OpenAPI 3.0 and 3.1 are
+    ///   supported, and Swagger 2.0 documents are converted on the fly.
     ///
     /// The `prefix` must correspond to a nav group in `_nav.json` whose `"group"` value
     /// matches [`Self::with_api_group_name`] (defaults to `"API Reference"`). The library
     /// dynamically injects API endpoints into that group's sidebar — do **not** list
     /// individual operation paths in the `"pages"` array of `_nav.json`.
     pub fn with_openapi(mut self, prefix: &str, yaml: &str) -> Self {
-        self.openapi_specs
+        self.openapi_specs.push((
+            prefix.to_string(),
+            OpenApiSource::Document(yaml.to_string()),
+        ));
+        self
+    }
+
+    /// Add an OpenAPI specification split across several files.
+    ///
+    /// - `root`: Path of the entry document within `documents`.
+    /// - `documents`: `(path, content)` pairs for every file the spec references,
+    ///   e.g. `("schemas/pet.yaml", include_str!("../api/schemas/pet.yaml"))`.
+    ///
+    /// Relative `$ref`s between the files are resolved when the registry is built.
+    /// To bundle at compile time instead, use `dioxus_docs_kit_build::bundle_openapi`
+    /// with [`openapi_bundle!`](crate::openapi_bundle) and pass the result to
+    /// [`Self::with_openapi`]. Otherwise behaves like [`Self::with_openapi`].
+    pub fn with_openapi_bundle(
+        mut self,
+        prefix: &str,
+        root: &str,
+        documents: &[(&str, &str)],
+    ) -> Self {
+        self.openapi_specs.push((
+            prefix.to_string(),
+            OpenApiSource::Bundle {
+                root: root.to_string(),
+                documents: documents
+                    .iter()
+                    .map(|(path, content)| (path.to_string(), content.to_string()))
+                    .collect(),
+            },
+        ));
+        self
+    }
+
+    /// Add an AsyncAPI specification.
+    ///
+    /// - `prefix`: The URL prefix for this spec's channel pages (e.g. "events").
+    /// - `yaml`: The raw YAML (or JSON) string of the spec. AsyncAPI 2.x and 3.x
+    ///   are supported.
+    ///
+    /// Each channel gets a page at `<prefix>/<channel-slug>` with its operations,
+    /// message payload schemas and examples. Like [`Self::with_openapi`], the
+    /// channels are injected into the sidebar of the nav group named by
+    /// [`Self::with_asyncapi_group_name`] (defaults to `"Events"`).
+    pub fn with_asyncapi(mut self, prefix: &str, yaml: &str) -> Self {
+        self.asyncapi_specs
             .push((prefix.to_string(), yaml.to_string()));
         self
     }
 
+    /// Add a glossary.
+    ///
+    /// `json` is the raw `_glossary.json` string, an array of
+    /// `{ "id", "term", "definition", "aliases"? }` objects.
+    ///
+    /// The glossary page is generated at the doc path named by the nav's
+    /// top-level `"glossary"` key (e.g. `"glossary": "reference/glossary"`),
+    /// after that page's own content if it has an `.mdx` file. `<Term id="...">`
+    /// in any page shows the definition as a tooltip linking to that page;
+    /// unknown ids fail the build.
+    pub fn with_glossary(mut self, json: &str) -> Self {
+        self.glossary = Some(json.to_string());
+        self
+    }
+
+    /// Automatically link the first mention of each glossary term (or alias)
+    /// on every page, as if it were wrapped in `<Term>`.
+    ///
+    /// Headings, links and inline code are left alone. Defaults to `false`.
+    pub fn with_glossary_auto_link(mut self, enabled: bool) -> Self {
+        self.glossary_auto_link = enabled;
+        self
+    }
+
+    /// Show a "Try it" panel on API endpoint pages that sends real requests
+    /// from the browser and displays the response.
+    ///
+    /// A spec or a single operation can opt back out with `x-playground: false`.
+    /// The target server must allow cross-origin requests from the docs site.
+    /// Defaults to `false`.
+    pub fn with_api_playground(mut self, enabled: bool) -> Self {
+        self.api_playground = enabled;
+        self
+    }
+
+    /// Redirect the other slugs of each API endpoint to its page: the forms
+    /// derived from its operationId and from its method and path, when its
+    /// `x-slug` or operationId now gives it a different one.
+    ///
+    /// Keeps old links working after pinning URLs with `x-slug`. Slugs that
+    /// belong to another page are never redirected. Defaults to `false`.
+    pub fn with_api_slug_redirects(mut self, enabled: bool) -> Self {
+        self.api_slug_redirects = enabled;
+        self
+    }
+
     /// Set the default documentation path for redirects.
     ///
     /// Defaults to the first page in the first nav group if not set.
@@ -123,6 +244,15 @@ impl DocsConfig {
         self
     }
 
+    /// Set the display name for the AsyncAPI channels sidebar group.
+    ///
+    /// Defaults to `"Events"`. The value must match a `"group"` in `_nav.json`;
+    /// see [`Self::with_asyncapi`].
+    pub fn with_asyncapi_group_name(mut self, name: &str) -> Self {
+        self.asyncapi_group_name = Some(name.to_string());
+        self
+    }
+
     /// Set a single theme (no toggle button).
     ///
     /// The theme name must match a DaisyUI theme defined in the consumer's `tailwind.css`.
@@ -174,21 +304,36 @@ impl DocsConfig {
         self
     }
 
+    /// Highlight code fences tagged `alias` as `language`.
+    ///
+    /// Aliases are case-insensitive and take precedence over the built-in
+    /// ones, e.g. `.with_language_alias("rsx", Language::Rust)`. The grammar
+    /// itself must be compiled in: the built-in set, or a `lang-*` feature.
+    ///
+    /// Only available with the `highlight` feature (default), which pulls in `dioxus-code`.
+    #[cfg(feature = "highlight")]
+    pub fn with_language_alias(mut self, alias: &str, language: Language) -> Self {
+        self.language_aliases
+            .insert(alias.trim().to_ascii_lowercase(), language);
+        self
+    }
+
     /// Build the [`DocsRegistry`].
     ///
-    /// Parses all documents, builds the search index, and parses OpenAPI specs.
+    /// Parses all documents, builds the search index, and parses OpenAPI and
+    /// AsyncAPI specs.
     ///
     /// # Panics
     ///
-    /// Panics with a descriptive message if `_nav.json` or an OpenAPI spec fails
-    /// to parse. Use [`Self::try_build`] to handle these errors yourself.
+    /// Panics with a descriptive message if `_nav.json` or an OpenAPI or AsyncAPI
+    /// spec fails to parse. Use [`Self::try_build`] to handle these errors yourself.
     pub fn build(self) -> DocsRegistry {
         self.try_build()
             .unwrap_or_else(|e| panic!("dioxus-docs-kit: {e}"))
     }
 
     /// Build the [`DocsRegistry`], returning an error instead of panicking when
-    /// `_nav.json` or an OpenAPI spec fails to parse.
+    /// `_nav.json` or an OpenAPI or AsyncAPI spec fails to parse.
     pub fn try_build(self) -> Result<DocsRegistry, crate::error::DocsKitError> {
         DocsRegistry::try_from_config(self)
     }
@@ -202,10 +347,14 @@ impl DocsConfig {
         &self.content_map
     }
 
-    pub(crate) fn openapi_specs(&self) -> &[(String, String)] {
+    pub(crate) fn openapi_specs(&self) -> &[(String, OpenApiSource)] {
         &self.openapi_specs
     }
 
+    pub(crate) fn asyncapi_specs(&self) -> &[(String, String)] {
+        &self.asyncapi_specs
+    }
+
     pub(crate) fn default_path_value(&self) -> Option<&str> {
         self.default_path.as_deref()
     }
@@ -214,12 +363,37 @@ impl DocsConfig {
         self.api_group_name.as_deref()
     }
 
+    pub(crate) fn asyncapi_group_name_value(&self) -> Option<&str> {
+        self.asyncapi_group_name.as_deref()
+    }
+
     pub(crate) fn theme_config(&self) -> Option<&ThemeConfig> {
         self.theme.as_ref()
     }
 
+    pub(crate) fn glossary(&self) -> Option<&str> {
+        self.glossary.as_deref()
+    }
+
+    pub(crate) fn glossary_auto_link(&self) -> bool {
+        self.glossary_auto_link
+    }
+
+    pub(crate) fn api_playground(&self) -> bool {
+        self.api_playground
+    }
+
+    pub(crate) fn api_slug_redirects(&self) -> bool {
+        self.api_slug_redirects
+    }
+
     #[cfg(feature = "highlight")]
     pub(crate) fn code_theme_value(&self) -> CodeThemeConfig {
         self.code_theme
     }
+
+    #[cfg(feature = "highlight")]
+    pub(crate) fn language_aliases(&self) -> &HashMap<String, Language> {
+        &self.language_aliases
+    }
 }
@@ -0,0 +1,172 @@
+//! `<DataTable>` loading and row-level search entries.
+//!
+//! `dioxus-docs-kit-build` embeds every file a page's `<DataTable src="...">`
+//! names into the content map under `"__data__/<src>"`, next to the pages.
+
+use std::collections::HashMap;
+
+use dioxus_mdx::{DataTableNode, DocNode, ParsedDoc, get_raw_markdown};
+
+use crate::error::DocsKitError;
+
+/// Content map key prefix of embedded data files.
+pub(crate) const DATA_PREFIX: &str = "__data__/";
+
+/// Load every page's data tables from the embedded files, refreshing the raw
+/// markdown of pages that have any.
+pub(crate) fn resolve_data_tables(
+    parsed_docs: &mut HashMap<&'static str, ParsedDoc>,
+    content_map: &HashMap<&'static str, &'static str>,
+) -> Result<(), DocsKitError> {
+    // Sorted so the reported error doesn't depend on HashMap order.
+    let mut paths: Vec<&'static str> = parsed_docs.keys().copied().collect();
+    paths.sort_unstable();
+
+    for path in paths {
+        let Some(doc) = parsed_docs.get_mut(path) else {
+            continue;
+        };
+        if load_nodes(&mut doc.content, content_map, path)? {
+            doc.raw_markdown = get_raw_markdown(&doc.content);
+        }
+    }
+    Ok(())
+}
+
+/// Load tables in `nodes` and their nested content. Returns whether any
+/// table was found.
+fn load_nodes(
+    nodes: &mut [DocNode],
+    content_map: &HashMap<&'static str, &'static str>,
+    page: &str,
+) -> Result<bool, DocsKitError> {
+    let mut found = false;
+
+    for node in nodes {
+        match node {
+            DocNode::DataTable(table) => {
+                let key = format!("{DATA_PREFIX}{}", table.src.trim_start_matches("./"));
+                let text =
+                    content_map
+                        .get(key.as_str())
+                        .ok_or_else(|| DocsKitError::UnknownDataFile {
+                            page: page.to_string(),
+                            src: table.src.clone(),
+                        })?;
+                table.load(text).map_err(|error| DocsKitError::DataTable {
+                    page: page.to_string(),
+                    src: table.src.clone(),
+                    error,
+                })?;
+                found = true;
+            }
+            other => {
+                for children in other.children_mut() {
+                    found |= load_nodes(children, content_map, page)?;
+                }
+            }
+        }
+    }
+
+    Ok(found)
+}
+
+/// A loaded table row, as indexed for search.
+pub(crate) struct TableRow {
+    /// Row anchor id ([`DataTableNode::row_anchors`]).
+    pub anchor: String,
+    /// First cell, e.g. the error code.
+    pub key: String,
+    /// Remaining cells as `Column: value` pairs.
+    pub body: String,
+}
+
+/// Every row of every loaded table on a page, in document order.
+pub(crate) fn table_rows(nodes: &[DocNode]) -> Vec<TableRow> {
+    let mut rows = Vec::new();
+    collect_rows(nodes, &mut rows);
+    rows
+}
+
+fn collect_rows(nodes: &[DocNode], rows: &mut Vec<TableRow>) {
+    for node in nodes {
+        match node {
+            DocNode::DataTable(table) => rows.extend(rows_of(table)),
+            other => {
+                for children in other.children() {
+                    collect_rows(children, rows);
+                }
+            }
+        }
+    }
+}
+
+fn rows_of(table: &DataTableNode) -> Vec<TableRow> {
+    let Some(data) = &table.data else {
+        return Vec::new();
+    };
+    data.rows
+        .iter()
+        .zip(table.row_anchors())
+        .map(|(row, anchor)| TableRow {
+            anchor,
+            key: row.first().cloned().unwrap_or_default(),
+            body: data
+                .columns
+                .iter()
+                .zip(row)
+                .skip(1)
+                .filter(|(_, cell)| !cell.is_empty())
+                .map(|(column, cell)| format!("{}: {cell}", column.name))
+                .collect::<Vec<_>>()
+                .join(" · "),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_mdx::parse_document;
+
+    const ERRORS_CSV: &str = "code,status,message\nE1042,429,Rate limit exceeded\nE2001,500,\n";
+
+    fn docs(content: &str) -> HashMap<&'static str, ParsedDoc> {
+        HashMap::from([("reference/errors", parse_document(content))])
+    }
+
+    #[test]
+    fn tables_load_from_the_content_map_and_yield_rows() {
+        let content_map = HashMap::from([("__data__/data/errors.csv", ERRORS_CSV)]);
+        let mut parsed = docs(
+            "<Tabs>\n<Tab title=\"All\">\n<DataTable src=\"data/errors.csv\" />\n</Tab>\n</Tabs>",
+        );
+        resolve_data_tables(&mut parsed, &content_map).unwrap();
+
+        let doc = &parsed["reference/errors"];
+        assert!(
+            doc.raw_markdown
+                .contains("| E1042 | 429 | Rate limit exceeded |")
+        );
+        let rows = table_rows(&doc.content);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].anchor, "errors-e1042");
+        assert_eq!(rows[0].key, "E1042");
+        assert_eq!(rows[0].body, "status: 429 · message: Rate limit exceeded");
+        assert_eq!(rows[1].body, "status: 500");
+    }
+
+    #[test]
+    fn missing_and_malformed_files_fail_the_build() {
+        let mut parsed = docs("<DataTable src=\"data/nope.csv\" />");
+        let err = resolve_data_tables(&mut parsed, &HashMap::new()).unwrap_err();
+        assert!(
+            matches!(&err, DocsKitError::UnknownDataFile { page, src } if page == "reference/errors" && src == "data/nope.csv")
+        );
+
+        let content_map = HashMap::from([("__data__/data/bad.csv", "a,b\n1,2,3\n")]);
+        let mut parsed = docs("<DataTable src=\"data/bad.csv\" />");
+        let err = resolve_data_tables(&mut parsed, &content_map).unwrap_err();
+        assert!(matches!(err, DocsKitError::DataTable { .. }), "{err}");
+    }
+}
@@ -1,6 +1,6 @@
 //! Error types for registry construction.
 
-use dioxus_mdx::OpenApiError;
+use dioxus_mdx::{AsyncApiError, DataTableError, OpenApiError};
 
 /// Errors produced when building a [`DocsRegistry`](crate::DocsRegistry) or
 /// [`BlogRegistry`](crate::blog::BlogRegistry) from configuration.
@@ -10,6 +10,8 @@ pub enum DocsKitError {
     NavParse(serde_json::Error),
     /// `_blog.json` failed to parse.
     BlogManifestParse(serde_json::Error),
+    /// `_glossary.json` failed to parse.
+    GlossaryParse(serde_json::Error),
     /// An OpenAPI spec failed to parse.
     OpenApi {
         /// URL prefix the spec was registered under.
@@ -17,6 +19,57 @@ pub enum DocsKitError {
         /// The underlying parse error.
         error: OpenApiError,
     },
+    /// An AsyncAPI spec failed to parse.
+    AsyncApi {
+        /// URL prefix the spec was registered under.
+        prefix: String,
+        /// The underlying parse error.
+        error: AsyncApiError,
+    },
+    /// An API page (operation, webhook, schema or AsyncAPI channel) resolves
+    /// to the same docs path as another API page or an MDX page, e.g.
+    /// because of a duplicated `x-slug`.
+    DuplicateApiSlug {
+        /// The docs path both pages claim (`prefix/slug`).
+        path: String,
+        /// The page registered first, e.g. `GET /pets` or
+        /// `page "api-reference/overview"` for an MDX page.
+        first: String,
+        /// The page that collides with it.
+        second: String,
+    },
+    /// A page's `<ApiEndpoint>` or `<Schema>` names something no registered
+    /// OpenAPI spec defines.
+    UnknownApiReference {
+        /// Doc path of the page containing the reference.
+        page: String,
+        /// What was referenced, e.g. `operationId "listPets"`.
+        reference: String,
+    },
+    /// A page's `<Term id="...">` names an id the glossary doesn't define.
+    UnknownGlossaryTerm {
+        /// Doc path of the page containing the term.
+        page: String,
+        /// The unknown term id.
+        id: String,
+    },
+    /// A page's `<DataTable src="...">` names a file the content map doesn't
+    /// embed.
+    UnknownDataFile {
+        /// Doc path of the page containing the table.
+        page: String,
+        /// The `src` attribute.
+        src: String,
+    },
+    /// A `<DataTable>` source file failed to load.
+    DataTable {
+        /// Doc path of the page containing the table.
+        page: String,
+        /// The `src` attribute.
+        src: String,
+        /// The underlying load error.
+        error: DataTableError,
+    },
 }
 
 impl std::fmt::Display for DocsKitError {
@@ -24,10 +77,42 @@ impl std::fmt::Display for DocsKitError {
         match self {
             Self::NavParse(e) => write!(f, "failed to parse _nav.json: {e}"),
             Self::BlogManifestParse(e) => write!(f, "failed to parse _blog.json: {e}"),
+            Self::GlossaryParse(e) => write!(f, "failed to parse _glossary.json: {e}"),
             Self::OpenApi { prefix, error } => write!(
                 f,
                 "failed to parse OpenAPI spec for prefix \"{prefix}\": {error}"
             ),
+            Self::AsyncApi { prefix, error } => write!(
+                f,
+                "failed to parse AsyncAPI spec for prefix \"{prefix}\": {error}"
+            ),
+            Self::DuplicateApiSlug {
+                path,
+                first,
+                second,
+            } => write!(
+                f,
+                "{first} and {second} both resolve to \"{path}\"; \
+                 set a distinct x-slug on the API page"
+            ),
+            Self::UnknownApiReference { page, reference } => {
+                write!(f, "page \"{page}\" references unknown {reference}")
+            }
+            Self::UnknownGlossaryTerm { page, id } => {
+                write!(
+                    f,
+                    "page \"{page}\" references unknown glossary term \"{id}\""
+                )
+            }
+            Self::UnknownDataFile { page, src } => {
+                write!(f, "page \"{page}\" references missing data file \"{src}\"")
+            }
+            Self::DataTable { page, src, error } => {
+                write!(
+                    f,
+                    "failed to load data file \"{src}\" on page \"{page}\": {error}"
+                )
+            }
         }
     }
 }
@@ -35,8 +120,14 @@ impl std::fmt::Display for DocsKitError {
 impl std::error::Error for DocsKitError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::NavParse(e) | Self::BlogManifestParse(e) => Some(e),
+            Self::NavParse(e) | Self::BlogManifestParse(e) | Self::GlossaryParse(e) => Some(e),
             Self::OpenApi { error, .. } => Some(error),
+            Self::AsyncApi { error, .. } => Some(error),
+            Self::DataTable { error, .. } => Some(error),
+            Self::DuplicateApiSlug { .. }
+            | Self::UnknownApiReference { .. }
+            | Self::UnknownGlossaryTerm { .. }
+            | Self::UnknownDataFile { .. } => None,
         }
     }
 }
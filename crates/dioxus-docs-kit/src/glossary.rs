@@ -0,0 +1,282 @@
+//! Glossary loading, the generated glossary page, and `<Term>` resolution.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use dioxus_mdx::{
+    DocNode, GlossaryEntry, ParsedDoc, code_spans, find_terms, get_raw_markdown, parse_document,
+};
+use regex::Regex;
+
+use crate::error::DocsKitError;
+
+/// Markdown links and images, raw HTML tags, bare URLs and heading lines:
+/// places an automatic term link must not go.
+static PROTECTED_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)!?\[[^\]]*\]\([^)]*\)|<[^>]+>|https?://\S+|^#{1,6}\s.*$").unwrap()
+});
+
+/// Parse `_glossary.json` (an array of entries), sorted by term.
+pub(crate) fn parse_glossary(json: &str) -> Result<Vec<GlossaryEntry>, DocsKitError> {
+    let mut entries: Vec<GlossaryEntry> =
+        serde_json::from_str(json).map_err(DocsKitError::GlossaryParse)?;
+    entries.sort_by_key(|entry| entry.term.to_lowercase());
+    Ok(entries)
+}
+
+/// Build the glossary page: the page's own MDX (if any) followed by one
+/// section per term. Pages without their own source get a default title.
+pub(crate) fn glossary_page(source: Option<&str>, entries: &[GlossaryEntry]) -> ParsedDoc {
+    let mut page = match source {
+        Some(source) => format!("{}\n\n", source.trim_end()),
+        None => "---\ntitle: Glossary\n---\n\n".to_string(),
+    };
+
+    for entry in entries {
+        page.push_str(&format!(
+            "## {}\n\n{}\n\n",
+            entry.term,
+            entry.definition.trim()
+        ));
+        if !entry.aliases.is_empty() {
+            page.push_str(&format!("_Also: {}_\n\n", entry.aliases.join(", ")));
+        }
+    }
+
+    parse_document(&page)
+}
+
+/// Validate every page's `<Term>` ids, give self-closing terms their display
+/// name and, with `auto_link`, link the first occurrence of each term per page.
+pub(crate) fn resolve_terms(
+    parsed_docs: &mut HashMap<&'static str, ParsedDoc>,
+    entries: &[GlossaryEntry],
+    glossary_path: Option<&str>,
+    auto_link: bool,
+) -> Result<(), DocsKitError> {
+    let matchers = if auto_link {
+        term_matchers(entries)
+    } else {
+        Vec::new()
+    };
+
+    // Sorted so the reported error doesn't depend on HashMap order.
+    let mut paths: Vec<&'static str> = parsed_docs.keys().copied().collect();
+    paths.sort_unstable();
+
+    for path in paths {
+        let Some(doc) = parsed_docs.get_mut(path) else {
+            continue;
+        };
+        let matchers = if Some(path) == glossary_path {
+            &[][..]
+        } else {
+            &matchers[..]
+        };
+        let mut linked = HashSet::new();
+        if resolve_nodes(&mut doc.content, entries, matchers, &mut linked, path)? {
+            doc.raw_markdown = get_raw_markdown(&doc.content);
+        }
+    }
+    Ok(())
+}
+
+/// Case-insensitive whole-word matchers for every term and alias, longest
+/// name first so "API key" wins over "API".
+fn term_matchers(entries: &[GlossaryEntry]) -> Vec<(&str, Regex)> {
+    let mut names: Vec<(&str, &str)> = entries
+        .iter()
+        .flat_map(|entry| {
+            std::iter::once(entry.term.as_str())
+                .chain(entry.aliases.iter().map(String::as_str))
+                .map(move |name| (entry.id.as_str(), name))
+        })
+        .collect();
+    names.sort_by_key(|(_, name)| std::cmp::Reverse(name.len()));
+
+    names
+        .into_iter()
+        .filter_map(|(id, name)| {
+            Regex::new(&format!(r"(?i)\b{}\b", regex::escape(name)))
+                .ok()
+                .map(|re| (id, re))
+        })
+        .collect()
+}
+
+/// Resolve terms in `nodes` and their nested content. Returns whether any
+/// markdown changed.
+fn resolve_nodes<'a>(
+    nodes: &mut [DocNode],
+    entries: &[GlossaryEntry],
+    matchers: &[(&'a str, Regex)],
+    linked: &mut HashSet<&'a str>,
+    page: &str,
+) -> Result<bool, DocsKitError> {
+    let mut changed = false;
+
+    for node in nodes {
+        match node {
+            DocNode::Markdown(md) => {
+                if let Some(resolved) = resolve_markdown(md, entries, matchers, linked, page)? {
+                    *md = resolved;
+                    changed = true;
+                }
+            }
+            other => {
+                for children in other.children_mut() {
+                    changed |= resolve_nodes(children, entries, matchers, linked, page)?;
+                }
+            }
+        }
+    }
+
+    Ok(changed)
+}
+
+fn resolve_markdown<'a>(
+    md: &str,
+    entries: &[GlossaryEntry],
+    matchers: &[(&'a str, Regex)],
+    linked: &mut HashSet<&'a str>,
+    page: &str,
+) -> Result<Option<String>, DocsKitError> {
+    let terms = find_terms(md);
+    // (start, end, replacement)
+    let mut edits: Vec<(usize, usize, String)> = Vec::new();
+
+    for term in &terms {
+        let entry = entries
+            .iter()
+            .find(|entry| entry.id == term.id)
+            .ok_or_else(|| DocsKitError::UnknownGlossaryTerm {
+                page: page.to_string(),
+                id: term.id.clone(),
+            })?;
+        if term.label.is_none() {
+            edits.push((
+                term.start,
+                term.end,
+                format!("<Term id=\"{}\">{}</Term>", term.id, entry.term),
+            ));
+        }
+    }
+
+    if !matchers.is_empty() {
+        // Terms written by hand count as the page's first mention.
+        for term in &terms {
+            if let Some((id, _)) = matchers.iter().find(|(id, _)| *id == term.id) {
+                linked.insert(id);
+            }
+        }
+
+        let mut protected: Vec<(usize, usize)> = code_spans(md);
+        protected.extend(PROTECTED_RE.find_iter(md).map(|m| (m.start(), m.end())));
+        protected.extend(terms.iter().map(|t| (t.start, t.end)));
+
+        for (id, re) in matchers {
+            if linked.contains(id) {
+                continue;
+            }
+            let Some(found) = re
+                .find_iter(md)
+                .find(|m| !protected.iter().any(|&(s, e)| m.start() < e && s < m.end()))
+            else {
+                continue;
+            };
+            linked.insert(id);
+            protected.push((found.start(), found.end()));
+            edits.push((
+                found.start(),
+                found.end(),
+                format!("<Term id=\"{id}\">{}</Term>", found.as_str()),
+            ));
+        }
+    }
+
+    if edits.is_empty() {
+        return Ok(None);
+    }
+
+    edits.sort_by_key(|(start, _, _)| *start);
+    let mut out = String::with_capacity(md.len());
+    let mut last = 0;
+    for (start, end, replacement) in edits {
+        out.push_str(&md[last..start]);
+        out.push_str(&replacement);
+        last = end;
+    }
+    out.push_str(&md[last..]);
+    Ok(Some(out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLOSSARY: &str = r#"[
+        {"id": "idempotency", "term": "Idempotency", "definition": "Same effect when repeated.", "aliases": ["idempotent"]},
+        {"id": "api-key", "term": "API key", "definition": "A secret token."}
+    ]"#;
+
+    fn docs(pages: &[(&'static str, &str)]) -> HashMap<&'static str, ParsedDoc> {
+        pages
+            .iter()
+            .map(|&(path, content)| (path, parse_document(content)))
+            .collect()
+    }
+
+    #[test]
+    fn glossary_page_lists_terms_alphabetically() {
+        let entries = parse_glossary(GLOSSARY).unwrap();
+        let page = glossary_page(None, &entries);
+        assert_eq!(page.frontmatter.title, "Glossary");
+        let api = page.raw_markdown.find("## API key").unwrap();
+        let idem = page.raw_markdown.find("## Idempotency").unwrap();
+        assert!(api < idem, "got: {}", page.raw_markdown);
+        assert!(page.raw_markdown.contains("_Also: idempotent_"));
+    }
+
+    #[test]
+    fn self_closing_terms_get_names_and_unknown_ids_fail() {
+        let entries = parse_glossary(GLOSSARY).unwrap();
+        let mut parsed = docs(&[("a", "Send an <Term id=\"api-key\" /> header.")]);
+        resolve_terms(&mut parsed, &entries, None, false).unwrap();
+        assert_eq!(parsed["a"].raw_markdown, "Send an API key header.\n\n");
+
+        let mut parsed = docs(&[("b", "A <Term id=\"nope\">thing</Term>.")]);
+        let err = resolve_terms(&mut parsed, &entries, None, false).unwrap_err();
+        assert!(
+            matches!(&err, DocsKitError::UnknownGlossaryTerm { page, id } if page == "b" && id == "nope")
+        );
+    }
+
+    #[test]
+    fn auto_link_wraps_first_occurrence_outside_code_links_and_headings() {
+        let entries = parse_glossary(GLOSSARY).unwrap();
+        let mut parsed = docs(&[
+            (
+                "guide",
+                "## API key setup\n\nSee [API key docs](/x) and `API key`. Your api key is secret. Another API key.\n\nRetries are idempotent.",
+            ),
+            ("glossary", "The API key glossary."),
+        ]);
+        resolve_terms(&mut parsed, &entries, Some("glossary"), true).unwrap();
+
+        let DocNode::Markdown(md) = &parsed["guide"].content[0] else {
+            panic!("expected markdown");
+        };
+        assert!(md.contains("## API key setup"), "heading untouched: {md}");
+        assert!(md.contains("[API key docs](/x)"), "link untouched: {md}");
+        assert!(md.contains("`API key`"), "code untouched: {md}");
+        assert!(
+            md.contains("Your <Term id=\"api-key\">api key</Term> is secret. Another API key."),
+            "only the first plain mention is linked: {md}"
+        );
+        assert!(
+            md.contains("<Term id=\"idempotency\">idempotent</Term>"),
+            "aliases match: {md}"
+        );
+        assert_eq!(parsed["glossary"].raw_markdown, "The API key glossary.\n\n");
+    }
+}
@@ -3,7 +3,8 @@
 //! Reusable documentation site shell and blog engine for Dioxus applications.
 //!
 //! Provides a complete docs layout with sidebar navigation, search modal,
-//! page navigation, OpenAPI API reference pages, and mobile drawer.
+//! page navigation, OpenAPI API reference pages, AsyncAPI channel pages, and
+//! mobile drawer.
 //! Also includes a full blog engine with post listing, tag filtering,
 //! search, reading time, and MDX rendering.
 //!
@@ -68,7 +69,9 @@ mod wasm_sysroot_stderr {
 pub mod blog;
 pub mod components;
 pub mod config;
+pub(crate) mod data_table;
 pub mod error;
+pub(crate) mod glossary;
 pub mod hooks;
 pub mod registry;
 pub(crate) mod search;
@@ -264,7 +267,10 @@ pub use config::CodeThemeConfig;
 pub use config::{DocsConfig, ThemeConfig};
 pub use error::DocsKitError;
 pub use registry::DocsRegistry;
-pub use registry::{ApiEndpointEntry, NavConfig, NavGroup, SearchEntry};
+pub use registry::{
+    ApiEndpointEntry, ApiModelEntry, ApiSidebarSection, AsyncApiChannelEntry, NavConfig, NavGroup,
+    SearchEntry,
+};
 
 pub use components::{
     ActiveTab, CopyPageButton, CurrentTheme, DocsLayout, DocsPageContent, DocsPageMeta,
@@ -275,12 +281,13 @@ pub use components::{
 pub use hooks::{DocsProviders, use_docs_context, use_docs_providers};
 
 pub use dioxus_mdx::{
-    ApiOperation, ApiTag, DocContent, DocTableOfContents, EndpointPage, HttpMethod, OpenApiSpec,
-    ParsedDoc, extract_headers,
+    ApiOperation, ApiTag, ApiWebhook, AsyncApiChannel, AsyncApiSpec, ChannelPage, DocContent,
+    DocTableOfContents, EndpointPage, GlossaryEntry, HttpMethod, OpenApiSpec, ParsedDoc,
+    SchemaDefinition, SchemaLinks, SchemaPage, WebhookPage, extract_headers,
 };
 
 #[cfg(feature = "highlight")]
-pub use dioxus_mdx::CodeThemeOverride;
+pub use dioxus_mdx::{CodeThemeOverride, LanguageAliases};
 
 #[cfg(feature = "highlight")]
 pub use dioxus_code::{Code, CodeTheme, Language, SourceCode, Theme};
@@ -345,3 +352,18 @@ macro_rules! blog_content_map {
         }
     };
 }
+
+/// Expands to the `&'static str` spec written by
+/// `dioxus_docs_kit_build::bundle_openapi`, named after the entry file's stem.
+///
+/// ```rust,ignore
+/// // build.rs: dioxus_docs_kit_build::bundle_openapi("api/petstore.yaml");
+/// DocsConfig::new(nav_json, content_map)
+///     .with_openapi("api-reference", dioxus_docs_kit::openapi_bundle!("petstore"))
+/// ```
+#[macro_export]
+macro_rules! openapi_bundle {
+    ($name:literal) => {
+        include_str!(concat!(env!("OUT_DIR"), "/", $name, ".bundled.json"))
+    };
+}
@@ -5,15 +5,19 @@
 use crate::components::seo::xml_escape;
 #[cfg(feature = "highlight")]
 use crate::config::CodeThemeConfig;
-use crate::config::{DocsConfig, ThemeConfig};
+use crate::config::{DocsConfig, OpenApiSource, ThemeConfig};
+use crate::data_table::{DATA_PREFIX, resolve_data_tables, table_rows};
 use crate::error::DocsKitError;
+use crate::glossary::{glossary_page, parse_glossary, resolve_terms};
 use crate::search::{Field, clean_markdown, search_lower};
 use dioxus_mdx::{
-    ApiOperation, ApiTag, HttpMethod, OpenApiSpec, ParsedDoc, parse_document, parse_openapi,
-    slugify,
+    ApiOperation, ApiTag, ApiWebhook, AsyncApiChannel, AsyncApiSpec, DocNode, ExampleViolation,
+    GlossaryEntry, HttpMethod, OpenApiSpec, OperationAction, ParsedDoc, SchemaDefinition,
+    get_raw_markdown, parse_asyncapi, parse_document, parse_openapi, parse_openapi_bundle, slugify,
 };
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Navigation configuration for the documentation sidebar.
 #[derive(Debug, Clone, Deserialize)]
@@ -21,6 +25,10 @@ pub struct NavConfig {
     #[serde(default)]
     pub tabs: Vec<String>,
     pub groups: Vec<NavGroup>,
+    /// Doc path of the page generated from the glossary, if any (see
+    /// [`DocsConfig::with_glossary`]).
+    #[serde(default)]
+    pub glossary: Option<String>,
 }
 
 impl NavConfig {
@@ -47,12 +55,12 @@ pub struct NavGroup {
     pub pages: Vec<String>,
 }
 
-/// A sidebar entry for an API endpoint.
+/// A sidebar entry for an API endpoint or webhook.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ApiEndpointEntry {
     /// URL prefix of the spec that owns this endpoint (e.g. "api-reference").
     pub prefix: String,
-    /// URL slug (e.g. "list-pets").
+    /// URL slug (e.g. "list-pets", or "webhooks/pet-adopted" for webhooks).
     pub slug: String,
     /// Display title (summary or fallback).
     pub title: String,
@@ -60,14 +68,49 @@ pub struct ApiEndpointEntry {
     pub method: HttpMethod,
 }
 
+/// Sidebar tag groups under one heading: an `x-tagGroups` entry, or no
+/// heading for tags outside any group and for webhooks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiSidebarSection {
+    /// Heading shown above the tags, if any.
+    pub heading: Option<String>,
+    /// Tags in display order, each with its endpoints.
+    pub groups: Vec<(ApiTag, Vec<ApiEndpointEntry>)>,
+}
+
+/// A sidebar entry for a component schema page (`<prefix>/schemas/<name>`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiModelEntry {
+    /// URL prefix of the spec that owns the schema.
+    pub prefix: String,
+    /// Schema name, its key under `components.schemas`.
+    pub name: String,
+}
+
+/// A sidebar entry for an AsyncAPI channel page (`<prefix>/<slug>`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsyncApiChannelEntry {
+    /// URL prefix of the spec that owns the channel (e.g. "events").
+    pub prefix: String,
+    /// URL slug (e.g. "user-signed-up").
+    pub slug: String,
+    /// Display title (channel title, operation summary or address).
+    pub title: String,
+    /// Actions performed on the channel, shown as badges.
+    pub actions: Vec<OperationAction>,
+}
+
 /// A searchable entry in the documentation.
 ///
 /// One entry per document *section* (split on h2–h4 headings): content before
 /// the first heading is a page-level "intro" entry with an empty `heading` /
 /// `anchor`, and each heading starts a new entry whose `anchor` deep-links to
-/// the rendered heading id. OpenAPI operations are indexed as single page-level
-/// entries. The `*_lower` fields are lowercased once at build time so search
-/// never re-lowercases per keystroke.
+/// the rendered heading id. Every `<DataTable>` row gets its own entry too, with
+/// the row's first cell as `heading` and its row id as `anchor`. OpenAPI
+/// operations, webhooks and schemas, and AsyncAPI channels, are indexed as
+/// single page-level entries.
+/// The `*_lower` fields are lowercased once at build time so search never
+/// re-lowercases per keystroke.
 #[derive(PartialEq)]
 pub struct SearchEntry {
     /// Content path of the owning page (e.g. "getting-started/introduction").
@@ -218,19 +261,45 @@ pub struct DocsRegistry {
     search_index: Vec<SearchEntry>,
     /// OpenAPI specs keyed by URL prefix.
     openapi_specs: Vec<(String, OpenApiSpec)>,
-    /// Precomputed API sidebar entries grouped by tag.
+    /// Precomputed API sidebar entries grouped by tag, under their headings.
+    api_sidebar_sections: Vec<ApiSidebarSection>,
+    /// The same groups across all headings.
     api_sidebar_entries: Vec<(ApiTag, Vec<ApiEndpointEntry>)>,
     /// Full docs path ("prefix/slug") → (spec index, operation index).
     api_operation_index: HashMap<String, (usize, usize)>,
+    /// Full docs path ("prefix/webhooks/slug") → (spec index, webhook index).
+    api_webhook_index: HashMap<String, (usize, usize)>,
+    /// Component schema pages, in spec then name order.
+    api_model_entries: Vec<ApiModelEntry>,
+    /// AsyncAPI specs keyed by URL prefix.
+    asyncapi_specs: Vec<(String, AsyncApiSpec)>,
+    /// Precomputed channel sidebar entries, one group per spec.
+    asyncapi_sidebar_groups: Vec<(String, Vec<AsyncApiChannelEntry>)>,
+    /// Full docs path ("prefix/slug") → (spec index, channel index).
+    asyncapi_channel_index: HashMap<String, (usize, usize)>,
+    /// Previous endpoint path → current path (see
+    /// [`DocsConfig::with_api_slug_redirects`]).
+    api_redirects: HashMap<String, String>,
+    /// Glossary entries, sorted by term.
+    glossary: Vec<GlossaryEntry>,
+    /// Doc path of the glossary page.
+    glossary_path: Option<&'static str>,
     /// Default page path for redirects.
     pub default_path: String,
     /// Display name for the API Reference sidebar group.
     pub api_group_name: String,
+    /// Display name for the AsyncAPI channels sidebar group.
+    pub asyncapi_group_name: String,
     /// Optional theme configuration.
     pub theme: Option<ThemeConfig>,
+    /// Whether endpoint pages show the "Try it" playground.
+    pub api_playground: bool,
     /// Syntax-highlighting theme for code blocks.
     #[cfg(feature = "highlight")]
     pub code_theme: CodeThemeConfig,
+    /// Extra fence-language aliases for code blocks.
+    #[cfg(feature = "highlight")]
+    pub language_aliases: HashMap<String, dioxus_code::Language>,
 }
 
 impl DocsRegistry {
@@ -240,9 +309,10 @@ impl DocsRegistry {
             serde_json::from_str(config.nav_json()).map_err(DocsKitError::NavParse)?;
 
         // Parse all documents
-        let parsed_docs: HashMap<&'static str, ParsedDoc> = config
+        let mut parsed_docs: HashMap<&'static str, ParsedDoc> = config
             .content_map()
             .iter()
+            .filter(|(path, _)| !path.starts_with(DATA_PREFIX))
             .map(|(&path, &content)| (path, parse_document(content)))
             .collect();
 
@@ -250,16 +320,67 @@ impl DocsRegistry {
         let openapi_specs: Vec<(String, OpenApiSpec)> = config
             .openapi_specs()
             .iter()
+            .map(|(prefix, source)| {
+                let spec = match source {
+                    OpenApiSource::Document(yaml) => parse_openapi(yaml),
+                    OpenApiSource::Bundle { root, documents } => {
+                        let documents: HashMap<&str, &str> = documents
+                            .iter()
+                            .map(|(path, content)| (path.as_str(), content.as_str()))
+                            .collect();
+                        parse_openapi_bundle(root, &documents)
+                    }
+                };
+                spec.map(|spec| (prefix.clone(), spec))
+                    .map_err(|error| DocsKitError::OpenApi {
+                        prefix: prefix.clone(),
+                        error,
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Parse AsyncAPI specs
+        let asyncapi_specs: Vec<(String, AsyncApiSpec)> = config
+            .asyncapi_specs()
+            .iter()
             .map(|(prefix, yaml)| {
-                parse_openapi(yaml)
+                parse_asyncapi(yaml)
                     .map(|spec| (prefix.clone(), spec))
-                    .map_err(|error| DocsKitError::OpenApi {
+                    .map_err(|error| DocsKitError::AsyncApi {
                         prefix: prefix.clone(),
                         error,
                     })
             })
             .collect::<Result<_, _>>()?;
 
+        check_api_paths(&openapi_specs, &asyncapi_specs, &parsed_docs)?;
+        resolve_api_references(&mut parsed_docs, &openapi_specs)?;
+        resolve_data_tables(&mut parsed_docs, config.content_map())?;
+
+        // Generate the glossary page, then resolve `<Term>`s on every page.
+        // Without a glossary, terms are left to render as plain text.
+        let mut glossary_path = None;
+        let mut glossary = Vec::new();
+        if let Some(json) = config.glossary() {
+            glossary = parse_glossary(json)?;
+            if let Some(path) = nav.glossary.as_deref() {
+                let (path, source) = match config.content_map().get_key_value(path) {
+                    Some((&path, &source)) => (path, Some(source)),
+                    // A page without an `.mdx` file has no `'static` key to
+                    // reuse; the registry lives for the whole program anyway.
+                    None => (&*Box::leak(Box::<str>::from(path)), None),
+                };
+                parsed_docs.insert(path, glossary_page(source, &glossary));
+                glossary_path = Some(path);
+            }
+            resolve_terms(
+                &mut parsed_docs,
+                &glossary,
+                glossary_path,
+                config.glossary_auto_link(),
+            )?;
+        }
+
         // Determine default path
         let default_path = config
             .default_path_value()
@@ -276,10 +397,16 @@ impl DocsRegistry {
             .api_group_name_value()
             .map(String::from)
             .unwrap_or_else(|| "API Reference".to_string());
+        let asyncapi_group_name = config
+            .asyncapi_group_name_value()
+            .map(String::from)
+            .unwrap_or_else(|| "Events".to_string());
 
         let theme = config.theme_config().cloned();
         #[cfg(feature = "highlight")]
         let code_theme = config.code_theme_value();
+        #[cfg(feature = "highlight")]
+        let language_aliases = config.language_aliases().clone();
 
         // Warn if OpenAPI specs were registered but no nav group matches api_group_name
         if !openapi_specs.is_empty() && !nav.groups.iter().any(|g| g.group == api_group_name) {
@@ -290,12 +417,32 @@ impl DocsRegistry {
                  _nav.json, or call .with_api_group_name(\"<your group name>\") on DocsConfig."
             );
         }
+        if !asyncapi_specs.is_empty() && !nav.groups.iter().any(|g| g.group == asyncapi_group_name)
+        {
+            tracing::warn!(
+                "dioxus-docs-kit: AsyncAPI specs registered but no nav group \
+                 matches asyncapi_group_name \"{asyncapi_group_name}\". Channels won't appear \
+                 in the sidebar. Add a group with `\"group\": \"{asyncapi_group_name}\"` to \
+                 _nav.json, or call .with_asyncapi_group_name(\"<your group name>\") on DocsConfig."
+            );
+        }
 
         // Build search index
-        let search_index =
-            Self::build_search_index(&nav, &parsed_docs, &openapi_specs, &api_group_name);
+        let search_index = Self::build_search_index(
+            &nav,
+            &parsed_docs,
+            &openapi_specs,
+            &api_group_name,
+            &asyncapi_specs,
+            &asyncapi_group_name,
+            glossary_path,
+        );
 
-        let api_sidebar_entries = Self::build_api_sidebar_entries(&openapi_specs);
+        let api_sidebar_sections = Self::build_api_sidebar_sections(&openapi_specs);
+        let api_sidebar_entries = api_sidebar_sections
+            .iter()
+            .flat_map(|section| section.groups.iter().cloned())
+            .collect();
 
         let api_operation_index = openapi_specs
             .iter()
@@ -307,18 +454,91 @@ impl DocsRegistry {
             })
             .collect();
 
+        let api_webhook_index = openapi_specs
+            .iter()
+            .enumerate()
+            .flat_map(|(spec_idx, (prefix, spec))| {
+                spec.webhooks
+                    .iter()
+                    .enumerate()
+                    .map(move |(hook_idx, hook)| (webhook_path(prefix, hook), (spec_idx, hook_idx)))
+            })
+            .collect();
+
+        let api_model_entries = openapi_specs
+            .iter()
+            .flat_map(|(prefix, spec)| {
+                spec.schemas.keys().map(move |name| ApiModelEntry {
+                    prefix: prefix.clone(),
+                    name: name.clone(),
+                })
+            })
+            .collect();
+
+        let asyncapi_sidebar_groups = asyncapi_specs
+            .iter()
+            .map(|(prefix, spec)| {
+                let title = if spec.info.title.is_empty() {
+                    prefix.clone()
+                } else {
+                    spec.info.title.clone()
+                };
+                let entries = spec
+                    .channels
+                    .iter()
+                    .map(|channel| AsyncApiChannelEntry {
+                        prefix: prefix.clone(),
+                        slug: channel.slug(),
+                        title: channel.title(),
+                        actions: channel.actions(),
+                    })
+                    .collect();
+                (title, entries)
+            })
+            .collect();
+
+        let asyncapi_channel_index = asyncapi_specs
+            .iter()
+            .enumerate()
+            .flat_map(|(spec_idx, (prefix, spec))| {
+                spec.channels
+                    .iter()
+                    .enumerate()
+                    .map(move |(idx, channel)| (channel_path(prefix, channel), (spec_idx, idx)))
+            })
+            .collect();
+
+        let api_redirects = if config.api_slug_redirects() {
+            build_api_redirects(&openapi_specs, &asyncapi_specs, &parsed_docs)
+        } else {
+            HashMap::new()
+        };
+
         Ok(Self {
             nav,
             parsed_docs,
             search_index,
             openapi_specs,
+            api_sidebar_sections,
             api_sidebar_entries,
             api_operation_index,
+            api_webhook_index,
+            api_model_entries,
+            asyncapi_specs,
+            asyncapi_sidebar_groups,
+            asyncapi_channel_index,
+            api_redirects,
+            glossary,
+            glossary_path,
             default_path,
             api_group_name,
+            asyncapi_group_name,
             theme,
+            api_playground: config.api_playground(),
             #[cfg(feature = "highlight")]
             code_theme,
+            #[cfg(feature = "highlight")]
+            language_aliases,
         })
     }
 
@@ -336,6 +556,15 @@ impl DocsRegistry {
                 .clone()
                 .or_else(|| Some(op.slug().replace('-', " ")));
         }
+        if let Some(webhook) = self.get_api_webhook(path) {
+            return Some(webhook.title());
+        }
+        if let Some((name, _)) = self.get_api_schema(path) {
+            return Some(name.to_string());
+        }
+        if let Some(channel) = self.get_asyncapi_channel(path) {
+            return Some(channel.title());
+        }
 
         self.get_parsed_doc(path).and_then(|doc| {
             doc.frontmatter.sidebar_title.clone().or_else(|| {
@@ -361,10 +590,11 @@ impl DocsRegistry {
 
     /// Get the page title for the document `<head>`.
     ///
-    /// Resolves MDX frontmatter titles *and* OpenAPI operation pages (which are
-    /// not in `parsed_docs`): for an endpoint path it returns the operation
-    /// summary, falling back to a humanised slug. Branding/suffixes are left to
-    /// the caller.
+    /// Resolves MDX frontmatter titles *and* OpenAPI operation, webhook and
+    /// schema pages and AsyncAPI channel pages (which are not in
+    /// `parsed_docs`): for an endpoint path it returns the operation summary,
+    /// falling back to a humanised slug.
+    /// Branding/suffixes are left to the caller.
     pub fn get_page_title(&self, path: &str) -> Option<String> {
         if let Some(op) = self.get_api_operation(path) {
             return op
@@ -372,6 +602,15 @@ impl DocsRegistry {
                 .clone()
                 .or_else(|| Some(op.slug().replace('-', " ")));
         }
+        if let Some(webhook) = self.get_api_webhook(path) {
+            return Some(webhook.title());
+        }
+        if let Some((name, _)) = self.get_api_schema(path) {
+            return Some(name.to_string());
+        }
+        if let Some(channel) = self.get_asyncapi_channel(path) {
+            return Some(channel.title());
+        }
         self.get_doc_title(path)
     }
 
@@ -384,6 +623,15 @@ impl DocsRegistry {
         if let Some(op) = self.get_api_operation(path) {
             return op.description.clone();
         }
+        if let Some(webhook) = self.get_api_webhook(path) {
+            return webhook.description().map(String::from);
+        }
+        if let Some((_, schema)) = self.get_api_schema(path) {
+            return schema.description.clone();
+        }
+        if let Some(channel) = self.get_asyncapi_channel(path) {
+            return channel.summary().map(String::from);
+        }
         self.get_parsed_doc(path)
             .and_then(|doc| doc.frontmatter.description.clone())
     }
@@ -401,6 +649,16 @@ impl DocsRegistry {
             .map(|doc| doc.raw_markdown.as_str())
     }
 
+    /// Get the glossary entries, sorted by term (empty without a glossary).
+    pub fn get_glossary(&self) -> &[GlossaryEntry] {
+        &self.glossary
+    }
+
+    /// Get the doc path of the glossary page, if a glossary is configured.
+    pub fn get_glossary_path(&self) -> Option<&str> {
+        self.glossary_path
+    }
+
     /// Get all available documentation paths.
     pub fn get_all_paths(&self) -> Vec<&str> {
         self.parsed_docs.keys().copied().collect()
@@ -428,6 +686,71 @@ impl DocsRegistry {
         Some((&spec.operations[op_idx], spec))
     }
 
+    /// Look up a webhook or callback by its full docs path, e.g.
+    /// "api-reference/webhooks/pet-adopted".
+    pub fn get_api_webhook(&self, path: &str) -> Option<&ApiWebhook> {
+        let &(spec_idx, hook_idx) = self.api_webhook_index.get(path)?;
+        let (_, spec) = &self.openapi_specs[spec_idx];
+        Some(&spec.webhooks[hook_idx])
+    }
+
+    /// Current docs path of an endpoint previously at `path`, e.g.
+    /// "api-reference/list-pets" for "api-reference/get-pets". Only set when
+    /// [`DocsConfig::with_api_slug_redirects`] is on.
+    pub fn get_api_redirect(&self, path: &str) -> Option<&str> {
+        self.api_redirects.get(path).map(String::as_str)
+    }
+
+    /// All endpoint redirects, as (previous path, current path) pairs.
+    pub fn get_api_redirects(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.api_redirects
+            .iter()
+            .map(|(from, to)| (from.as_str(), to.as_str()))
+    }
+
+    /// Look up a component schema by its full docs path, e.g.
+    /// "api-reference/schemas/Pet". Returns the schema name and definition.
+    pub fn get_api_schema(&self, path: &str) -> Option<(&str, &SchemaDefinition)> {
+        self.openapi_specs.iter().find_map(|(prefix, spec)| {
+            let name = path
+                .strip_prefix(prefix.as_str())?
+                .strip_prefix("/schemas/")?;
+            spec.schemas
+                .get_key_value(name)
+                .map(|(name, schema)| (name.as_str(), schema))
+        })
+    }
+
+    /// Operations that use the schema at `path`, each with its docs path.
+    pub fn get_api_schema_users(&self, path: &str) -> Vec<(&ApiOperation, String)> {
+        self.openapi_specs
+            .iter()
+            .find_map(|(prefix, spec)| {
+                let name = path
+                    .strip_prefix(prefix.as_str())?
+                    .strip_prefix("/schemas/")?;
+                Some(
+                    spec.schema_users(name)
+                        .into_iter()
+                        .map(|op| (op, format!("{prefix}/{}", op.slug())))
+                        .collect(),
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    /// Docs path of each schema name's page, for [`dioxus_mdx::SchemaLinks`].
+    /// When several specs define the same name, the first spec wins.
+    pub fn get_api_schema_paths(&self) -> BTreeMap<String, String> {
+        let mut paths = BTreeMap::new();
+        for entry in &self.api_model_entries {
+            paths
+                .entry(entry.name.clone())
+                .or_insert_with(|| schema_path(&entry.prefix, &entry.name));
+        }
+        paths
+    }
+
     /// Get the OpenAPI spec that owns a given path prefix.
     pub fn get_api_spec(&self, prefix: &str) -> Option<&OpenApiSpec> {
         self.openapi_specs
@@ -436,6 +759,23 @@ impl DocsRegistry {
             .map(|(_, spec)| spec)
     }
 
+    /// Check every registered spec's examples against their schemas (see
+    /// [`OpenApiSpec::validate_examples`]). Each violation comes with the URL
+    /// prefix of its spec.
+    ///
+    /// Asserting this is empty in a test catches examples that have drifted
+    /// from their schemas.
+    pub fn validate_api_examples(&self) -> Vec<(&str, ExampleViolation)> {
+        self.openapi_specs
+            .iter()
+            .flat_map(|(prefix, spec)| {
+                spec.validate_examples()
+                    .into_iter()
+                    .map(move |violation| (prefix.as_str(), violation))
+            })
+            .collect()
+    }
+
     /// Get the first OpenAPI spec (convenience for single-spec setups).
     pub fn get_first_api_spec(&self) -> Option<&OpenApiSpec> {
         self.openapi_specs.first().map(|(_, spec)| spec)
@@ -446,16 +786,64 @@ impl DocsRegistry {
         self.openapi_specs.first().map(|(p, _)| p.as_str())
     }
 
-    /// Get API endpoint sidebar entries grouped by tag (precomputed).
+    /// Get component schema sidebar entries (precomputed).
+    pub fn get_api_model_entries(&self) -> &[ApiModelEntry] {
+        &self.api_model_entries
+    }
+
+    /// Get API endpoint sidebar entries grouped by tag, under their
+    /// `x-tagGroups` headings (precomputed).
+    pub fn get_api_sidebar_sections(&self) -> &[ApiSidebarSection] {
+        &self.api_sidebar_sections
+    }
+
+    /// Get API endpoint sidebar entries grouped by tag, across all headings
+    /// (precomputed).
     pub fn get_api_sidebar_entries(&self) -> &[(ApiTag, Vec<ApiEndpointEntry>)] {
         &self.api_sidebar_entries
     }
 
-    /// Build API endpoint sidebar entries grouped by tag.
-    fn build_api_sidebar_entries(
+    // ========================================================================
+    // AsyncAPI methods
+    // ========================================================================
+
+    /// Look up an AsyncAPI channel by its full docs path, e.g.
+    /// "events/user-signed-up".
+    pub fn get_asyncapi_channel(&self, path: &str) -> Option<&AsyncApiChannel> {
+        self.get_asyncapi_channel_with_spec(path)
+            .map(|(channel, _)| channel)
+    }
+
+    /// Look up an AsyncAPI channel together with the spec that owns it.
+    pub fn get_asyncapi_channel_with_spec(
+        &self,
+        path: &str,
+    ) -> Option<(&AsyncApiChannel, &AsyncApiSpec)> {
+        let &(spec_idx, channel_idx) = self.asyncapi_channel_index.get(path)?;
+        let (_, spec) = &self.asyncapi_specs[spec_idx];
+        Some((&spec.channels[channel_idx], spec))
+    }
+
+    /// Get the AsyncAPI spec registered under a path prefix.
+    pub fn get_asyncapi_spec(&self, prefix: &str) -> Option<&AsyncApiSpec> {
+        self.asyncapi_specs
+            .iter()
+            .find(|(p, _)| p == prefix)
+            .map(|(_, spec)| spec)
+    }
+
+    /// Get channel sidebar entries, grouped under each spec's title
+    /// (precomputed).
+    pub fn get_asyncapi_sidebar_entries(&self) -> &[(String, Vec<AsyncApiChannelEntry>)] {
+        &self.asyncapi_sidebar_groups
+    }
+
+    /// Build API endpoint sidebar sections: each spec's tag sections (see
+    /// [`OpenApiSpec::tag_sections`]), then its webhooks.
+    fn build_api_sidebar_sections(
         openapi_specs: &[(String, OpenApiSpec)],
-    ) -> Vec<(ApiTag, Vec<ApiEndpointEntry>)> {
-        let mut all_groups: Vec<(ApiTag, Vec<ApiEndpointEntry>)> = Vec::new();
+    ) -> Vec<ApiSidebarSection> {
+        let mut sections = Vec::new();
 
         let make_entry = |prefix: &str, op: &ApiOperation| ApiEndpointEntry {
             prefix: prefix.to_string(),
@@ -468,51 +856,70 @@ impl DocsRegistry {
         };
 
         for (prefix, spec) in openapi_specs {
-            for tag in &spec.tags {
-                let entries: Vec<ApiEndpointEntry> = spec
-                    .operations
-                    .iter()
-                    .filter(|op| op.tags.contains(&tag.name))
-                    .map(|op| make_entry(prefix, op))
-                    .collect();
-
-                if !entries.is_empty() {
-                    all_groups.push((tag.clone(), entries));
-                }
+            for section in spec.tag_sections() {
+                sections.push(ApiSidebarSection {
+                    heading: section.heading.map(str::to_string),
+                    groups: section
+                        .tags
+                        .into_iter()
+                        .map(|(tag, ops)| {
+                            let entries = ops.into_iter().map(|op| make_entry(prefix, op));
+                            (tag, entries.collect())
+                        })
+                        .collect(),
+                });
             }
 
-            // Untagged operations
-            let tagged_ids: Vec<_> = spec.tags.iter().map(|t| t.name.as_str()).collect();
-            let untagged: Vec<ApiEndpointEntry> = spec
-                .operations
+            // Webhooks and callbacks, under `<prefix>/webhooks/`
+            let webhooks: Vec<ApiEndpointEntry> = spec
+                .webhooks
                 .iter()
-                .filter(|op| {
-                    op.tags.is_empty() || op.tags.iter().all(|t| !tagged_ids.contains(&t.as_str()))
+                .filter_map(|hook| {
+                    Some(ApiEndpointEntry {
+                        prefix: prefix.clone(),
+                        slug: format!("webhooks/{}", hook.slug()),
+                        title: hook.title(),
+                        method: hook.operations.first()?.method,
+                    })
                 })
-                .map(|op| make_entry(prefix, op))
                 .collect();
 
-            if !untagged.is_empty() {
-                all_groups.push((
-                    ApiTag {
-                        name: "Other".to_string(),
-                        description: None,
-                    },
-                    untagged,
-                ));
+            if !webhooks.is_empty() {
+                sections.push(ApiSidebarSection {
+                    heading: None,
+                    groups: vec![(
+                        ApiTag {
+                            name: "Webhooks".to_string(),
+                            description: None,
+                        },
+                        webhooks,
+                    )],
+                });
             }
         }
 
-        all_groups
+        sections
     }
 
-    /// Get all API endpoint paths for navigation ordering.
+    /// Get all API endpoint, webhook, schema and channel paths for navigation
+    /// ordering.
     pub fn get_api_endpoint_paths(&self) -> Vec<String> {
         let mut paths = Vec::new();
         for (prefix, spec) in &self.openapi_specs {
             for op in &spec.operations {
                 paths.push(format!("{prefix}/{}", op.slug()));
             }
+            for hook in &spec.webhooks {
+                paths.push(webhook_path(prefix, hook));
+            }
+            for name in spec.schemas.keys() {
+                paths.push(schema_path(prefix, name));
+            }
+        }
+        for (prefix, spec) in &self.asyncapi_specs {
+            for channel in &spec.channels {
+                paths.push(channel_path(prefix, channel));
+            }
         }
         paths
     }
@@ -537,6 +944,15 @@ impl DocsRegistry {
             }
         }
 
+        // Check dynamic channel pages
+        if self.asyncapi_channel_index.contains_key(path) {
+            for group in &self.nav.groups {
+                if group.group == self.asyncapi_group_name {
+                    return group.tab.clone();
+                }
+            }
+        }
+
         None
     }
 
@@ -636,14 +1052,22 @@ impl DocsRegistry {
             }
         }
 
-        // API endpoint pages
-        for (prefix, spec) in &self.openapi_specs {
-            for op in &spec.operations {
-                let loc = xml_escape(&format!("{site_url}{docs_path}/{prefix}/{}", op.slug()));
-                xml.push_str(&format!(
-                    "<url>\n<loc>{loc}</loc>\n<changefreq>monthly</changefreq>\n<priority>0.5</priority>\n</url>\n"
-                ));
-            }
+        // Glossary page, when it isn't already listed in the nav
+        if let Some(path) = self.glossary_path
+            && !in_nav(&self.nav, path)
+        {
+            let loc = xml_escape(&format!("{site_url}{docs_path}/{path}"));
+            xml.push_str(&format!(
+                "<url>\n<loc>{loc}</loc>\n<changefreq>weekly</changefreq>\n<priority>0.5</priority>\n</url>\n"
+            ));
+        }
+
+        // API endpoint, webhook, schema and channel pages
+        for path in self.get_api_endpoint_paths() {
+            let loc = xml_escape(&format!("{site_url}{docs_path}/{path}"));
+            xml.push_str(&format!(
+                "<url>\n<loc>{loc}</loc>\n<changefreq>monthly</changefreq>\n<priority>0.5</priority>\n</url>\n"
+            ));
         }
 
         xml.push_str("</urlset>\n");
@@ -677,12 +1101,15 @@ impl DocsRegistry {
     /// Build the search index from parsed docs and OpenAPI specs.
     ///
     /// Docs are indexed per section (split on h2–h4 headings); OpenAPI
-    /// operations stay page-level.
+    /// operations, webhooks and schemas and AsyncAPI channels stay page-level.
     fn build_search_index(
         nav: &NavConfig,
         parsed_docs: &HashMap<&'static str, ParsedDoc>,
         openapi_specs: &[(String, OpenApiSpec)],
         api_group_name: &str,
+        asyncapi_specs: &[(String, AsyncApiSpec)],
+        asyncapi_group_name: &str,
+        glossary_path: Option<&str>,
     ) -> Vec<SearchEntry> {
         let mut entries = Vec::new();
 
@@ -690,40 +1117,19 @@ impl DocsRegistry {
         for group in &nav.groups {
             for page in &group.pages {
                 if let Some(doc) = parsed_docs.get(page.as_str()) {
-                    let title = if doc.frontmatter.title.is_empty() {
-                        page.split('/')
-                            .next_back()
-                            .unwrap_or(page)
-                            .replace('-', " ")
-                    } else {
-                        doc.frontmatter.title.clone()
-                    };
-                    let description = doc.frontmatter.description.clone().unwrap_or_default();
-
-                    let sections = split_into_sections(&doc.raw_markdown);
-                    let has_headings = sections.iter().any(|s| !s.heading.is_empty());
-                    for section in sections {
-                        let body = clean_markdown(&section.body);
-                        // Drop an empty intro once real sections exist; keep it
-                        // for a heading-less page so it stays findable by title.
-                        if section.heading.is_empty() && body.is_empty() && has_headings {
-                            continue;
-                        }
-                        entries.push(SearchEntry::new(
-                            page.clone(),
-                            section.anchor,
-                            title.clone(),
-                            section.heading,
-                            description.clone(),
-                            body,
-                            group.group.clone(),
-                            None,
-                        ));
-                    }
+                    Self::index_page(&mut entries, page, doc, &group.group);
                 }
             }
         }
 
+        // The glossary page is searchable even when the nav doesn't list it.
+        if let Some(path) = glossary_path
+            && !in_nav(nav, path)
+            && let Some(doc) = parsed_docs.get(path)
+        {
+            Self::index_page(&mut entries, path, doc, &doc.frontmatter.title);
+        }
+
         // Index API operations (page-level).
         for (prefix, spec) in openapi_specs {
             for op in &spec.operations {
@@ -749,10 +1155,315 @@ impl DocsRegistry {
                     Some(op.method),
                 ));
             }
+
+            for hook in &spec.webhooks {
+                let Some(method) = hook.operations.first().map(|op| op.method) else {
+                    continue;
+                };
+                let description = hook.description().unwrap_or_default().to_string();
+                entries.push(SearchEntry::new(
+                    webhook_path(prefix, hook),
+                    String::new(),
+                    hook.title(),
+                    String::new(),
+                    description.clone(),
+                    clean_markdown(&description),
+                    format!("{api_group_name} > Webhooks"),
+                    Some(method),
+                ));
+            }
+
+            for (name, schema) in &spec.schemas {
+                let description = schema.description.clone().unwrap_or_default();
+                entries.push(SearchEntry::new(
+                    schema_path(prefix, name),
+                    String::new(),
+                    name.clone(),
+                    String::new(),
+                    description.clone(),
+                    clean_markdown(&description),
+                    format!("{api_group_name} > Models"),
+                    None,
+                ));
+            }
+        }
+
+        // Index AsyncAPI channels (page-level), with their address and
+        // message names as body text.
+        for (prefix, spec) in asyncapi_specs {
+            for channel in &spec.channels {
+                let description = channel.summary().unwrap_or_default().to_string();
+                let mut body = vec![channel.address.clone(), clean_markdown(&description)];
+                body.extend(channel.messages.iter().map(|m| m.name.clone()));
+                entries.push(SearchEntry::new(
+                    channel_path(prefix, channel),
+                    String::new(),
+                    channel.title(),
+                    String::new(),
+                    description,
+                    body.join(" "),
+                    format!("{asyncapi_group_name} > {}", spec.info.title),
+                    None,
+                ));
+            }
         }
 
         entries
     }
+
+    /// Index one doc page, one entry per section.
+    fn index_page(entries: &mut Vec<SearchEntry>, page: &str, doc: &ParsedDoc, breadcrumb: &str) {
+        let title = if doc.frontmatter.title.is_empty() {
+            page.split('/')
+                .next_back()
+                .unwrap_or(page)
+                .replace('-', " ")
+        } else {
+            doc.frontmatter.title.clone()
+        };
+        let description = doc.frontmatter.description.clone().unwrap_or_default();
+
+        let sections = split_into_sections(&doc.raw_markdown);
+        let has_headings = sections.iter().any(|s| !s.heading.is_empty());
+        for section in sections {
+            let body = clean_markdown(&section.body);
+            // Drop an empty intro once real sections exist; keep it
+            // for a heading-less page so it stays findable by title.
+            if section.heading.is_empty() && body.is_empty() && has_headings {
+                continue;
+            }
+            entries.push(SearchEntry::new(
+                page.to_string(),
+                section.anchor,
+                title.clone(),
+                section.heading,
+                description.clone(),
+                body,
+                breadcrumb.to_string(),
+                None,
+            ));
+        }
+
+        // Table rows, so an error code or region name finds its row.
+        for row in table_rows(&doc.content) {
+            entries.push(SearchEntry::new(
+                page.to_string(),
+                row.anchor,
+                title.clone(),
+                row.key,
+                description.clone(),
+                row.body,
+                breadcrumb.to_string(),
+                None,
+            ));
+        }
+    }
+}
+
+/// Full docs path of a webhook page: `<prefix>/webhooks/<slug>`.
+fn webhook_path(prefix: &str, hook: &ApiWebhook) -> String {
+    format!("{prefix}/webhooks/{}", hook.slug())
+}
+
+/// Full docs path of a schema page: `<prefix>/schemas/<name>`.
+fn schema_path(prefix: &str, name: &str) -> String {
+    format!("{prefix}/schemas/{name}")
+}
+
+/// Full docs path of a channel page: `<prefix>/<slug>`.
+fn channel_path(prefix: &str, channel: &AsyncApiChannel) -> String {
+    format!("{prefix}/{}", channel.slug())
+}
+
+/// Every API page (endpoints, webhooks, schemas, channels) as its docs path
+/// and a description for error messages.
+fn api_pages(
+    specs: &[(String, OpenApiSpec)],
+    asyncapi_specs: &[(String, AsyncApiSpec)],
+) -> Vec<(String, String)> {
+    let mut pages = Vec::new();
+    for (prefix, spec) in specs {
+        for op in &spec.operations {
+            pages.push((
+                format!("{prefix}/{}", op.slug()),
+                format!("{} {}", op.method.as_str(), op.path),
+            ));
+        }
+        for hook in &spec.webhooks {
+            pages.push((
+                webhook_path(prefix, hook),
+                format!("webhook \"{}\"", hook.name),
+            ));
+        }
+        for name in spec.schemas.keys() {
+            pages.push((schema_path(prefix, name), format!("schema \"{name}\"")));
+        }
+    }
+    for (prefix, spec) in asyncapi_specs {
+        for channel in &spec.channels {
+            pages.push((
+                channel_path(prefix, channel),
+                format!("channel \"{}\"", channel.address),
+            ));
+        }
+    }
+    pages
+}
+
+/// Fail when two API pages, or an API page and an MDX page, resolve to the
+/// same docs path, which would make one of them unreachable.
+fn check_api_paths(
+    specs: &[(String, OpenApiSpec)],
+    asyncapi_specs: &[(String, AsyncApiSpec)],
+    parsed_docs: &HashMap<&'static str, ParsedDoc>,
+) -> Result<(), DocsKitError> {
+    let mut seen: HashMap<String, String> = parsed_docs
+        .keys()
+        .map(|path| (path.to_string(), format!("page \"{path}\"")))
+        .collect();
+    for (path, page) in api_pages(specs, asyncapi_specs) {
+        match seen.entry(path) {
+            Entry::Occupied(first) => {
+                return Err(DocsKitError::DuplicateApiSlug {
+                    path: first.key().clone(),
+                    first: first.get().clone(),
+                    second: page,
+                });
+            }
+            Entry::Vacant(slot) => {
+                slot.insert(page);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Map each endpoint's previous slugs to its current path, skipping any
+/// that a page already uses. The first endpoint to claim a slug keeps it.
+fn build_api_redirects(
+    specs: &[(String, OpenApiSpec)],
+    asyncapi_specs: &[(String, AsyncApiSpec)],
+    parsed_docs: &HashMap<&'static str, ParsedDoc>,
+) -> HashMap<String, String> {
+    let pages: HashSet<String> = api_pages(specs, asyncapi_specs)
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect();
+    let mut redirects = HashMap::new();
+    for (prefix, spec) in specs {
+        for op in &spec.operations {
+            let target = format!("{prefix}/{}", op.slug());
+            for slug in op.previous_slugs() {
+                let from = format!("{prefix}/{slug}");
+                if !pages.contains(&from) && !parsed_docs.contains_key(from.as_str()) {
+                    redirects.entry(from).or_insert_with(|| target.clone());
+                }
+            }
+        }
+    }
+    redirects
+}
+
+/// Whether any nav group lists `path`.
+fn in_nav(nav: &NavConfig, path: &str) -> bool {
+    nav.groups.iter().any(|g| g.pages.iter().any(|p| p == path))
+}
+
+/// Resolve every page's `<ApiEndpoint>` and `<Schema>` references against the
+/// registered specs, refreshing the raw markdown of pages that have any.
+fn resolve_api_references(
+    parsed_docs: &mut HashMap<&'static str, ParsedDoc>,
+    specs: &[(String, OpenApiSpec)],
+) -> Result<(), DocsKitError> {
+    // Sorted so the reported error doesn't depend on HashMap order.
+    let mut paths: Vec<&'static str> = parsed_docs.keys().copied().collect();
+    paths.sort_unstable();
+
+    for path in paths {
+        let Some(doc) = parsed_docs.get_mut(path) else {
+            continue;
+        };
+        if resolve_nodes(&mut doc.content, specs, path)? {
+            doc.raw_markdown = get_raw_markdown(&doc.content);
+        }
+    }
+    Ok(())
+}
+
+/// Resolve references in `nodes` and their nested content. Returns whether any
+/// reference was found.
+fn resolve_nodes(
+    nodes: &mut [DocNode],
+    specs: &[(String, OpenApiSpec)],
+    page: &str,
+) -> Result<bool, DocsKitError> {
+    let mut found = false;
+
+    for node in nodes {
+        match node {
+            DocNode::ApiEndpoint(endpoint) => {
+                let (prefix, op) = specs_in_scope(specs, endpoint.spec.as_deref())
+                    .find_map(|(prefix, spec)| {
+                        spec.operations
+                            .iter()
+                            .find(|op| op.operation_id.as_ref() == Some(&endpoint.operation_id))
+                            .map(|op| (prefix, op))
+                    })
+                    .ok_or_else(|| {
+                        unknown_reference(
+                            page,
+                            format!("operationId \"{}\"", endpoint.operation_id),
+                            endpoint.spec.as_deref(),
+                        )
+                    })?;
+                endpoint.href = Some(format!("{prefix}/{}", op.slug()));
+                endpoint.operation = Some(op.clone());
+                found = true;
+            }
+            DocNode::Schema(schema) => {
+                let definition = specs_in_scope(specs, schema.spec.as_deref())
+                    .find_map(|(_, spec)| spec.schemas.get(&schema.name))
+                    .ok_or_else(|| {
+                        unknown_reference(
+                            page,
+                            format!("schema \"{}\"", schema.name),
+                            schema.spec.as_deref(),
+                        )
+                    })?;
+                schema.schema = Some(definition.clone());
+                found = true;
+            }
+            other => {
+                for children in other.children_mut() {
+                    found |= resolve_nodes(children, specs, page)?;
+                }
+            }
+        }
+    }
+
+    Ok(found)
+}
+
+/// Specs a reference may resolve against: the one named by `spec="..."`, or all.
+fn specs_in_scope<'a>(
+    specs: &'a [(String, OpenApiSpec)],
+    prefix: Option<&'a str>,
+) -> impl Iterator<Item = (&'a str, &'a OpenApiSpec)> {
+    specs
+        .iter()
+        .filter(move |(p, _)| prefix.is_none_or(|prefix| p == prefix))
+        .map(|(p, spec)| (p.as_str(), spec))
+}
+
+fn unknown_reference(page: &str, reference: String, spec: Option<&str>) -> DocsKitError {
+    let reference = match spec {
+        Some(prefix) => format!("{reference} in spec \"{prefix}\""),
+        None => reference,
+    };
+    DocsKitError::UnknownApiReference {
+        page: page.to_string(),
+        reference,
+    }
 }
 
 #[cfg(test)]
@@ -809,6 +1520,16 @@ paths:
       responses:
         "200":
           description: OK
+components:
+  schemas:
+    Pet:
+      type: object
+      required: [id]
+      properties:
+        id:
+          type: integer
+        name:
+          type: string
 "#;
 
     const ADMIN_SPEC: &str = r#"
@@ -868,6 +1589,106 @@ paths:
         assert!(err.to_string().contains("api-reference"));
     }
 
+    #[test]
+    fn try_build_reports_duplicate_api_slugs() {
+        let spec = PETS_SPEC.replace(
+            "operationId: miscThing",
+            "operationId: miscThing\n      x-slug: list-pets",
+        );
+        let Err(err) = DocsConfig::new(NAV, content_map())
+            .with_openapi("api-reference", &spec)
+            .try_build()
+        else {
+            panic!("expected a duplicate slug error");
+        };
+        match &err {
+            DocsKitError::DuplicateApiSlug {
+                path,
+                first,
+                second,
+            } => {
+                assert_eq!(path, "api-reference/list-pets");
+                assert_eq!(first, "GET /pets");
+                assert_eq!(second, "GET /misc");
+            }
+            other => panic!("expected DuplicateApiSlug, got {other:?}"),
+        }
+        assert!(err.to_string().contains("x-slug"), "{err}");
+    }
+
+    #[test]
+    fn try_build_reports_api_slugs_that_shadow_doc_pages() {
+        let spec = PETS_SPEC.replace(
+            "operationId: listPets",
+            "operationId: listPets\n      x-slug: Overview",
+        );
+        let Err(err) = DocsConfig::new(NAV, content_map())
+            .with_openapi("api-reference", &spec)
+            .try_build()
+        else {
+            panic!("expected a duplicate slug error");
+        };
+        match &err {
+            DocsKitError::DuplicateApiSlug {
+                path,
+                first,
+                second,
+            } => {
+                assert_eq!(path, "api-reference/overview");
+                assert_eq!(first, "page \"api-reference/overview\"");
+                assert_eq!(second, "GET /pets");
+            }
+            other => panic!("expected DuplicateApiSlug, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn x_slug_pins_endpoint_paths_and_redirects_previous_ones() {
+        let spec = PETS_SPEC.replace(
+            "operationId: listPets",
+            "operationId: listPets\n      x-slug: all-pets",
+        );
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_openapi("api-reference", &spec)
+            .build();
+        assert!(reg.get_api_operation("api-reference/all-pets").is_some());
+        assert!(reg.get_api_operation("api-reference/list-pets").is_none());
+        assert_eq!(reg.get_api_redirects().count(), 0);
+
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_openapi("api-reference", &spec)
+            .with_api_slug_redirects(true)
+            .build();
+        for old in ["api-reference/list-pets", "api-reference/get-pets"] {
+            assert_eq!(reg.get_api_redirect(old), Some("api-reference/all-pets"));
+        }
+        assert_eq!(
+            reg.get_api_redirect("api-reference/post-pets"),
+            Some("api-reference/create-pet")
+        );
+        assert_eq!(reg.get_api_redirect("api-reference/all-pets"), None);
+    }
+
+    #[test]
+    fn validate_api_examples_reports_drifted_examples_per_spec() {
+        assert!(registry().validate_api_examples().is_empty());
+
+        let spec = PETS_SPEC.replace(
+            "    get:\n      operationId: listPets",
+            "    get:\n      operationId: listPets\n      parameters:\n        \
+             - {name: limit, in: query, schema: {type: integer}, example: all}",
+        );
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_openapi("api-reference", &spec)
+            .build();
+        let violations = reg.validate_api_examples();
+        assert_eq!(violations.len(), 1);
+        let (prefix, violation) = &violations[0];
+        assert_eq!(*prefix, "api-reference");
+        assert_eq!(violation.operation, "GET /pets");
+        assert_eq!(violation.path, "$");
+    }
+
     #[test]
     fn search_ranks_title_before_description_before_content() {
         let reg = registry();
@@ -976,18 +1797,242 @@ paths:
         assert_eq!(slugs, vec!["list-pets", "create-pet"]);
         assert!(pets_entries.iter().all(|e| e.prefix == "api-reference"));
 
-        let (other_tag, other_entries) = &groups[1];
-        assert_eq!(other_tag.name, "Other");
-        assert_eq!(other_entries[0].slug, "misc-thing");
-        assert_eq!(other_entries[0].prefix, "api-reference");
+        // Untagged operations are grouped by their first path segment.
+        let (misc_tag, misc_entries) = &groups[1];
+        assert_eq!(misc_tag.name, "misc");
+        assert_eq!(misc_entries[0].slug, "misc-thing");
+        assert_eq!(misc_entries[0].prefix, "api-reference");
 
         // The second spec's untagged operation carries its own prefix.
         let (admin_tag, admin_entries) = &groups[2];
-        assert_eq!(admin_tag.name, "Other");
+        assert_eq!(admin_tag.name, "admin");
         assert_eq!(admin_entries[0].slug, "list-admin-users");
         assert_eq!(admin_entries[0].prefix, "admin-api");
     }
 
+    #[cfg(feature = "highlight")]
+    #[test]
+    fn language_aliases_are_normalized_onto_the_registry() {
+        use dioxus_code::Language;
+
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_language_alias(" RSX ", Language::Rust)
+            .with_language_alias("pgsql", Language::Markdown)
+            .with_language_alias("PgSQL", Language::Json)
+            .build();
+        assert_eq!(reg.language_aliases.len(), 2);
+        assert_eq!(reg.language_aliases.get("rsx"), Some(&Language::Rust));
+        assert_eq!(
+            reg.language_aliases.get("pgsql"),
+            Some(&Language::Json),
+            "a later alias replaces an earlier one"
+        );
+    }
+
+    #[test]
+    fn api_playground_is_opt_in() {
+        let reg = DocsConfig::new(NAV, content_map()).build();
+        assert!(!reg.api_playground);
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_api_playground(true)
+            .build();
+        assert!(reg.api_playground);
+    }
+
+    #[test]
+    fn swagger_2_specs_get_endpoint_pages_and_sidebar_entries() {
+        let swagger = r#"
+swagger: "2.0"
+info: {title: Legacy, version: "1"}
+host: legacy.example.com
+basePath: /v1
+tags: [{name: users}]
+paths:
+  /users:
+    post:
+      operationId: createUser
+      summary: Create user
+      tags: [users]
+      parameters:
+        - {name: user, in: body, required: true, schema: {type: object}}
+      responses:
+        "201": {description: Created}
+"#;
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_openapi("legacy", swagger)
+            .build();
+
+        let (tag, entries) = reg.get_api_sidebar_entries().first().unwrap();
+        assert_eq!(tag.name, "users");
+        assert_eq!(entries[0].slug, "create-user");
+        assert_eq!(entries[0].prefix, "legacy");
+
+        let (op, spec) = reg
+            .get_api_operation_with_spec("legacy/create-user")
+            .unwrap();
+        assert!(op.request_body.is_some());
+        assert_eq!(spec.servers[0].url, "https://legacy.example.com/v1");
+    }
+
+    #[test]
+    fn webhooks_get_pages_sidebar_entries_search_and_sitemap() {
+        let spec = r#"
+openapi: 3.1.0
+info: {title: Events, version: "1"}
+paths:
+  /subscriptions:
+    post:
+      operationId: subscribe
+      callbacks:
+        onEvent:
+          "{$request.body#/url}":
+            post:
+              responses:
+                "200": {description: OK}
+      responses:
+        "201": {description: Created}
+webhooks:
+  petAdopted:
+    post:
+      summary: Pet adopted
+      description: Sent when a pet finds a home.
+      responses:
+        "200": {description: OK}
+"#;
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_openapi("events", spec)
+            .build();
+
+        let (tag, entries) = reg.get_api_sidebar_entries().last().unwrap();
+        assert_eq!(tag.name, "Webhooks");
+        let slugs: Vec<&str> = entries.iter().map(|e| e.slug.as_str()).collect();
+        assert_eq!(
+            slugs,
+            ["webhooks/pet-adopted", "webhooks/subscribe-on-event"]
+        );
+        assert_eq!(entries[0].method, HttpMethod::Post);
+
+        let hook = reg.get_api_webhook("events/webhooks/pet-adopted").unwrap();
+        assert_eq!(hook.name, "petAdopted");
+        assert_eq!(
+            reg.get_page_title("events/webhooks/pet-adopted").as_deref(),
+            Some("Pet adopted")
+        );
+        assert!(
+            reg.get_api_operation("events/webhooks/pet-adopted")
+                .is_none()
+        );
+
+        let hit = reg.search_docs("adopted")[0];
+        assert_eq!(hit.path, "events/webhooks/pet-adopted");
+        assert_eq!(hit.breadcrumb, "API Reference > Webhooks");
+
+        let xml = reg.generate_sitemap("https://example.com", "/docs");
+        assert!(xml.contains("<loc>https://example.com/docs/events/webhooks/pet-adopted</loc>"));
+        assert!(xml.contains("/docs/events/webhooks/subscribe-on-event</loc>"));
+    }
+
+    #[test]
+    fn schemas_get_pages_backlinks_search_and_sitemap() {
+        let spec = r##"
+openapi: "3.0.0"
+info: {title: Pets, version: "1"}
+paths:
+  /pets/{id}:
+    get:
+      operationId: getPet
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema: {$ref: "#/components/schemas/Pet"}
+components:
+  schemas:
+    Pet:
+      type: object
+      description: A pet up for adoption.
+      properties:
+        tag: {$ref: "#/components/schemas/Tag"}
+    Tag:
+      type: string
+"##;
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_openapi("pets", spec)
+            .build();
+
+        let names: Vec<&str> = reg
+            .get_api_model_entries()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["Pet", "Tag"]);
+
+        let (name, schema) = reg.get_api_schema("pets/schemas/Pet").unwrap();
+        assert_eq!(name, "Pet");
+        assert!(schema.properties.contains_key("tag"));
+        assert!(reg.get_api_schema("pets/schemas/Missing").is_none());
+        assert_eq!(
+            reg.get_page_description("pets/schemas/Pet").as_deref(),
+            Some("A pet up for adoption.")
+        );
+
+        // Nested references count as uses.
+        let users = reg.get_api_schema_users("pets/schemas/Tag");
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].1, "pets/get-pet");
+
+        assert_eq!(reg.get_api_schema_paths()["Tag"], "pets/schemas/Tag");
+
+        let hit = reg.search_docs("adoption")[0];
+        assert_eq!(hit.path, "pets/schemas/Pet");
+        assert_eq!(hit.breadcrumb, "API Reference > Models");
+
+        let xml = reg.generate_sitemap("https://example.com", "/docs");
+        assert!(xml.contains("<loc>https://example.com/docs/pets/schemas/Pet</loc>"));
+    }
+
+    #[test]
+    fn openapi_bundles_resolve_external_refs() {
+        let root = r#"
+openapi: "3.0.0"
+info: {title: Split, version: "1"}
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema: {$ref: "./schemas/pet.yaml"}
+"#;
+        let reg = DocsConfig::new(NAV, content_map())
+            .with_openapi_bundle(
+                "api-reference",
+                "openapi.yaml",
+                &[
+                    ("openapi.yaml", root),
+                    (
+                        "schemas/pet.yaml",
+                        "type: object\nproperties:\n  name: {type: string}\n",
+                    ),
+                ],
+            )
+            .build();
+        let spec = reg.get_api_spec("api-reference").unwrap();
+        assert!(spec.schemas["pet"].properties.contains_key("name"));
+
+        let Err(err) = DocsConfig::new(NAV, content_map())
+            .with_openapi_bundle("api-reference", "openapi.yaml", &[("openapi.yaml", root)])
+            .try_build()
+        else {
+            panic!("expected a missing-file error");
+        };
+        assert!(err.to_string().contains("schemas/pet.yaml"), "{err}");
+    }
+
     #[test]
     fn operation_lookup_resolves_owning_spec() {
         let reg = registry();
@@ -1106,4 +2151,239 @@ paths:
             "bare `&` in <loc> breaks XML parsing: {xml}"
         );
     }
+
+    fn registry_with_page(page: &'static str) -> Result<DocsRegistry, DocsKitError> {
+        let mut content = content_map();
+        content.insert("getting-started/intro", page);
+        DocsConfig::new(NAV, content)
+            .with_openapi("api-reference", PETS_SPEC)
+            .with_openapi("admin-api", ADMIN_SPEC)
+            .try_build()
+    }
+
+    #[test]
+    fn api_references_resolve_inside_nested_content() {
+        let registry = registry_with_page(
+            "---\ntitle: Intro\n---\n\n<Steps>\n<Step title=\"Call it\">\n<ApiEndpoint operationId=\"listAdminUsers\" />\n</Step>\n</Steps>\n\n<Schema name=\"Pet\" />\n",
+        )
+        .unwrap();
+        let doc = registry.get_parsed_doc("getting-started/intro").unwrap();
+
+        let DocNode::Steps(steps) = &doc.content[0] else {
+            panic!("expected Steps, got {:?}", doc.content);
+        };
+        let DocNode::ApiEndpoint(endpoint) = &steps.steps[0].content[0] else {
+            panic!("expected ApiEndpoint");
+        };
+        assert_eq!(endpoint.href.as_deref(), Some("admin-api/list-admin-users"));
+        assert_eq!(
+            endpoint.operation.as_ref().unwrap().path,
+            "/admin/users".to_string()
+        );
+        assert!(matches!(&doc.content[1], DocNode::Schema(s) if s.schema.is_some()));
+
+        // Raw markdown (llms-full.txt, search) reflects the resolved data.
+        assert!(
+            doc.raw_markdown
+                .contains("**GET** `/admin/users` — List admin users")
+        );
+        assert!(doc.raw_markdown.contains("- `id` _integer_ *(required)*"));
+    }
+
+    #[test]
+    fn unknown_api_references_fail_the_build() {
+        let Err(err) = registry_with_page(
+            "---\ntitle: Intro\n---\n\n<ApiEndpoint operationId=\"listPetz\" />\n",
+        ) else {
+            panic!("expected unknown reference error");
+        };
+        assert!(matches!(err, DocsKitError::UnknownApiReference { .. }));
+        assert_eq!(
+            err.to_string(),
+            "page \"getting-started/intro\" references unknown operationId \"listPetz\""
+        );
+
+        let Err(err) = registry_with_page(
+            "---\ntitle: Intro\n---\n\n<Schema name=\"Pet\" spec=\"admin-api\" />\n",
+        ) else {
+            panic!("schema scoped to the wrong spec should not resolve");
+        };
+        assert!(
+            err.to_string()
+                .ends_with("schema \"Pet\" in spec \"admin-api\"")
+        );
+    }
+
+    #[test]
+    fn glossary_page_is_generated_searchable_and_in_sitemap() {
+        let glossary =
+            r#"[{"id": "etag", "term": "ETag", "definition": "A version tag for a resource."}]"#;
+        let nav = NAV.replacen('{', r#"{ "glossary": "reference/glossary","#, 1);
+        let reg = DocsConfig::new(&nav, content_map())
+            .with_glossary(glossary)
+            .try_build()
+            .unwrap();
+
+        assert_eq!(reg.get_glossary_path(), Some("reference/glossary"));
+        assert_eq!(reg.get_glossary()[0].id, "etag");
+        assert_eq!(
+            reg.get_doc_title("reference/glossary").as_deref(),
+            Some("Glossary")
+        );
+
+        let results = reg.search_docs("etag");
+        let hit = results
+            .iter()
+            .find(|e| e.path == "reference/glossary")
+            .expect("glossary term indexed");
+        assert_eq!(hit.anchor, "etag");
+        assert_eq!(hit.breadcrumb, "Glossary");
+
+        assert!(
+            reg.generate_sitemap("https://x.dev", "/docs")
+                .contains("<loc>https://x.dev/docs/reference/glossary</loc>")
+        );
+    }
+
+    #[test]
+    fn unknown_glossary_term_fails_the_build() {
+        let mut content = content_map();
+        content.insert(
+            "getting-started/intro",
+            "---\ntitle: Intro\n---\n\nAn <Term id=\"nope\" /> here.\n",
+        );
+        let err = DocsConfig::new(NAV, content)
+            .with_glossary("[]")
+            .try_build()
+            .err()
+            .expect("unknown term must fail");
+        assert_eq!(
+            err.to_string(),
+            "page \"getting-started/intro\" references unknown glossary term \"nope\""
+        );
+    }
+
+    #[test]
+    fn terms_without_a_glossary_are_left_as_plain_text() {
+        let reg = registry_with_page("---\ntitle: Intro\n---\n\nAn <Term id=\"nope\" /> here.\n")
+            .unwrap();
+        assert_eq!(reg.get_glossary_path(), None);
+        assert!(reg.get_glossary().is_empty());
+        let DocNode::Markdown(md) =
+            &reg.get_parsed_doc("getting-started/intro").unwrap().content[0]
+        else {
+            panic!("expected markdown");
+        };
+        assert!(md.contains("<Term id=\"nope\" />"), "got: {md}");
+    }
+
+    #[test]
+    fn data_table_rows_are_searchable_and_data_files_are_not_pages() {
+        let mut content = content_map();
+        content.insert(
+            "getting-started/intro",
+            "---\ntitle: Intro\n---\n\n## Errors\n\n<DataTable src=\"data/errors.csv\" />\n",
+        );
+        content.insert(
+            "__data__/data/errors.csv",
+            "code,message\nE1042,Rate limit exceeded\nE2001,Internal error\n",
+        );
+        let reg = DocsConfig::new(NAV, content).try_build().unwrap();
+
+        assert!(
+            !reg.get_all_paths()
+                .iter()
+                .any(|p| p.starts_with("__data__"))
+        );
+        let results = reg.search_docs("e1042");
+        let hit = results.first().expect("row indexed");
+        assert_eq!(hit.path, "getting-started/intro");
+        assert_eq!(hit.anchor, "errors-e1042");
+        assert_eq!(hit.heading, "E1042");
+        assert_eq!(hit.body, "message: Rate limit exceeded");
+    }
+
+    const EVENTS_SPEC: &str = r#"
+asyncapi: 3.0.0
+info: {title: Account events, version: "1.0.0"}
+channels:
+  userSignedUp:
+    address: user/signedup
+    description: A new account was created.
+    messages:
+      UserSignedUp:
+        payload:
+          type: object
+          properties:
+            email: {type: string, format: email}
+operations:
+  onUserSignedUp:
+    action: receive
+    summary: User signed up
+    channel: {$ref: '#/channels/userSignedUp'}
+"#;
+
+    #[test]
+    fn asyncapi_channels_get_pages_sidebar_entries_and_search() {
+        let nav = NAV.replace(
+            r#"{ "group": "API Reference""#,
+            r#"{ "group": "Events", "tab": "API Reference", "pages": [] },
+            { "group": "API Reference""#,
+        );
+        let reg = DocsConfig::new(&nav, content_map())
+            .with_asyncapi("events", EVENTS_SPEC)
+            .build();
+
+        let (channel, spec) = reg
+            .get_asyncapi_channel_with_spec("events/user-signed-up")
+            .expect("channel page");
+        assert_eq!(channel.address, "user/signedup");
+        assert_eq!(spec.info.title, "Account events");
+        assert_eq!(
+            reg.get_page_title("events/user-signed-up").as_deref(),
+            Some("User signed up")
+        );
+        assert_eq!(
+            reg.get_page_description("events/user-signed-up").as_deref(),
+            Some("A new account was created.")
+        );
+        assert_eq!(
+            reg.tab_for_path("events/user-signed-up").as_deref(),
+            Some("API Reference")
+        );
+
+        let groups = reg.get_asyncapi_sidebar_entries();
+        assert_eq!(groups.len(), 1);
+        let (title, entries) = &groups[0];
+        assert_eq!(title, "Account events");
+        assert_eq!(entries[0].slug, "user-signed-up");
+        assert_eq!(entries[0].actions, [OperationAction::Receive]);
+
+        let results = reg.search_docs("signedup");
+        let hit = results.first().expect("channel indexed");
+        assert_eq!(hit.path, "events/user-signed-up");
+        assert_eq!(hit.breadcrumb, "Events > Account events");
+        assert!(
+            reg.generate_sitemap("https://example.com", "/docs")
+                .contains("/docs/events/user-signed-up")
+        );
+    }
+
+    #[test]
+    fn try_build_reports_asyncapi_error_with_prefix() {
+        let Err(err) = DocsConfig::new(NAV, content_map())
+            .with_asyncapi("events", "asyncapi: 1.0.0")
+            .try_build()
+        else {
+            panic!("expected AsyncAPI parse error");
+        };
+        assert!(matches!(
+            err,
+            DocsKitError::AsyncApi {
+                error: dioxus_mdx::AsyncApiError::InvalidSpec(_),
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("prefix \"events\""));
+    }
 }
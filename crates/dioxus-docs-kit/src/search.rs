@@ -299,7 +299,8 @@ pub(crate) fn clean_markdown(md: &str) -> String {
         no_code.push('\n');
     }
 
-    // Collapse `[text](url)` to `text`, strip the noisiest inline markers, and
+    // Collapse `[text](url)` to `text`, drop `[^note]` footnote markers (and
+    // the colon of their definitions), strip the noisiest inline markers, and
     // squeeze runs of whitespace to a single space.
     let mut out = String::with_capacity(no_code.len());
     let mut prev_space = false;
@@ -313,6 +314,12 @@ pub(crate) fn clean_markdown(md: &str) -> String {
                 }
                 text.push(tc);
             }
+            if text.starts_with('^') {
+                if chars.peek() == Some(&':') {
+                    chars.next();
+                }
+                continue;
+            }
             if chars.peek() == Some(&'(') {
                 chars.next();
                 for uc in chars.by_ref() {
@@ -507,4 +514,10 @@ mod tests {
         assert!(!cleaned.contains("https://"), "link target dropped");
         assert!(!cleaned.contains('\n'), "whitespace collapsed");
     }
+
+    #[test]
+    fn clean_markdown_drops_footnote_markers() {
+        let cleaned = clean_markdown("Cached[^ttl] responses.\n\n[^ttl]: Five minutes.");
+        assert_eq!(cleaned, "Cached responses. Five minutes.");
+    }
 }
@@ -17,7 +17,7 @@
 //! });
 //! ```
 
-use dioxus::server::axum::{Router, http::header, routing::get};
+use dioxus::server::axum::{Router, http::header, response::Redirect, routing::get};
 
 use crate::blog::BlogRegistry;
 use crate::components::seo::xml_escape;
@@ -109,6 +109,16 @@ impl SeoRouter {
                 }
             }
 
+            // Previous endpoint URLs (see `with_api_slug_redirects`) answer
+            // with a permanent redirect, so crawlers move their index over.
+            for (from, to) in docs.get_api_redirects() {
+                let location = format!("{base}/{to}");
+                router = router.route(
+                    &format!("{base}/{from}"),
+                    get(move || async move { Redirect::permanent(&location) }),
+                );
+            }
+
             let docs_base_url = format!("{site_url}{base}");
 
             let llms =
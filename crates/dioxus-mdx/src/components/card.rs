@@ -134,7 +134,7 @@ pub fn DocCard(props: DocCardProps) -> Element {
 }
 
 /// Convert Mintlify-style doc paths to internal routing.
-fn convert_doc_href(href: &str, base_path: &str) -> String {
+pub(crate) fn convert_doc_href(href: &str, base_path: &str) -> String {
     // If the href already starts with the base path, return as-is
     if href.starts_with(base_path) {
         return href.to_string();
@@ -2,6 +2,11 @@
 //!
 //! Features syntax highlighting for common programming languages.
 
+#[cfg(feature = "highlight")]
+use std::collections::HashMap;
+#[cfg(feature = "highlight")]
+use std::sync::Arc;
+
 use dioxus::prelude::*;
 #[cfg(feature = "highlight")]
 use dioxus_code::{Code, CodeTheme, Language, SourceCode, Theme};
@@ -26,6 +31,25 @@ use crate::parser::{CodeBlockNode, CodeGroupNode};
 #[derive(Clone, Copy)]
 pub struct CodeThemeOverride(pub ReadSignal<CodeTheme>);
 
+/// Extra fence-language aliases for syntax highlighting.
+///
+/// Provide this context above [`MdxContent`](crate::MdxContent) to map fence
+/// names the built-in table doesn't know, e.g. ```` ```rsx ```` to Rust.
+/// Keys are matched case-insensitively and take precedence over the built-in
+/// aliases.
+///
+/// Only available with the `highlight` feature (default), which pulls in `dioxus-code`.
+#[cfg(feature = "highlight")]
+#[derive(Clone, Default)]
+pub struct LanguageAliases(pub Arc<HashMap<String, Language>>);
+
+#[cfg(feature = "highlight")]
+impl LanguageAliases {
+    fn get(&self, language: &str) -> Option<Language> {
+        self.0.get(&language.trim().to_ascii_lowercase()).cloned()
+    }
+}
+
 /// Props for DocCodeBlock component.
 #[derive(Props, Clone, PartialEq)]
 pub struct DocCodeBlockProps {
@@ -179,7 +203,12 @@ struct HighlightedCodeProps {
 #[cfg(feature = "highlight")]
 #[component]
 fn HighlightedCode(props: HighlightedCodeProps) -> Element {
-    let language = code_language(props.language.as_deref(), props.filename.as_deref());
+    let aliases = try_use_context::<LanguageAliases>();
+    let language = code_language(
+        props.language.as_deref(),
+        props.filename.as_deref(),
+        aliases.as_ref(),
+    );
     let theme = match try_use_context::<CodeThemeOverride>() {
         Some(CodeThemeOverride(theme)) => theme(),
         None => CodeTheme::system(Theme::GITHUB_LIGHT, Theme::TOKYO_NIGHT),
@@ -229,9 +258,14 @@ pub(crate) fn plain_code_block(code: &str) -> Element {
 }
 
 #[cfg(feature = "highlight")]
-pub(crate) fn code_language(language: Option<&str>, filename: Option<&str>) -> Language {
+pub(crate) fn code_language(
+    language: Option<&str>,
+    filename: Option<&str>,
+    aliases: Option<&LanguageAliases>,
+) -> Language {
     language
-        .and_then(language_from_alias)
+        .and_then(|l| aliases.and_then(|aliases| aliases.get(l)))
+        .or_else(|| language.and_then(language_from_alias))
         .or_else(|| filename.and_then(Language::detect))
         .or_else(|| language.and_then(Language::detect))
         .unwrap_or(Language::Markdown)
@@ -245,12 +279,15 @@ fn language_from_alias(language: &str) -> Option<Language> {
         "c++" | "cc" | "cxx" | "hpp" => Some(Language::Cpp),
         "c#" | "cs" => Some(Language::CSharp),
         "docker" | "dockerfile" | "containerfile" => Some(Language::Dockerfile),
+        "golang" => Language::from_slug("go"),
+        "gql" => Language::from_slug("graphql"),
         "html" | "htm" => Some(Language::Html),
         "js" | "javascript" | "jsx" | "mjs" | "cjs" => Some(Language::JavaScript),
         "json" | "jsonc" => Some(Language::Json),
         "markdown" | "md" | "mdx" => Some(Language::Markdown),
         "py" | "python" => Some(Language::Python),
         "rs" | "rust" => Some(Language::Rust),
+        "psql" | "postgres" | "postgresql" | "mysql" | "sqlite" => Language::from_slug("sql"),
         "ts" | "typescript" => Some(Language::TypeScript),
         "tsx" => Some(Language::Tsx),
         "toml" => Some(Language::Toml),
@@ -261,14 +298,14 @@ fn language_from_alias(language: &str) -> Option<Language> {
 
 /// Props for CopyButton.
 #[derive(Props, Clone, PartialEq)]
-struct CopyButtonProps {
-    code: String,
-    copied: Signal<bool>,
+pub(crate) struct CopyButtonProps {
+    pub(crate) code: String,
+    pub(crate) copied: Signal<bool>,
 }
 
 /// Copy to clipboard button.
 #[component]
-fn CopyButton(props: CopyButtonProps) -> Element {
+pub(crate) fn CopyButton(props: CopyButtonProps) -> Element {
     #[allow(unused_mut)]
     let mut copied = props.copied;
     let code = props.code.clone();
@@ -304,3 +341,35 @@ fn CopyButton(props: CopyButtonProps) -> Element {
         }
     }
 }
+
+#[cfg(all(test, feature = "highlight"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_aliases_take_precedence_over_built_in_ones() {
+        let aliases = LanguageAliases(Arc::new(HashMap::from([
+            ("rsx".to_string(), Language::Rust),
+            ("js".to_string(), Language::TypeScript),
+        ])));
+
+        assert_eq!(
+            code_language(Some(" RSX "), None, Some(&aliases)),
+            Language::Rust
+        );
+        assert_eq!(
+            code_language(Some("js"), None, Some(&aliases)),
+            Language::TypeScript
+        );
+        assert_eq!(
+            code_language(Some("yml"), None, Some(&aliases)),
+            Language::Yaml,
+            "unmapped fences fall back to the built-in table"
+        );
+        assert_eq!(
+            code_language(Some("rsx"), None, None),
+            Language::Markdown,
+            "unknown fences without aliases render as plain markdown"
+        );
+    }
+}
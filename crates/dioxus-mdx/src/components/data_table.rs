@@ -0,0 +1,243 @@
+//! Data table component for CSV/JSON-backed tables.
+
+use std::cmp::Ordering;
+
+use dioxus::prelude::*;
+use dioxus_free_icons::{Icon, icons::ld_icons::*};
+
+use crate::parser::{ColumnType, DataColumn, DataTableNode, parse_bool};
+
+/// Props for DocDataTable component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocDataTableProps {
+    /// Data table node, with `data` filled in by the host.
+    pub table: DataTableNode,
+}
+
+/// Table with a filter box and click-to-sort column headers.
+///
+/// Each row carries an id from [`DataTableNode::row_anchors`], so search
+/// results can deep-link to it.
+#[component]
+pub fn DocDataTable(props: DocDataTableProps) -> Element {
+    let mut filter = use_signal(String::new);
+    // (column index, descending)
+    let mut sort = use_signal(|| None::<(usize, bool)>);
+
+    let Some(data) = &props.table.data else {
+        return rsx! {
+            div { class: "not-prose my-4 px-4 py-3 rounded-lg border border-dashed border-base-content/20 text-sm text-base-content/60",
+                "Unresolved data table "
+                code { class: "font-mono", "{props.table.src}" }
+            }
+        };
+    };
+
+    let query = filter().to_lowercase();
+    let mut visible: Vec<usize> = (0..data.rows.len())
+        .filter(|&i| {
+            query.split_whitespace().all(|term| {
+                data.rows[i]
+                    .iter()
+                    .any(|cell| cell.to_lowercase().contains(term))
+            })
+        })
+        .collect();
+    if let Some((col, descending)) = sort() {
+        let column_type = data.columns[col].column_type;
+        visible.sort_by(|&a, &b| {
+            let ordering = compare_cells(&data.rows[a][col], &data.rows[b][col], column_type);
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+    let total = data.rows.len();
+    let shown = visible.len();
+    let anchors = props.table.row_anchors();
+
+    rsx! {
+        div { class: "dk-data-table not-prose my-6",
+            if props.table.filterable {
+                div { class: "flex items-center gap-3 mb-3",
+                    label { class: "input input-sm input-bordered flex items-center gap-2 w-full max-w-xs",
+                        Icon { class: "size-4 text-base-content/50", icon: LdSearch }
+                        input {
+                            class: "grow",
+                            r#type: "search",
+                            placeholder: "Filter rows…",
+                            value: "{filter}",
+                            oninput: move |e| filter.set(e.value()),
+                        }
+                    }
+                    if shown != total {
+                        span { class: "text-xs text-base-content/60", "{shown} of {total} rows" }
+                    }
+                }
+            }
+            div { class: "overflow-x-auto rounded-lg border border-base-content/10",
+                table { class: "table table-sm",
+                    thead {
+                        tr { class: "bg-base-200/50",
+                            for (i, column) in data.columns.iter().enumerate() {
+                                HeaderCell {
+                                    key: "{i}",
+                                    column: column.clone(),
+                                    sortable: props.table.sortable,
+                                    direction: sort().and_then(|(col, desc)| (col == i).then_some(desc)),
+                                    on_sort: move |_| {
+                                        let next = match sort() {
+                                            Some((col, false)) if col == i => Some((i, true)),
+                                            Some((col, true)) if col == i => None,
+                                            _ => Some((i, false)),
+                                        };
+                                        sort.set(next);
+                                    },
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        for i in visible {
+                            tr {
+                                key: "{i}",
+                                id: anchors[i].clone(),
+                                class: "scroll-mt-24 target:bg-primary/10",
+                                for (cell, column) in data.rows[i].iter().zip(&data.columns) {
+                                    Cell { cell: cell.clone(), column_type: column.column_type }
+                                }
+                            }
+                        }
+                        if shown == 0 {
+                            tr {
+                                td {
+                                    colspan: "{data.columns.len()}",
+                                    class: "text-center text-base-content/60 py-6",
+                                    "No matching rows"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Sort order of two cells, numeric for number columns.
+fn compare_cells(a: &str, b: &str, column_type: ColumnType) -> Ordering {
+    match column_type {
+        ColumnType::Number => {
+            let parse = |s: &str| s.trim().parse::<f64>().ok();
+            match (parse(a), parse(b)) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                // Empty cells sort last.
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        }
+        ColumnType::Boolean => parse_bool(a).cmp(&parse_bool(b)),
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+/// Props for HeaderCell.
+#[derive(Props, Clone, PartialEq)]
+struct HeaderCellProps {
+    column: DataColumn,
+    sortable: bool,
+    /// `Some(descending)` when the table is sorted by this column.
+    direction: Option<bool>,
+    on_sort: EventHandler<()>,
+}
+
+#[component]
+fn HeaderCell(props: HeaderCellProps) -> Element {
+    let align = if props.column.column_type == ColumnType::Number {
+        "text-right"
+    } else {
+        "text-left"
+    };
+    let aria_sort = match props.direction {
+        Some(false) => "ascending",
+        Some(true) => "descending",
+        None => "none",
+    };
+
+    if !props.sortable {
+        return rsx! {
+            th { class: "{align} text-base-content/70", "{props.column.name}" }
+        };
+    }
+
+    rsx! {
+        th { class: "{align}", "aria-sort": aria_sort,
+            button {
+                class: "inline-flex items-center gap-1 font-semibold text-base-content/70 hover:text-base-content transition-colors cursor-pointer",
+                onclick: move |_| props.on_sort.call(()),
+                "{props.column.name}"
+                match props.direction {
+                    Some(false) => rsx! { Icon { class: "size-3.5", icon: LdChevronUp } },
+                    Some(true) => rsx! { Icon { class: "size-3.5", icon: LdChevronDown } },
+                    None => rsx! { Icon { class: "size-3.5 opacity-40", icon: LdChevronsUpDown } },
+                }
+            }
+        }
+    }
+}
+
+/// A cell formatted for its column type.
+#[component]
+fn Cell(cell: String, column_type: ColumnType) -> Element {
+    if cell.is_empty() {
+        return rsx! {
+            td {}
+        };
+    }
+
+    match column_type {
+        ColumnType::Text => rsx! {
+            td { class: "whitespace-pre-line", "{cell}" }
+        },
+        ColumnType::Number => rsx! {
+            td { class: "text-right tabular-nums", "{cell}" }
+        },
+        ColumnType::Boolean => match parse_bool(&cell) {
+            Some(true) => rsx! {
+                td {
+                    Icon { class: "size-4 text-success", icon: LdCheck }
+                    span { class: "sr-only", "{cell}" }
+                }
+            },
+            Some(false) => rsx! {
+                td {
+                    Icon { class: "size-4 text-base-content/30", icon: LdMinus }
+                    span { class: "sr-only", "{cell}" }
+                }
+            },
+            None => rsx! {
+                td { "{cell}" }
+            },
+        },
+        ColumnType::Date => rsx! {
+            td { class: "whitespace-nowrap tabular-nums",
+                time { datetime: "{cell}", "{cell}" }
+            }
+        },
+        ColumnType::Link => rsx! {
+            td {
+                a { class: "link link-primary break-all", href: "{cell}", "{cell}" }
+            }
+        },
+        ColumnType::Code => rsx! {
+            td {
+                code { class: "font-mono text-xs px-1.5 py-0.5 rounded bg-base-200 text-base-content whitespace-nowrap",
+                    "{cell}"
+                }
+            }
+        },
+    }
+}
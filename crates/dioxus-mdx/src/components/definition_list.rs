@@ -0,0 +1,38 @@
+//! Definition list component.
+
+use dioxus::prelude::*;
+
+use super::inline::{GlossaryContext, inline_markdown_to_html, markdown_to_html};
+use crate::parser::DefinitionListNode;
+
+/// Props for DocDefinitionList component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocDefinitionListProps {
+    /// Definition list data.
+    pub list: DefinitionListNode,
+}
+
+/// Terms with their definitions.
+#[component]
+pub fn DocDefinitionList(props: DocDefinitionListProps) -> Element {
+    let glossary = try_use_context::<GlossaryContext>();
+
+    rsx! {
+        dl { class: "dk-deflist my-6 space-y-4",
+            for (i, item) in props.list.items.iter().enumerate() {
+                div { key: "{i}",
+                    dt {
+                        class: "font-semibold text-base-content",
+                        dangerous_inner_html: inline_markdown_to_html(&item.term, glossary.as_ref()),
+                    }
+                    for definition in item.definitions.iter() {
+                        dd {
+                            class: "prose-content ms-6 mt-1 text-base-content/80",
+                            dangerous_inner_html: markdown_to_html(definition, glossary.as_ref()),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! FileTree component for directory layouts.
+
+use dioxus::prelude::*;
+use dioxus_free_icons::{Icon, icons::ld_icons::*};
+
+use crate::components::MdxIcon;
+use crate::parser::{FileTreeEntry, FileTreeNode};
+
+/// Props for DocFileTree component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocFileTreeProps {
+    /// File tree data.
+    pub tree: FileTreeNode,
+}
+
+/// Directory layout with collapsible folders.
+#[component]
+pub fn DocFileTree(props: DocFileTreeProps) -> Element {
+    rsx! {
+        div { class: "dk-file-tree not-prose my-6 rounded-lg border border-base-content/10 bg-base-200/50 px-3 py-2.5 font-mono text-sm",
+            ul { role: "tree", class: "space-y-0.5",
+                for (i, entry) in props.tree.entries.iter().enumerate() {
+                    FileTreeItem { key: "{i}", entry: entry.clone() }
+                }
+            }
+        }
+    }
+}
+
+/// Props for FileTreeItem.
+#[derive(Props, Clone, PartialEq)]
+struct FileTreeItemProps {
+    entry: FileTreeEntry,
+}
+
+/// A single file, or a folder with its (collapsible) children.
+#[component]
+fn FileTreeItem(props: FileTreeItemProps) -> Element {
+    let mut open = use_signal(|| true);
+    let entry = &props.entry;
+    let name_class = if entry.highlighted {
+        "font-semibold text-primary"
+    } else {
+        "text-base-content/80"
+    };
+    let row_class = if entry.highlighted {
+        "flex items-center gap-1.5 w-full px-1.5 py-0.5 rounded text-left bg-primary/10"
+    } else {
+        "flex items-center gap-1.5 w-full px-1.5 py-0.5 rounded text-left hover:bg-base-content/5 transition-colors"
+    };
+
+    if !entry.is_dir {
+        return rsx! {
+            li { role: "treeitem",
+                div { class: row_class,
+                    // Spacer keeps file names aligned with folder names.
+                    span { class: "size-3.5 shrink-0" }
+                    MdxIcon { name: entry.icon_name(false).to_string(), class: "size-4 shrink-0 text-base-content/50".to_string() }
+                    span { class: name_class, "{entry.name}" }
+                }
+            }
+        };
+    }
+
+    rsx! {
+        li { role: "treeitem", aria_expanded: "{open()}",
+            button {
+                class: row_class,
+                onclick: move |_| open.set(!open()),
+                Icon {
+                    class: if open() {
+                        "size-3.5 shrink-0 text-base-content/40 rotate-90 transition-transform duration-150"
+                    } else {
+                        "size-3.5 shrink-0 text-base-content/40 transition-transform duration-150"
+                    },
+                    icon: LdChevronRight,
+                }
+                MdxIcon { name: entry.icon_name(open()).to_string(), class: "size-4 shrink-0 text-primary/80".to_string() }
+                span { class: name_class, "{entry.name}" }
+            }
+            if open() && !entry.children.is_empty() {
+                ul { role: "group", class: "ml-3 pl-2.5 border-l border-base-content/10 space-y-0.5",
+                    for (i, child) in entry.children.iter().enumerate() {
+                        FileTreeItem { key: "{i}", entry: child.clone() }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Footnotes section.
+
+use dioxus::prelude::*;
+
+use super::inline::{GlossaryContext, markdown_to_html};
+use crate::parser::FootnotesNode;
+
+/// Props for DocFootnotes component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocFootnotesProps {
+    /// The page's footnotes.
+    pub footnotes: FootnotesNode,
+}
+
+/// Numbered footnotes with links back to their first reference.
+#[component]
+pub fn DocFootnotes(props: DocFootnotesProps) -> Element {
+    let glossary = try_use_context::<GlossaryContext>();
+    let namespace = &props.footnotes.namespace;
+
+    rsx! {
+        section { class: "dk-footnotes not-prose mt-12 pt-6 border-t border-base-content/10 text-sm text-base-content/70",
+            h2 { id: "footnotes", class: "mb-3 text-sm font-semibold text-base-content", "Footnotes" }
+            ol { class: "list-decimal ps-6 space-y-2",
+                for item in props.footnotes.items.iter() {
+                    {
+                        let anchor = FootnotesNode::anchor(namespace, &item.label);
+                        let html = markdown_to_html(&item.content, glossary.as_ref());
+                        rsx! {
+                            li { key: "{anchor}", id: "fn-{anchor}", class: "scroll-mt-24",
+                                div { class: "prose-content inline [&>p]:inline", dangerous_inner_html: html }
+                                " "
+                                a {
+                                    class: "link link-hover text-primary",
+                                    href: "#fnref-{anchor}",
+                                    aria_label: "Back to reference {item.number}",
+                                    "↩"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
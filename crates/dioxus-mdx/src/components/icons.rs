@@ -24,7 +24,11 @@ pub fn MdxIcon(
         "brain-circuit" | "brain" => rsx! { Icon { class: icon_class, icon: LdBrainCircuit } },
         "folder" => rsx! { Icon { class: icon_class, icon: LdFolder } },
         "list" => rsx! { Icon { class: icon_class, icon: LdList } },
+        "folder-open" => rsx! { Icon { class: icon_class, icon: LdFolderOpen } },
         "file" => rsx! { Icon { class: icon_class, icon: LdFile } },
+        "file-code" => rsx! { Icon { class: icon_class, icon: LdFileCode } },
+        "file-text" => rsx! { Icon { class: icon_class, icon: LdFileText } },
+        "file-cog" => rsx! { Icon { class: icon_class, icon: LdFileCog } },
         "plus" | "plus-circle" => rsx! { Icon { class: icon_class, icon: LdPlus } },
         "pen" | "pencil" => rsx! { Icon { class: icon_class, icon: LdPencil } },
         "trash" | "trash-alt" => rsx! { Icon { class: icon_class, icon: LdTrash } },
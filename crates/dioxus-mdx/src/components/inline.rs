@@ -0,0 +1,228 @@
+//! Inline tags inside markdown text.
+//!
+//! `<Term>`, `<FootnoteRef>` and `<Abbr>` tags stay inside markdown text, so
+//! they are rendered as HTML spliced into the converted markdown rather than
+//! as components.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use super::slugify;
+use crate::parser::{FootnotesNode, GlossaryEntry, NoteTag, TermRef, find_note_tags, find_terms};
+
+/// Glossary available to `<Term>` tooltips.
+///
+/// Provide this context above [`MdxContent`](crate::MdxContent) to resolve
+/// term ids to definitions. Without it, terms render as plain text.
+#[derive(Clone, PartialEq)]
+pub struct GlossaryContext {
+    /// Known terms.
+    pub entries: Arc<Vec<GlossaryEntry>>,
+    /// URL of the glossary page; term links point at `<href>#<term-slug>`.
+    pub href: Option<String>,
+}
+
+impl GlossaryContext {
+    fn entry(&self, id: &str) -> Option<&GlossaryEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+}
+
+/// An inline tag found in markdown text.
+enum InlineTag {
+    Term(TermRef),
+    Note(NoteTag),
+}
+
+/// Convert markdown containing inline tags to HTML.
+pub(crate) fn markdown_to_html(md: &str, glossary: Option<&GlossaryContext>) -> String {
+    let mut tags: Vec<(Range<usize>, InlineTag)> = find_terms(md)
+        .into_iter()
+        .map(|term| (term.start..term.end, InlineTag::Term(term)))
+        .chain(
+            find_note_tags(md)
+                .into_iter()
+                .map(|(range, tag)| (range, InlineTag::Note(tag))),
+        )
+        .collect();
+    let options = markdown::Options::gfm();
+    if tags.is_empty() {
+        return markdown::to_html_with_options(md, &options).unwrap_or_else(|_| md.to_string());
+    }
+    tags.sort_by_key(|(range, _)| range.start);
+
+    // Swap each tag for a private-use placeholder that survives markdown
+    // conversion, then splice the tag HTML back in.
+    let mut source = String::with_capacity(md.len());
+    let mut last = 0;
+    for (i, (range, _)) in tags.iter().enumerate() {
+        source.push_str(&md[last..range.start]);
+        source.push_str(&placeholder(i));
+        last = range.end;
+    }
+    source.push_str(&md[last..]);
+
+    let mut html =
+        markdown::to_html_with_options(&source, &options).unwrap_or_else(|_| source.clone());
+    for (i, (_, tag)) in tags.iter().enumerate() {
+        let tag_html = match tag {
+            InlineTag::Term(term) => term_html(term, glossary),
+            InlineTag::Note(note) => note_html(note),
+        };
+        html = html.replace(&placeholder(i), &tag_html);
+    }
+    html
+}
+
+/// Convert a single line of markdown to HTML without the wrapping `<p>`.
+pub(crate) fn inline_markdown_to_html(md: &str, glossary: Option<&GlossaryContext>) -> String {
+    let html = markdown_to_html(md, glossary);
+    let trimmed = html.trim();
+    trimmed
+        .strip_prefix("<p>")
+        .and_then(|inner| inner.strip_suffix("</p>"))
+        .filter(|inner| !inner.contains("<p>"))
+        .unwrap_or(trimmed)
+        .to_string()
+}
+
+fn placeholder(i: usize) -> String {
+    format!("\u{E000}{i}\u{E001}")
+}
+
+fn note_html(note: &NoteTag) -> String {
+    match note {
+        NoteTag::FootnoteRef {
+            namespace,
+            label,
+            number,
+            repeat,
+        } => {
+            let anchor = FootnotesNode::anchor(namespace, label);
+            // Only the first reference gets the id the footnote links back to.
+            let id = if *repeat {
+                String::new()
+            } else {
+                format!(" id=\"fnref-{anchor}\"")
+            };
+            format!(
+                "<sup class=\"dk-fnref\"><a{id} href=\"#fn-{anchor}\" aria-describedby=\"footnotes\">{number}</a></sup>"
+            )
+        }
+        NoteTag::Abbr { abbr, title } => format!(
+            "<abbr class=\"dk-abbr cursor-help\" title=\"{}\">{}</abbr>",
+            escape(title),
+            escape(abbr)
+        ),
+    }
+}
+
+fn term_html(term: &TermRef, glossary: Option<&GlossaryContext>) -> String {
+    let entry = glossary.and_then(|g| g.entry(&term.id));
+    let label = term
+        .label
+        .as_deref()
+        .or(entry.map(|e| e.term.as_str()))
+        .unwrap_or(&term.id);
+
+    let Some(entry) = entry else {
+        return format!("<span class=\"dk-term\">{}</span>", escape(label));
+    };
+
+    let inner = match glossary.and_then(|g| g.href.as_deref()) {
+        Some(href) => format!(
+            "<a class=\"dk-term-link\" href=\"{}#{}\">{}</a>",
+            escape(href),
+            slugify(&entry.term),
+            escape(label)
+        ),
+        None => escape(label),
+    };
+    format!(
+        "<span class=\"dk-term tooltip tooltip-bottom underline decoration-dotted underline-offset-4 cursor-help\" data-tip=\"{}\">{inner}</span>",
+        escape(&entry.definition)
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glossary() -> GlossaryContext {
+        GlossaryContext {
+            entries: Arc::new(vec![GlossaryEntry {
+                id: "idempotency".to_string(),
+                term: "Idempotency".to_string(),
+                definition: "Repeating a request has the same effect as sending it once."
+                    .to_string(),
+                aliases: vec!["idempotent".to_string()],
+            }]),
+            href: Some("/docs/glossary".to_string()),
+        }
+    }
+
+    #[test]
+    fn known_terms_render_tooltip_links() {
+        let html = markdown_to_html(
+            "PUT is <Term id=\"idempotency\">idempotent</Term>, see <Term id=\"idempotency\" />.",
+            Some(&glossary()),
+        );
+        assert!(
+            html.contains("data-tip=\"Repeating a request"),
+            "got: {html}"
+        );
+        assert!(
+            html.contains(
+                "<a class=\"dk-term-link\" href=\"/docs/glossary#idempotency\">idempotent</a>"
+            ),
+            "got: {html}"
+        );
+        assert!(
+            html.contains(">Idempotency</a>"),
+            "self-closing uses the term name: {html}"
+        );
+        assert!(html.starts_with("<p>PUT is "), "got: {html}");
+    }
+
+    #[test]
+    fn unknown_terms_and_missing_context_render_plain_labels() {
+        let html = markdown_to_html("An <Term id=\"nope\">odd</Term> word.", Some(&glossary()));
+        assert!(
+            html.contains("<span class=\"dk-term\">odd</span>"),
+            "got: {html}"
+        );
+        let html = markdown_to_html("<Term id=\"idempotency\" />", None);
+        assert!(
+            html.contains("<span class=\"dk-term\">idempotency</span>"),
+            "got: {html}"
+        );
+    }
+
+    #[test]
+    fn footnote_refs_and_abbreviations_render_inline() {
+        let html = markdown_to_html(
+            "The <Abbr title=\"Application Programming Interface\">API</Abbr> caches<FootnoteRef ns=\"abc123\" label=\"ttl\" n=\"1\" /> twice<FootnoteRef ns=\"abc123\" label=\"ttl\" n=\"1\" repeat />.",
+            None,
+        );
+        assert!(
+            html.contains("<abbr class=\"dk-abbr cursor-help\" title=\"Application Programming Interface\">API</abbr>"),
+            "got: {html}"
+        );
+        assert!(
+            html.contains("<a id=\"fnref-abc123-ttl\" href=\"#fn-abc123-ttl\""),
+            "got: {html}"
+        );
+        assert_eq!(
+            html.matches("id=\"fnref-").count(),
+            1,
+            "repeat refs carry no id"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Video and embed components.
+//!
+//! YouTube and generic embeds start as a static facade: nothing is requested
+//! from the third party until the reader clicks to load the iframe.
+
+use dioxus::prelude::*;
+use dioxus_free_icons::{Icon, icons::ld_icons::*};
+
+use crate::parser::{EmbedNode, VideoNode, YouTubeNode};
+
+/// Props for DocVideo component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocVideoProps {
+    /// Video data.
+    pub video: VideoNode,
+}
+
+/// Native `<video>` player for self-hosted files.
+#[component]
+pub fn DocVideo(props: DocVideoProps) -> Element {
+    let video = &props.video;
+
+    rsx! {
+        figure { class: "dk-video not-prose my-6",
+            video {
+                class: "w-full rounded-lg border border-base-content/10 bg-base-300",
+                src: "{video.src}",
+                poster: video.poster.clone(),
+                title: video.title.clone(),
+                controls: true,
+                preload: "metadata",
+                if let Some(captions) = &video.captions {
+                    track {
+                        kind: "captions",
+                        src: "{captions}",
+                        srclang: "{video.captions_lang}",
+                        label: "Captions",
+                        default: true,
+                    }
+                }
+                a { href: "{video.src}", "Download the video" }
+            }
+            if let Some(title) = &video.title {
+                figcaption { class: "mt-2 text-sm text-center text-base-content/60", "{title}" }
+            }
+        }
+    }
+}
+
+/// Props for DocYouTube component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocYouTubeProps {
+    /// YouTube video data.
+    pub video: YouTubeNode,
+}
+
+/// YouTube player behind a click-to-load facade (youtube-nocookie.com).
+#[component]
+pub fn DocYouTube(props: DocYouTubeProps) -> Element {
+    let mut loaded = use_signal(|| false);
+    let video = &props.video;
+    let title = video
+        .title
+        .clone()
+        .unwrap_or_else(|| "YouTube video".to_string());
+
+    rsx! {
+        div { class: "dk-embed not-prose my-6 aspect-video w-full overflow-hidden rounded-lg border border-base-content/10",
+            if loaded() {
+                iframe {
+                    class: "size-full",
+                    src: "{video.embed_url()}",
+                    title: "{title}",
+                    allow: "accelerometer; autoplay; clipboard-write; encrypted-media; gyroscope; picture-in-picture",
+                    allowfullscreen: true,
+                }
+            } else {
+                EmbedFacade {
+                    title: title.clone(),
+                    host: "youtube-nocookie.com",
+                    link: video.watch_url(),
+                    on_load: move |_| loaded.set(true),
+                }
+            }
+        }
+    }
+}
+
+/// Props for DocEmbed component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocEmbedProps {
+    /// Embed data.
+    pub embed: EmbedNode,
+}
+
+/// Arbitrary iframe behind a click-to-load facade.
+#[component]
+pub fn DocEmbed(props: DocEmbedProps) -> Element {
+    let mut loaded = use_signal(|| false);
+    let embed = &props.embed;
+    let title = embed
+        .title
+        .clone()
+        .unwrap_or_else(|| "Embedded content".to_string());
+    let height = embed.height.unwrap_or(400);
+
+    rsx! {
+        div {
+            class: "dk-embed not-prose my-6 w-full overflow-hidden rounded-lg border border-base-content/10",
+            style: "height: {height}px",
+            if loaded() {
+                iframe {
+                    class: "size-full",
+                    src: "{embed.src}",
+                    title: "{title}",
+                    allowfullscreen: true,
+                }
+            } else {
+                EmbedFacade {
+                    title: title.clone(),
+                    host: embed.host().to_string(),
+                    link: embed.src.clone(),
+                    on_load: move |_| loaded.set(true),
+                }
+            }
+        }
+    }
+}
+
+/// Static placeholder: names the third party and loads it only on request.
+#[component]
+fn EmbedFacade(title: String, host: String, link: String, on_load: EventHandler<()>) -> Element {
+    rsx! {
+        div { class: "size-full flex flex-col items-center justify-center gap-3 p-6 bg-base-200 text-center",
+            button {
+                class: "btn btn-circle btn-lg btn-primary",
+                aria_label: "Load {title}",
+                onclick: move |_| on_load.call(()),
+                Icon { class: "size-6", icon: LdPlay }
+            }
+            p { class: "font-semibold text-base-content", "{title}" }
+            p { class: "text-xs text-base-content/60",
+                "Loads content from {host}. "
+                a {
+                    class: "link inline-flex items-center gap-1",
+                    href: "{link}",
+                    target: "_blank",
+                    rel: "noopener noreferrer",
+                    "Open in a new tab"
+                    Icon { class: "size-3", icon: LdExternalLink }
+                }
+            }
+        }
+    }
+}
@@ -27,7 +27,13 @@ mod api_examples;
 mod callout;
 mod card;
 mod code;
+mod data_table;
+mod definition_list;
+mod file_tree;
+mod footnotes;
 mod icons;
+mod inline;
+mod media;
 #[cfg(feature = "mermaid")]
 mod mermaid;
 pub mod openapi;
@@ -36,6 +42,7 @@ mod renderer;
 mod response_field;
 mod steps;
 mod tabs;
+mod terminal;
 mod toc;
 mod update;
 
@@ -44,7 +51,13 @@ pub use api_examples::*;
 pub use callout::*;
 pub use card::*;
 pub use code::*;
+pub use data_table::*;
+pub use definition_list::*;
+pub use file_tree::*;
+pub use footnotes::*;
 pub use icons::*;
+pub use inline::*;
+pub use media::*;
 #[cfg(feature = "mermaid")]
 pub use mermaid::*;
 pub use openapi::*;
@@ -53,5 +66,6 @@ pub use renderer::*;
 pub use response_field::*;
 pub use steps::*;
 pub use tabs::*;
+pub use terminal::*;
 pub use toc::*;
 pub use update::*;
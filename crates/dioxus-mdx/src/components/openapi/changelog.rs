@@ -0,0 +1,32 @@
+//! API changelog entry generated from a spec diff.
+
+use dioxus::prelude::*;
+
+use crate::components::DocUpdate;
+use crate::parser::SpecDiff;
+
+/// Props for ApiChangelog component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ApiChangelogProps {
+    /// Changes between two versions of a spec, from
+    /// [`crate::parser::diff_specs`].
+    pub diff: SpecDiff,
+    /// Version label (e.g., "v2.0.0").
+    pub label: String,
+    /// Date description (e.g., "October 2026").
+    #[props(default)]
+    pub description: String,
+}
+
+/// A changelog entry for one API release, laid out like an `<Update>`:
+/// breaking changes first, then the others.
+///
+/// Stack one per release, newest first, to build an API changelog page.
+#[component]
+pub fn ApiChangelog(props: ApiChangelogProps) -> Element {
+    let update = props.diff.to_update(&props.label, &props.description);
+
+    rsx! {
+        DocUpdate { update }
+    }
+}
@@ -0,0 +1,230 @@
+//! Two-column reference page for an AsyncAPI channel.
+
+use dioxus::prelude::*;
+
+use crate::parser::{AsyncApiChannel, AsyncApiMessage, AsyncApiServer, ChannelOperation};
+
+use super::endpoint_page::code_sample;
+use super::parameters_list::ParametersList;
+use super::schema_viewer::SchemaViewer;
+
+/// Props for ChannelPage component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ChannelPageProps {
+    /// The channel to display.
+    pub channel: AsyncApiChannel,
+    /// The spec's servers, listed under the address.
+    #[props(default)]
+    pub servers: Vec<AsyncApiServer>,
+}
+
+/// Full-page layout for an AsyncAPI channel, mirroring [`super::EndpointPage`].
+///
+/// Left column: address, publish/subscribe badges, parameters, operations,
+/// and per message its headers and payload schema. Right column (sticky):
+/// an example payload per message.
+#[component]
+pub fn ChannelPage(props: ChannelPageProps) -> Element {
+    let channel = &props.channel;
+
+    rsx! {
+        div { class: "flex flex-col lg:flex-row gap-0",
+            // Left column — scrollable content
+            div { class: "flex-1 min-w-0 px-8 py-12 lg:px-12",
+                div { class: "max-w-2xl",
+                    div { class: "flex items-center gap-3 mb-6 flex-wrap",
+                        span { class: "badge badge-outline badge-sm uppercase tracking-wider",
+                            "Channel"
+                        }
+                        for action in channel.actions() {
+                            span {
+                                key: "{action.as_str()}",
+                                class: "badge badge-sm font-mono font-bold {action.badge_class()}",
+                                "{action.as_str()}"
+                            }
+                        }
+                        code { class: "font-mono text-lg text-base-content break-all",
+                            "{channel.address}"
+                        }
+                    }
+
+                    h1 { class: "text-3xl font-bold tracking-tight mb-3", "{channel.title()}" }
+
+                    if let Some(desc) = &channel.description {
+                        p { class: "text-base text-base-content/70 mb-6 leading-relaxed",
+                            "{desc}"
+                        }
+                    }
+
+                    if !props.servers.is_empty() {
+                        div { class: "mb-8 space-y-1",
+                            for server in props.servers.iter() {
+                                div {
+                                    key: "{server.name}",
+                                    class: "flex items-center gap-2 text-sm",
+                                    span { class: "badge badge-ghost badge-sm font-mono", "{server.protocol}" }
+                                    code { class: "font-mono text-base-content/70 break-all",
+                                        "{server.url}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if !channel.parameters.is_empty() {
+                        div { class: "mb-8",
+                            h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                                "Parameters"
+                            }
+                            ParametersList { parameters: channel.parameters.clone() }
+                        }
+                    }
+
+                    if !channel.operations.is_empty() {
+                        div { class: "mb-8",
+                            h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                                "Operations"
+                            }
+                            div { class: "space-y-4",
+                                for op in channel.operations.iter() {
+                                    ChannelOperationItem {
+                                        key: "{op.action.as_str()} {op.id.as_deref().unwrap_or_default()}",
+                                        operation: op.clone(),
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    for message in channel.messages.iter() {
+                        MessageSection { key: "{message.name}", message: message.clone() }
+                    }
+                }
+            }
+
+            // Right column — sticky message examples
+            aside { class: "lg:w-[45%] lg:shrink-0 lg:border-l border-base-300 bg-base-200/20",
+                div { class: "lg:sticky lg:top-16 lg:h-[calc(100vh-4rem)] lg:overflow-y-auto p-6 space-y-6",
+                    for message in channel.messages.iter() {
+                        if let Some(example) = message.example_payload() {
+                            div { key: "{message.name}",
+                                h3 { class: "text-sm font-semibold text-base-content/70 uppercase tracking-wider mb-3",
+                                    "Example"
+                                }
+                                div { class: "rounded-lg border border-base-300 overflow-hidden",
+                                    div { class: "px-3 py-2 bg-base-300/50 border-b border-base-300 flex items-center gap-2",
+                                        span { class: "text-xs font-mono font-semibold text-base-content/70",
+                                            "{message.name}"
+                                        }
+                                        if let Some(content_type) = &message.content_type {
+                                            span { class: "text-xs text-base-content/50", "{content_type}" }
+                                        }
+                                    }
+                                    div { class: "dk-code-block-body bg-base-200 max-h-[60vh] overflow-y-auto",
+                                        {code_sample(example, "json")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Props for ChannelOperationItem component.
+#[derive(Props, Clone, PartialEq)]
+struct ChannelOperationItemProps {
+    operation: ChannelOperation,
+}
+
+/// One operation: its action, summary and the messages involved.
+#[component]
+fn ChannelOperationItem(props: ChannelOperationItemProps) -> Element {
+    let op = &props.operation;
+    let action_bg = op.action.bg_class();
+
+    rsx! {
+        div { class: "rounded-lg border border-base-300 p-4",
+            div { class: "flex items-center gap-3 flex-wrap",
+                span { class: "px-2 py-1 rounded-lg font-mono text-xs font-bold border {action_bg}",
+                    "{op.action.as_str()}"
+                }
+                if let Some(summary) = &op.summary {
+                    span { class: "font-medium text-base-content", "{summary}" }
+                }
+                if let Some(id) = &op.id {
+                    code { class: "font-mono text-xs text-base-content/50 ml-auto", "{id}" }
+                }
+            }
+            if let Some(desc) = &op.description {
+                p { class: "text-sm text-base-content/70 mt-3 leading-relaxed", "{desc}" }
+            }
+            if !op.messages.is_empty() {
+                div { class: "flex items-center gap-2 flex-wrap mt-3 text-xs text-base-content/60",
+                    "Messages:"
+                    for name in op.messages.iter() {
+                        code { key: "{name}", class: "font-mono bg-base-200 px-1.5 py-0.5 rounded",
+                            "{name}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Props for MessageSection component.
+#[derive(Props, Clone, PartialEq)]
+struct MessageSectionProps {
+    message: AsyncApiMessage,
+}
+
+/// One message: title, description, headers and payload schemas.
+#[component]
+fn MessageSection(props: MessageSectionProps) -> Element {
+    let message = &props.message;
+    let heading = message.title.as_deref().unwrap_or(&message.name);
+
+    rsx! {
+        section { class: "mb-10",
+            div { class: "flex items-center gap-3 mb-4 pb-2 border-b border-base-300 flex-wrap",
+                h2 { class: "text-lg font-semibold", "{heading}" }
+                if message.title.is_some() {
+                    code { class: "font-mono text-sm text-base-content/60", "{message.name}" }
+                }
+                if let Some(content_type) = &message.content_type {
+                    span { class: "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70 ml-auto",
+                        "{content_type}"
+                    }
+                }
+            }
+
+            if let Some(summary) = &message.summary {
+                p { class: "text-base text-base-content/70 mb-3 leading-relaxed", "{summary}" }
+            }
+            if let Some(desc) = &message.description {
+                p { class: "text-base text-base-content/70 mb-6 leading-relaxed", "{desc}" }
+            }
+
+            if let Some(headers) = &message.headers {
+                div { class: "mb-6",
+                    h3 { class: "text-sm font-semibold text-base-content/70 uppercase tracking-wider mb-3",
+                        "Headers"
+                    }
+                    SchemaViewer { schema: headers.clone(), expanded: true }
+                }
+            }
+
+            if let Some(payload) = &message.payload {
+                div { class: "mb-6",
+                    h3 { class: "text-sm font-semibold text-base-content/70 uppercase tracking-wider mb-3",
+                        "Payload"
+                    }
+                    SchemaViewer { schema: payload.clone(), expanded: true }
+                }
+            }
+        }
+    }
+}
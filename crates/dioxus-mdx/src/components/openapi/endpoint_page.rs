@@ -2,30 +2,30 @@
 
 use dioxus::prelude::*;
 #[cfg(feature = "highlight")]
-use dioxus_code::{Code, CodeTheme, Language, SourceCode, Theme};
+use dioxus_code::{Code, CodeTheme, SourceCode, Theme};
 
-use crate::parser::{ApiOperation, OpenApiSpec};
+use crate::parser::{ApiOperation, CodeSample, HttpMethod, OpenApiSpec, ResponseExamples};
 
 use super::method_badge::MethodBadge;
 use super::parameters_list::ParametersList;
+use super::playground::ApiPlayground;
 use super::request_body::RequestBodySection;
 use super::responses_list::ResponsesList;
+use super::security::SecurityRequirements;
+use super::server_selector::{ServerSelector, update_server_choice, use_server_choice};
 
-/// Language selector for the endpoint page's code samples, kept independent of
-/// `dioxus-code` so the markup compiles with the `highlight` feature disabled.
-enum SampleLang {
-    Bash,
-    Json,
-}
+/// localStorage key of the persisted request sample language.
+const SAMPLE_LANG_KEY: &str = "dk-sample-lang";
 
 /// Render a syntax-highlighted code sample using the fixed GitHub Light / Tokyo Night
 /// system theme (endpoint pages don't follow the site theme toggle).
+///
+/// `lang` is a fence-style language name (`"json"`, `"python"`, ...); `"curl"`
+/// highlights as Bash.
 #[cfg(feature = "highlight")]
-fn code_sample(code: String, lang: SampleLang) -> Element {
-    let language = match lang {
-        SampleLang::Bash => Language::Bash,
-        SampleLang::Json => Language::Json,
-    };
+pub(super) fn code_sample(code: String, lang: &str) -> Element {
+    let lang = if lang == "curl" { "bash" } else { lang };
+    let language = crate::components::code::code_language(Some(lang), None, None);
     let theme = CodeTheme::system(Theme::GITHUB_LIGHT, Theme::TOKYO_NIGHT);
     rsx! {
         Code {
@@ -38,7 +38,7 @@ fn code_sample(code: String, lang: SampleLang) -> Element {
 /// Fallback code sample when the `highlight` feature is disabled: escaped plain text
 /// in the same `<pre class="dxc">` markup, without token coloring.
 #[cfg(not(feature = "highlight"))]
-fn code_sample(code: String, _lang: SampleLang) -> Element {
+pub(super) fn code_sample(code: String, _lang: &str) -> Element {
     crate::components::code::plain_code_block(&code)
 }
 
@@ -47,29 +47,33 @@ fn code_sample(code: String, _lang: SampleLang) -> Element {
 pub struct EndpointPageProps {
     /// The operation to display.
     pub operation: ApiOperation,
-    /// The full OpenAPI spec (for base URL).
+    /// The full OpenAPI spec (for its servers).
     pub spec: OpenApiSpec,
+    /// Show the "Try it" playground, unless the spec or operation turns it
+    /// off with `x-playground: false`. Defaults to `false`.
+    #[props(default)]
+    pub playground: bool,
 }
 
 /// Full-page two-column layout for a single API endpoint.
 ///
-/// Left column: method badge, path, summary, description, parameters, request body, responses.
-/// Right column (sticky): curl example, response JSON example.
+/// Left column: method badge, path, summary, description, base URL with a
+/// server picker, authorization, parameters, request body, responses.
+/// Right column (sticky): the opt-in "Try it" playground, request samples with
+/// a language switcher, response examples.
 #[component]
 pub fn EndpointPage(props: EndpointPageProps) -> Element {
     let op = &props.operation;
     let spec = &props.spec;
 
-    let base_url = spec
-        .servers
-        .first()
-        .map(|s| s.url.as_str())
-        .unwrap_or("https://api.example.com");
+    let server_choice = use_server_choice();
+    let base_url = server_choice.read().base_url(&spec.servers);
 
-    let curl = op.generate_curl(base_url);
-    let response_example = op.generate_response_example();
+    let samples = op.request_samples(&base_url);
+    let response_examples = op.response_examples();
 
     let method_bg = op.method.bg_class();
+    let playground = props.playground && spec.playground && op.playground;
 
     rsx! {
         div { class: "flex flex-col lg:flex-row gap-0",
@@ -104,13 +108,16 @@ pub fn EndpointPage(props: EndpointPageProps) -> Element {
                         }
                     }
 
-                    // Base URL
-                    div { class: "mb-8 flex items-center gap-2",
-                        span { class: "text-xs text-base-content/50 font-semibold uppercase tracking-wider",
-                            "Base URL"
-                        }
-                        code { class: "text-sm font-mono text-base-content/70 bg-base-200 px-2 py-1 rounded",
-                            "{base_url}"
+                    // Base URL, with a server picker
+                    ServerSelector { servers: spec.servers.clone(), choice: server_choice }
+
+                    // Authorization section
+                    if !op.security.is_empty() {
+                        div { class: "mb-8",
+                            h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                                "Authorization"
+                            }
+                            SecurityRequirements { requirements: op.security.clone() }
                         }
                     }
 
@@ -149,58 +156,182 @@ pub fn EndpointPage(props: EndpointPageProps) -> Element {
             // Right column — sticky code examples
             aside { class: "lg:w-[45%] lg:shrink-0 lg:border-l border-base-300 bg-base-200/20",
                 div { class: "lg:sticky lg:top-16 lg:h-[calc(100vh-4rem)] lg:overflow-y-auto p-6 space-y-6",
+                    if playground {
+                        ApiPlayground {
+                            key: "{op.method.as_str()} {op.path}",
+                            operation: op.clone(),
+                            base_url: base_url.clone(),
+                            on_base_url_change: move |url| {
+                                update_server_choice(server_choice, |c| c.custom_url = Some(url));
+                            },
+                        }
+                    }
+
                     // Request example
                     div {
                         h3 { class: "text-sm font-semibold text-base-content/70 uppercase tracking-wider mb-3",
                             "Request"
                         }
-                        div { class: "rounded-lg border border-base-300 overflow-hidden",
-                            div { class: "px-3 py-2 bg-base-300/50 border-b border-base-300 flex items-center gap-2",
-                                MethodBadge { method: op.method }
-                                code { class: "text-xs font-mono text-base-content/70 truncate",
-                                    "{op.path}"
-                                }
-                            }
-                            div { class: "dk-code-block-body bg-base-200",
-                                {code_sample(curl.clone(), SampleLang::Bash)}
-                            }
+                        RequestSamples {
+                            samples,
+                            method: op.method,
+                            path: op.path.clone(),
                         }
                     }
 
                     // Response example
-                    if let Some((status_code, response_json)) = &response_example {
-                        {
-                            let status_color = if status_code.starts_with('2') {
-                                "badge-success"
-                            } else if status_code.starts_with('3') {
-                                "badge-info"
-                            } else {
-                                "badge-ghost"
-                            };
-                            rsx! {
-                                div {
-                                    h3 { class: "text-sm font-semibold text-base-content/70 uppercase tracking-wider mb-3",
-                                        "Response"
-                                    }
-                                    div { class: "rounded-lg border border-base-300 overflow-hidden",
-                                        div { class: "px-3 py-2 bg-base-300/50 border-b border-base-300 flex items-center gap-2",
-                                            span { class: "badge {status_color} badge-sm font-mono font-bold",
-                                                "{status_code}"
-                                            }
-                                            span { class: "text-xs text-base-content/50",
-                                                "application/json"
-                                            }
-                                        }
-                                        div { class: "dk-code-block-body bg-base-200 max-h-[60vh] overflow-y-auto",
-                                            {code_sample(response_json.clone(), SampleLang::Json)}
-                                        }
-                                    }
-                                }
+                    if let Some(examples) = response_examples {
+                        ResponseExample { key: "{op.method.as_str()} {op.path}", examples }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Example response for the right column, with a picker when the spec
+/// provides several named examples.
+#[component]
+fn ResponseExample(examples: ResponseExamples) -> Element {
+    let mut selected = use_signal(|| 0usize);
+    let status_code = &examples.status_code;
+    let status_color = if status_code.starts_with('2') {
+        "badge-success"
+    } else if status_code.starts_with('3') {
+        "badge-info"
+    } else {
+        "badge-ghost"
+    };
+    let current = selected().min(examples.examples.len().saturating_sub(1));
+    let Some((_, value)) = examples.examples.get(current) else {
+        return rsx! {};
+    };
+    let lang = if examples.media_type.contains("json") {
+        "json"
+    } else if examples.media_type.contains("xml") {
+        "xml"
+    } else {
+        "text"
+    };
+
+    rsx! {
+        div {
+            h3 { class: "text-sm font-semibold text-base-content/70 uppercase tracking-wider mb-3",
+                "Response"
+            }
+            div { class: "rounded-lg border border-base-300 overflow-hidden",
+                div { class: "px-3 py-2 bg-base-300/50 border-b border-base-300 flex items-center gap-2",
+                    span { class: "badge {status_color} badge-sm font-mono font-bold",
+                        "{status_code}"
+                    }
+                    span { class: "text-xs text-base-content/50", "{examples.media_type}" }
+                }
+                if examples.examples.len() > 1 {
+                    div {
+                        class: "px-2 py-1.5 border-b border-base-300 flex items-center gap-1 flex-wrap",
+                        role: "tablist",
+                        for (i, (label, _)) in examples.examples.iter().enumerate() {
+                            button {
+                                key: "{i}",
+                                class: if i == current { "px-2 py-0.5 rounded text-xs cursor-pointer bg-base-100 text-base-content shadow-sm" } else { "px-2 py-0.5 rounded text-xs cursor-pointer text-base-content/60 hover:text-base-content" },
+                                role: "tab",
+                                "aria-selected": i == current,
+                                onclick: move |_| selected.set(i),
+                                "{label}"
+                            }
+                        }
+                    }
+                }
+                div { class: "dk-code-block-body bg-base-200 max-h-[60vh] overflow-y-auto",
+                    {code_sample(value.clone(), lang)}
+                }
+            }
+        }
+    }
+}
+
+/// Props for RequestSamples.
+#[derive(Props, Clone, PartialEq)]
+struct RequestSamplesProps {
+    samples: Vec<CodeSample>,
+    method: HttpMethod,
+    path: String,
+}
+
+/// Request samples behind a language switcher. The chosen language is
+/// persisted in localStorage, so every endpoint page opens on it.
+#[component]
+fn RequestSamples(props: RequestSamplesProps) -> Element {
+    let mut selected = use_signal(|| "curl".to_string());
+
+    use_effect(move || {
+        spawn(async move {
+            let mut eval = document::eval(&format!(
+                r#"
+                let lang = null;
+                try {{ lang = localStorage.getItem('{SAMPLE_LANG_KEY}'); }} catch(e) {{}}
+                dioxus.send(lang || '');
+                "#
+            ));
+            if let Ok(stored) = eval.recv::<String>().await
+                && !stored.is_empty()
+            {
+                selected.set(stored);
+            }
+        });
+    });
+
+    let current = props
+        .samples
+        .iter()
+        .find(|s| s.lang == selected())
+        .or(props.samples.first());
+
+    rsx! {
+        div { class: "rounded-lg border border-base-300 overflow-hidden",
+            div { class: "px-3 py-2 bg-base-300/50 border-b border-base-300 flex items-center gap-2",
+                MethodBadge { method: props.method }
+                code { class: "text-xs font-mono text-base-content/70 truncate",
+                    "{props.path}"
+                }
+            }
+            div {
+                class: "flex gap-1 px-2 py-1.5 border-b border-base-300 overflow-x-auto",
+                role: "tablist",
+                for sample in props.samples.iter() {
+                    {
+                        let lang = sample.lang.clone();
+                        let active = current.is_some_and(|c| c.lang == sample.lang);
+                        let tab_class = if active {
+                            "bg-base-100 text-base-content shadow-sm"
+                        } else {
+                            "text-base-content/60 hover:text-base-content"
+                        };
+                        rsx! {
+                            button {
+                                key: "{sample.lang}",
+                                class: "px-2 py-1 rounded text-xs font-medium whitespace-nowrap transition-colors cursor-pointer {tab_class}",
+                                role: "tab",
+                                "aria-selected": active,
+                                onclick: move |_| {
+                                    let lang = lang.clone();
+                                    selected.set(lang.clone());
+                                    let _ = document::eval(&format!(
+                                        "try {{ localStorage.setItem('{SAMPLE_LANG_KEY}', {}); }} catch(e) {{}}",
+                                        serde_json::to_string(&lang).unwrap_or_default()
+                                    ));
+                                },
+                                "{sample.label}"
                             }
                         }
                     }
                 }
             }
+            if let Some(sample) = current {
+                div { class: "dk-code-block-body bg-base-200",
+                    {code_sample(sample.source.clone(), &sample.lang)}
+                }
+            }
         }
     }
 }
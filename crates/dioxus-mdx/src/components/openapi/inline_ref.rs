@@ -0,0 +1,101 @@
+//! Inline OpenAPI references (`<ApiEndpoint>` and `<Schema>`) for guide pages.
+
+use dioxus::prelude::*;
+use dioxus_free_icons::{Icon, icons::ld_icons::*};
+
+use crate::components::convert_doc_href;
+use crate::parser::{ApiEndpointNode, SchemaNode};
+
+use super::method_badge::MethodBadge;
+use super::schema_viewer::SchemaViewer;
+
+/// Props for DocApiEndpoint component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocApiEndpointProps {
+    /// Endpoint reference, normally resolved by the docs registry.
+    pub endpoint: ApiEndpointNode,
+    /// Base path for doc links (e.g., "/docs").
+    #[props(default = "/docs".to_string())]
+    pub doc_base_path: String,
+}
+
+/// Compact method-badge card linking to an operation's endpoint page.
+#[component]
+pub fn DocApiEndpoint(props: DocApiEndpointProps) -> Element {
+    let Some(op) = &props.endpoint.operation else {
+        return rsx! {
+            UnresolvedRef { kind: "operation", id: props.endpoint.operation_id.clone() }
+        };
+    };
+
+    let card = rsx! {
+        MethodBadge { method: op.method }
+        code { class: "font-mono text-sm text-base-content truncate", "{op.path}" }
+        if op.deprecated {
+            span { class: "badge badge-warning badge-sm", "deprecated" }
+        }
+        if let Some(summary) = &op.summary {
+            span { class: "text-sm text-base-content/60 truncate ml-auto", "{summary}" }
+        }
+    };
+
+    match &props.endpoint.href {
+        Some(href) => {
+            let href = convert_doc_href(href, &props.doc_base_path);
+            rsx! {
+                a {
+                    href: "{href}",
+                    class: "dk-api-endpoint not-prose my-4 flex items-center gap-3 px-4 py-3 rounded-lg border border-base-content/10 bg-base-200/50 no-underline hover:border-primary/50 transition-colors",
+                    {card}
+                    Icon { class: "size-4 text-base-content/40 shrink-0", icon: LdArrowRight }
+                }
+            }
+        }
+        None => rsx! {
+            div { class: "dk-api-endpoint not-prose my-4 flex items-center gap-3 px-4 py-3 rounded-lg border border-base-content/10 bg-base-200/50",
+                {card}
+            }
+        },
+    }
+}
+
+/// Props for DocSchema component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocSchemaProps {
+    /// Schema reference, normally resolved by the docs registry.
+    pub schema: SchemaNode,
+}
+
+/// A single component schema rendered with [`SchemaViewer`].
+#[component]
+pub fn DocSchema(props: DocSchemaProps) -> Element {
+    let Some(schema) = &props.schema.schema else {
+        return rsx! {
+            UnresolvedRef { kind: "schema", id: props.schema.name.clone() }
+        };
+    };
+
+    rsx! {
+        div { class: "dk-schema not-prose my-6 rounded-lg border border-base-content/10 overflow-hidden",
+            div { class: "flex items-center gap-2 px-4 py-2.5 bg-base-200/50 border-b border-base-content/10",
+                Icon { class: "size-4 text-base-content/50", icon: LdBraces }
+                code { class: "font-mono font-semibold text-sm text-base-content", "{props.schema.name}" }
+            }
+            div { class: "px-4 py-2",
+                SchemaViewer { schema: schema.clone(), expanded: true }
+            }
+        }
+    }
+}
+
+/// Placeholder for a reference nothing resolved (e.g. plain `MdxContent`
+/// rendering without a registry).
+#[component]
+fn UnresolvedRef(kind: &'static str, id: String) -> Element {
+    rsx! {
+        div { class: "not-prose my-4 px-4 py-3 rounded-lg border border-dashed border-base-content/20 text-sm text-base-content/60",
+            "Unresolved {kind} "
+            code { class: "font-mono", "{id}" }
+        }
+    }
+}
@@ -3,22 +3,37 @@
 //! This module provides components for rendering OpenAPI 3.0/3.1 specifications
 //! with interactive endpoint documentation.
 
+mod changelog;
+mod channel_page;
 mod endpoint_card;
 mod endpoint_page;
+mod inline_ref;
 mod method_badge;
 mod parameters_list;
+mod playground;
 mod request_body;
 mod responses_list;
+mod schema_page;
 mod schema_viewer;
+mod security;
+mod server_selector;
 mod spec_viewer;
 mod tag_group;
+mod webhook_page;
 
+pub use changelog::*;
+pub use channel_page::*;
 pub use endpoint_card::*;
 pub use endpoint_page::*;
+pub use inline_ref::*;
 pub use method_badge::*;
 pub use parameters_list::*;
+pub use playground::*;
 pub use request_body::*;
 pub use responses_list::*;
+pub use schema_page::*;
 pub use schema_viewer::*;
+pub use security::*;
 pub use spec_viewer::*;
 pub use tag_group::*;
+pub use webhook_page::*;
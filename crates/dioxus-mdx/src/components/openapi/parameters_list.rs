@@ -4,7 +4,7 @@ use dioxus::prelude::*;
 
 use crate::parser::ApiParameter;
 
-use super::schema_viewer::SchemaViewer;
+use super::schema_viewer::{SchemaViewer, type_badge};
 
 /// Props for ParametersList component.
 #[derive(Props, Clone, PartialEq)]
@@ -58,9 +58,7 @@ pub fn ParameterItem(props: ParameterItemProps) -> Element {
 
                 // Type from schema
                 if let Some(schema) = &param.schema {
-                    span { class: "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70",
-                        "{schema.display_type()}"
-                    }
+                    {type_badge(schema, "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70")}
                 }
 
                 // Required indicator
@@ -0,0 +1,335 @@
+//! Interactive "Try it" panel for API endpoint pages.
+
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::parser::{ApiOperation, ParameterLocation, SecuritySchemeKind};
+
+use super::endpoint_page::code_sample;
+
+/// Sends the request handed over with `eval.send` and reports back either
+/// `{ ok: response }` or `{ error: message }`.
+const FETCH_JS: &str = r#"
+const req = await dioxus.recv();
+const started = performance.now();
+try {
+    const res = await fetch(req.url, {
+        method: req.method,
+        headers: req.headers,
+        body: req.body ?? undefined,
+    });
+    const body = await res.text();
+    dioxus.send({ ok: {
+        status: res.status,
+        status_text: res.statusText,
+        headers: Array.from(res.headers.entries()),
+        body,
+        elapsed_ms: Math.round(performance.now() - started),
+    } });
+} catch (e) {
+    dioxus.send({ error: String(e) });
+}
+"#;
+
+/// What came back from `fetch`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct PlaygroundResponse {
+    status: u16,
+    status_text: String,
+    headers: Vec<(String, String)>,
+    body: String,
+    elapsed_ms: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FetchResult {
+    Ok(PlaygroundResponse),
+    Error(String),
+}
+
+/// Props for ApiPlayground component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ApiPlaygroundProps {
+    /// The operation to call.
+    pub operation: ApiOperation,
+    /// Base URL picked in the page's server selector.
+    pub base_url: String,
+    /// Called with the URL typed into the server input.
+    pub on_base_url_change: EventHandler<String>,
+}
+
+/// Form for sending a real request to an endpoint and inspecting the reply.
+///
+/// Path, query and header parameters start from their examples and the body
+/// from the generated example JSON. Credentials for the operation's security
+/// schemes are kept in memory only. The server URL follows the page's server
+/// selector and can be overridden here, e.g. with a local mock server; the
+/// override is remembered until another server is picked.
+#[component]
+pub fn ApiPlayground(props: ApiPlaygroundProps) -> Element {
+    let op = props.operation.clone();
+    let mut open = use_signal(|| false);
+    let server = props.base_url.clone();
+    let mut inputs = use_signal(|| op.playground_inputs());
+    let mut credentials = use_signal(BTreeMap::<String, String>::new);
+    let mut body = use_signal(|| op.playground_body());
+    let mut sending = use_signal(|| false);
+    let mut result = use_signal(|| None::<Result<PlaygroundResponse, String>>);
+
+    // Browsers don't let scripts set cookies on cross-origin requests.
+    let auth_schemes: Vec<_> = op
+        .auth_schemes()
+        .into_iter()
+        .filter(|s| {
+            !matches!(
+                s.scheme.kind,
+                SecuritySchemeKind::ApiKey {
+                    location: ParameterLocation::Cookie,
+                    ..
+                }
+            )
+        })
+        .cloned()
+        .collect();
+
+    let send = {
+        let op = op.clone();
+        let server = server.clone();
+        move |_| {
+            let request = op.build_playground_request(
+                &server,
+                &inputs(),
+                &credentials(),
+                body().as_deref().unwrap_or_default(),
+            );
+            let request = match request {
+                Ok(request) => request,
+                Err(e) => {
+                    result.set(Some(Err(e.to_string())));
+                    return;
+                }
+            };
+            sending.set(true);
+            spawn(async move {
+                let mut eval = document::eval(FETCH_JS);
+                let _ = eval.send(serde_json::json!({
+                    "method": request.method.as_str(),
+                    "url": request.url,
+                    "headers": request.headers,
+                    "body": request.body,
+                }));
+                let outcome = match eval.recv::<FetchResult>().await {
+                    Ok(FetchResult::Ok(response)) => Ok(response),
+                    Ok(FetchResult::Error(e)) => Err(format!(
+                        "{e}. Check that the server is reachable and allows cross-origin requests (CORS)."
+                    )),
+                    Err(e) => Err(format!("{e:?}")),
+                };
+                result.set(Some(outcome));
+                sending.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div { class: "dk-playground rounded-lg border border-base-300 overflow-hidden",
+            button {
+                class: "w-full px-3 py-2 bg-base-300/50 flex items-center justify-between text-sm font-semibold cursor-pointer",
+                "aria-expanded": open(),
+                onclick: move |_| open.toggle(),
+                span { "Try it" }
+                span { class: "text-xs text-base-content/50",
+                    if open() { "Hide" } else { "Show" }
+                }
+            }
+            if open() {
+                div { class: "p-3 space-y-4 border-t border-base-300",
+                    // Server
+                    div { class: "space-y-1.5",
+                        label { class: "text-xs font-semibold text-base-content/70 uppercase tracking-wider",
+                            "Server"
+                        }
+                        input {
+                            class: "input input-sm input-bordered w-full font-mono",
+                            r#type: "url",
+                            placeholder: "http://localhost:4010",
+                            value: "{server}",
+                            oninput: move |e: FormEvent| {
+                                props.on_base_url_change.call(e.value());
+                            },
+                        }
+                    }
+
+                    // Authorization
+                    if !auth_schemes.is_empty() {
+                        div { class: "space-y-2",
+                            h4 { class: "text-xs font-semibold text-base-content/70 uppercase tracking-wider",
+                                "Authorization"
+                            }
+                            for required in auth_schemes {
+                                {
+                                    let name = required.name.clone();
+                                    let placeholder = match &required.scheme.kind {
+                                        SecuritySchemeKind::Http { scheme, .. } if scheme == "basic" => "username:password",
+                                        SecuritySchemeKind::ApiKey { .. } => "API key",
+                                        _ => "Token",
+                                    };
+                                    rsx! {
+                                        label { key: "{required.name}", class: "flex items-center gap-2",
+                                            span { class: "w-1/3 min-w-0 text-xs truncate",
+                                                code { class: "font-mono", "{required.name}" }
+                                            }
+                                            span { class: "badge badge-ghost badge-xs", "{required.scheme.label()}" }
+                                            input {
+                                                class: "input input-xs input-bordered flex-1 min-w-0 font-mono",
+                                                r#type: "password",
+                                                autocomplete: "off",
+                                                placeholder,
+                                                value: credentials.read().get(&name).cloned().unwrap_or_default(),
+                                                oninput: move |e| {
+                                                    credentials.write().insert(name.clone(), e.value());
+                                                },
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Parameters
+                    if op.parameters.iter().any(|p| p.location != ParameterLocation::Cookie) {
+                        div { class: "space-y-2",
+                            h4 { class: "text-xs font-semibold text-base-content/70 uppercase tracking-wider",
+                                "Parameters"
+                            }
+                            for (i, param) in op.parameters.iter().enumerate() {
+                                if param.location != ParameterLocation::Cookie {
+                                    label {
+                                        key: "{param.location.as_str()}-{param.name}",
+                                        class: "flex items-center gap-2",
+                                        span { class: "w-1/3 min-w-0 flex items-center gap-1",
+                                            code { class: "text-xs font-mono truncate", "{param.name}" }
+                                            if param.required {
+                                                span { class: "text-error text-xs", "*" }
+                                            }
+                                        }
+                                        span { class: "badge {param.location.badge_class()} badge-xs badge-outline",
+                                            "{param.location.as_str()}"
+                                        }
+                                        input {
+                                            class: "input input-xs input-bordered flex-1 min-w-0 font-mono",
+                                            placeholder: param.sample_value().unwrap_or_default(),
+                                            value: inputs.read().get(i).cloned().unwrap_or_default(),
+                                            oninput: move |e| {
+                                                if let Some(value) = inputs.write().get_mut(i) {
+                                                    *value = e.value();
+                                                }
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Body
+                    if let Some(text) = body() {
+                        div { class: "space-y-1.5",
+                            h4 { class: "text-xs font-semibold text-base-content/70 uppercase tracking-wider",
+                                "Body"
+                            }
+                            textarea {
+                                class: "textarea textarea-bordered w-full font-mono text-xs leading-relaxed",
+                                rows: 8,
+                                spellcheck: false,
+                                value: "{text}",
+                                oninput: move |e| body.set(Some(e.value())),
+                            }
+                        }
+                    }
+
+                    button {
+                        class: "btn btn-primary btn-sm w-full",
+                        disabled: sending(),
+                        onclick: send,
+                        if sending() { "Sending…" } else { "Send request" }
+                    }
+
+                    match result() {
+                        Some(Ok(response)) => rsx! { ResponseView { response } },
+                        Some(Err(message)) => rsx! {
+                            div { class: "alert alert-error alert-soft text-sm", role: "alert", "{message}" }
+                        },
+                        None => rsx! {},
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Status line, headers and highlighted body of a playground response.
+#[component]
+fn ResponseView(response: PlaygroundResponse) -> Element {
+    let status_color = match response.status / 100 {
+        2 => "badge-success",
+        3 => "badge-info",
+        4 => "badge-warning",
+        5 => "badge-error",
+        _ => "badge-ghost",
+    };
+    let content_type = response
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+        .map(|(_, value)| value.to_ascii_lowercase())
+        .unwrap_or_default();
+    let json = serde_json::from_str::<serde_json::Value>(&response.body)
+        .ok()
+        .and_then(|v| serde_json::to_string_pretty(&v).ok());
+    let (body, lang) = match json {
+        Some(pretty) => (pretty, Some("json")),
+        None if content_type.contains("html") => (response.body.clone(), Some("html")),
+        None if content_type.contains("xml") => (response.body.clone(), Some("xml")),
+        None => (response.body.clone(), None),
+    };
+
+    rsx! {
+        div { class: "rounded-lg border border-base-300 overflow-hidden",
+            div { class: "px-3 py-2 bg-base-300/50 border-b border-base-300 flex items-center gap-2",
+                span { class: "badge {status_color} badge-sm font-mono font-bold",
+                    "{response.status}"
+                }
+                span { class: "text-xs text-base-content/70", "{response.status_text}" }
+                span { class: "ml-auto text-xs text-base-content/50", "{response.elapsed_ms} ms" }
+            }
+            if !response.headers.is_empty() {
+                details { class: "border-b border-base-300",
+                    summary { class: "px-3 py-1.5 text-xs text-base-content/70 cursor-pointer",
+                        "Headers ({response.headers.len()})"
+                    }
+                    dl { class: "px-3 pb-2 grid grid-cols-[auto_1fr] gap-x-3 gap-y-0.5 text-xs font-mono",
+                        for (name, value) in response.headers.iter() {
+                            dt { class: "text-base-content/60", "{name}" }
+                            dd { class: "break-all", "{value}" }
+                        }
+                    }
+                }
+            }
+            if !body.is_empty() {
+                div { class: "dk-code-block-body bg-base-200 max-h-[50vh] overflow-y-auto",
+                    match lang {
+                        Some(lang) => code_sample(body, lang),
+                        None => rsx! {
+                            pre { class: "p-3 text-xs font-mono whitespace-pre-wrap break-all", "{body}" }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
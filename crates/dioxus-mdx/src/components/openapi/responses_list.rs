@@ -3,7 +3,7 @@
 use dioxus::prelude::*;
 use dioxus_free_icons::{Icon, icons::ld_icons::*};
 
-use crate::parser::ApiResponse;
+use crate::parser::{ApiResponse, MediaTypeContent, NamedExample};
 
 use super::schema_viewer::SchemaViewer;
 
@@ -37,14 +37,15 @@ pub struct ResponseItemProps {
     pub response: ApiResponse,
 }
 
-/// Single response item with collapsible content.
+/// Single response item with collapsible content, headers and links.
 #[component]
 pub fn ResponseItem(props: ResponseItemProps) -> Element {
     let mut is_expanded = use_signal(|| false);
     let response = &props.response;
     let badge_class = response.status_badge_class();
 
-    let has_content = !response.content.is_empty();
+    let has_content =
+        !response.content.is_empty() || !response.headers.is_empty() || !response.links.is_empty();
 
     rsx! {
         div { class: "border border-base-300 rounded-lg overflow-hidden",
@@ -80,6 +81,40 @@ pub fn ResponseItem(props: ResponseItemProps) -> Element {
             // Content
             if is_expanded() && has_content {
                 div { class: "border-t border-base-300 bg-base-200/30",
+                    // Headers
+                    if !response.headers.is_empty() {
+                        div { class: "p-3",
+                            div { class: "mb-2 text-xs text-base-content/50 font-semibold", "Headers" }
+                            table { class: "table table-xs",
+                                thead {
+                                    tr {
+                                        th { "Name" }
+                                        th { "Type" }
+                                        th { "Description" }
+                                    }
+                                }
+                                tbody {
+                                    for header in &response.headers {
+                                        tr { key: "{header.name}",
+                                            td {
+                                                code { class: "font-mono text-primary", "{header.name}" }
+                                                if header.required {
+                                                    span { class: "ml-1 text-error", "*" }
+                                                }
+                                            }
+                                            td { class: "font-mono text-base-content/70",
+                                                {header.schema.as_ref().map(|s| s.display_type()).unwrap_or_default()}
+                                            }
+                                            td { class: "text-base-content/70",
+                                                {header.description.clone().unwrap_or_default()}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
                     for content in &response.content {
                         div { class: "p-3",
                             // Media type
@@ -97,12 +132,33 @@ pub fn ResponseItem(props: ResponseItemProps) -> Element {
                                 }
                             }
 
-                            // Example
-                            if let Some(example) = &content.example {
-                                div { class: "mt-3 p-2 bg-base-300 rounded",
-                                    span { class: "text-xs text-base-content/50 font-semibold", "Example" }
-                                    pre { class: "mt-1 text-xs font-mono text-secondary overflow-x-auto whitespace-pre-wrap",
-                                        "{example}"
+                            // Examples
+                            MediaExamples { content: content.clone() }
+                        }
+                    }
+
+                    // Links
+                    if !response.links.is_empty() {
+                        div { class: "p-3",
+                            div { class: "mb-2 text-xs text-base-content/50 font-semibold", "Links" }
+                            ul { class: "space-y-2",
+                                for link in &response.links {
+                                    li { key: "{link.name}", class: "text-sm",
+                                        div { class: "flex items-center gap-2 flex-wrap",
+                                            code { class: "font-mono font-semibold text-primary", "{link.name}" }
+                                            if let Some(target) = link.operation_id.as_ref().or(link.operation_ref.as_ref()) {
+                                                span { class: "text-xs text-base-content/50", "→" }
+                                                code { class: "text-xs font-mono", "{target}" }
+                                            }
+                                        }
+                                        if let Some(desc) = &link.description {
+                                            p { class: "text-base-content/70", "{desc}" }
+                                        }
+                                        for (param, value) in &link.parameters {
+                                            div { key: "{param}", class: "text-xs font-mono text-base-content/60",
+                                                "{param} = {value}"
+                                            }
+                                        }
                                     }
                                 }
                             }
@@ -113,3 +169,69 @@ pub fn ResponseItem(props: ResponseItemProps) -> Element {
         }
     }
 }
+
+/// Props for MediaExamples component.
+#[derive(Props, Clone, PartialEq)]
+struct MediaExamplesProps {
+    content: MediaTypeContent,
+}
+
+/// A media type's examples: the named `examples` behind a picker, or the
+/// single `example`.
+#[component]
+fn MediaExamples(props: MediaExamplesProps) -> Element {
+    let mut selected = use_signal(|| 0usize);
+    let named = &props.content.examples;
+
+    if named.is_empty() {
+        return match &props.content.example {
+            Some(example) => rsx! {
+                div { class: "mt-3 p-2 bg-base-300 rounded",
+                    span { class: "text-xs text-base-content/50 font-semibold", "Example" }
+                    pre { class: "mt-1 text-xs font-mono text-secondary overflow-x-auto whitespace-pre-wrap",
+                        "{example}"
+                    }
+                }
+            },
+            None => rsx! {},
+        };
+    }
+
+    let current = selected().min(named.len() - 1);
+    let example: &NamedExample = &named[current];
+
+    rsx! {
+        div { class: "mt-3 p-2 bg-base-300 rounded",
+            div { class: "flex items-center gap-1 flex-wrap", role: "tablist",
+                span { class: "text-xs text-base-content/50 font-semibold mr-1", "Examples" }
+                for (i, e) in named.iter().enumerate() {
+                    button {
+                        key: "{e.name}",
+                        class: if i == current { "px-2 py-0.5 rounded text-xs cursor-pointer bg-base-100 text-base-content shadow-sm" } else { "px-2 py-0.5 rounded text-xs cursor-pointer text-base-content/60 hover:text-base-content" },
+                        role: "tab",
+                        "aria-selected": i == current,
+                        onclick: move |_| selected.set(i),
+                        "{e.label()}"
+                    }
+                }
+            }
+            if let Some(desc) = &example.description {
+                p { class: "mt-1 text-xs text-base-content/70", "{desc}" }
+            }
+            if let Some(value) = &example.value {
+                pre { class: "mt-1 text-xs font-mono text-secondary overflow-x-auto whitespace-pre-wrap",
+                    "{value}"
+                }
+            }
+            if let Some(url) = &example.external_value {
+                a {
+                    class: "mt-1 block text-xs link link-primary break-all",
+                    href: "{url}",
+                    target: "_blank",
+                    rel: "noopener noreferrer",
+                    "{url}"
+                }
+            }
+        }
+    }
+}
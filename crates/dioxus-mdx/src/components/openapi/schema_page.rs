@@ -0,0 +1,119 @@
+//! Reference page for a single component schema ("model").
+
+use dioxus::prelude::*;
+use dioxus_free_icons::{Icon, icons::ld_icons::*};
+
+use crate::parser::{ApiOperation, SchemaDefinition};
+
+use super::endpoint_page::code_sample;
+use super::method_badge::MethodBadge;
+use super::schema_viewer::SchemaViewer;
+
+/// Props for SchemaPage component.
+#[derive(Props, Clone, PartialEq)]
+pub struct SchemaPageProps {
+    /// Schema name, its key under `components.schemas`.
+    pub name: String,
+    /// The schema to display.
+    pub schema: SchemaDefinition,
+    /// Operations that use the schema, each with the URL of its endpoint page.
+    #[props(default)]
+    pub used_by: Vec<(ApiOperation, String)>,
+}
+
+/// Two-column page for a component schema, laid out like
+/// [`super::EndpointPage`].
+///
+/// Left column: name, description, the schema's properties and the
+/// operations that use it. Right column (sticky): an example JSON value.
+#[component]
+pub fn SchemaPage(props: SchemaPageProps) -> Element {
+    let schema = &props.schema;
+    let example = serde_json::to_string_pretty(&schema.generate_example_json(0)).ok();
+
+    rsx! {
+        div { class: "flex flex-col lg:flex-row gap-0",
+            // Left column — scrollable content
+            div { class: "flex-1 min-w-0 px-8 py-12 lg:px-12",
+                div { class: "max-w-2xl",
+                    div { class: "flex items-center gap-3 mb-6",
+                        span { class: "badge badge-outline badge-sm uppercase tracking-wider",
+                            "Model"
+                        }
+                        span { class: "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70",
+                            "{schema.display_type()}"
+                        }
+                    }
+
+                    h1 { class: "text-3xl font-bold tracking-tight mb-3 font-mono", "{props.name}" }
+
+                    if let Some(desc) = &schema.description {
+                        p { class: "text-base text-base-content/70 mb-6 leading-relaxed",
+                            "{desc}"
+                        }
+                    }
+
+                    div { class: "mb-8",
+                        h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                            "Properties"
+                        }
+                        SchemaViewer {
+                            key: "{props.name}",
+                            schema: SchemaDefinition {
+                                description: None,
+                                ..schema.clone()
+                            },
+                            expanded: true,
+                        }
+                    }
+
+                    if !props.used_by.is_empty() {
+                        div { class: "mb-8",
+                            h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                                "Used by"
+                            }
+                            ul { class: "space-y-2",
+                                for (op, href) in props.used_by.iter() {
+                                    li { key: "{href}",
+                                        a {
+                                            href: "{href}",
+                                            class: "flex items-center gap-3 px-4 py-2.5 rounded-lg border border-base-300 hover:border-primary/50 transition-colors",
+                                            MethodBadge { method: op.method }
+                                            code { class: "font-mono text-sm text-base-content truncate",
+                                                "{op.path}"
+                                            }
+                                            if let Some(summary) = &op.summary {
+                                                span { class: "text-sm text-base-content/60 truncate ml-auto",
+                                                    "{summary}"
+                                                }
+                                            }
+                                            Icon { class: "size-4 text-base-content/40 shrink-0", icon: LdArrowRight }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Right column — sticky example
+            aside { class: "lg:w-[45%] lg:shrink-0 lg:border-l border-base-300 bg-base-200/20",
+                div { class: "lg:sticky lg:top-16 lg:h-[calc(100vh-4rem)] lg:overflow-y-auto p-6 space-y-6",
+                    if let Some(example) = example {
+                        div {
+                            h3 { class: "text-sm font-semibold text-base-content/70 uppercase tracking-wider mb-3",
+                                "Example"
+                            }
+                            div { class: "rounded-lg border border-base-300 overflow-hidden",
+                                div { class: "dk-code-block-body bg-base-200 max-h-[60vh] overflow-y-auto",
+                                    {code_sample(example, "json")}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
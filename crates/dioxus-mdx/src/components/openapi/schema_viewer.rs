@@ -1,10 +1,48 @@
 //! Schema viewer component for displaying type definitions.
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
 use dioxus::prelude::*;
 use dioxus_free_icons::{Icon, icons::ld_icons::*};
 
 use crate::parser::SchemaDefinition;
 
+/// Pages of named component schemas.
+///
+/// Provide this context above [`SchemaViewer`] to turn `$ref` type names into
+/// links to their schema pages. Without it, type names render as plain text.
+#[derive(Clone, PartialEq, Default)]
+pub struct SchemaLinks {
+    /// Schema name → page URL.
+    pub hrefs: Arc<BTreeMap<String, String>>,
+}
+
+impl SchemaLinks {
+    /// Page URL for the schema a type links to, if it has one.
+    pub fn href(&self, schema: &SchemaDefinition) -> Option<&str> {
+        self.hrefs.get(schema.linked_ref()?).map(String::as_str)
+    }
+}
+
+/// A type badge, linking to the schema page when [`SchemaLinks`] has one.
+pub(super) fn type_badge(schema: &SchemaDefinition, class: &str) -> Element {
+    let type_display = schema.display_type();
+    let links = try_use_context::<SchemaLinks>();
+    match links.as_ref().and_then(|links| links.href(schema)) {
+        Some(href) => rsx! {
+            a {
+                class: "{class} no-underline hover:bg-primary/10 hover:text-primary transition-colors",
+                href: "{href}",
+                "{type_display}"
+            }
+        },
+        None => rsx! {
+            span { class: "{class}", "{type_display}" }
+        },
+    }
+}
+
 /// Props for SchemaViewer component.
 #[derive(Props, Clone, PartialEq)]
 pub struct SchemaViewerProps {
@@ -22,16 +60,37 @@ pub struct SchemaViewerProps {
     /// Whether this property is required.
     #[props(default = false)]
     pub required: bool,
+    /// Schema an `allOf`-merged property was inherited from.
+    #[props(default)]
+    pub source: Option<String>,
 }
 
 /// Recursive schema viewer with expand/collapse for complex types.
+///
+/// `allOf` branches are merged into one property list, each inherited
+/// property tagged with the schema it comes from. `oneOf`/`anyOf` variants
+/// are shown one at a time behind tabs labelled by discriminator value.
 #[component]
 pub fn SchemaViewer(props: SchemaViewerProps) -> Element {
     let mut is_expanded = use_signal(|| props.expanded || props.depth == 0);
     let schema = &props.schema;
 
-    let is_complex = schema.is_complex();
-    let type_display = schema.display_type();
+    let properties = schema.flattened_properties();
+    let variants: Vec<(String, SchemaDefinition)> = schema
+        .variants()
+        .into_iter()
+        .map(|(label, variant)| (label, variant.clone()))
+        .collect();
+    let variants_label = if schema.one_of.is_empty() {
+        "Any of"
+    } else {
+        "One of"
+    };
+    let complex_items = schema.items.as_ref().filter(|items| items.is_complex());
+    let expandable = !properties.is_empty()
+        || !variants.is_empty()
+        || schema.not.is_some()
+        || complex_items.is_some();
 
     let indent_class = if props.depth > 0 {
         "ml-4 border-l-2 border-base-300 pl-3"
@@ -44,7 +103,7 @@ pub fn SchemaViewer(props: SchemaViewerProps) -> Element {
             // Header row with name, type, and expand button
             div { class: "flex items-center gap-2 flex-wrap",
                 // Expand/collapse for complex types
-                if is_complex && !schema.properties.is_empty() {
+                if expandable {
                     button {
                         class: "p-0.5 hover:bg-base-300 rounded transition-colors",
                         onclick: move |_| is_expanded.set(!is_expanded()),
@@ -63,9 +122,7 @@ pub fn SchemaViewer(props: SchemaViewerProps) -> Element {
                 }
 
                 // Type badge
-                span { class: "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70",
-                    "{type_display}"
-                }
+                {type_badge(schema, "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70")}
 
                 // Required indicator
                 if props.required {
@@ -81,6 +138,14 @@ pub fn SchemaViewer(props: SchemaViewerProps) -> Element {
                     }
                 }
 
+                // allOf provenance
+                if let Some(source) = &props.source {
+                    span { class: "text-xs text-base-content/50",
+                        "from "
+                        code { class: "font-mono", "{source}" }
+                    }
+                }
+
                 // Format
                 if let Some(format) = &schema.format {
                     span { class: "text-xs text-base-content/50",
@@ -128,16 +193,27 @@ pub fn SchemaViewer(props: SchemaViewerProps) -> Element {
                 }
             }
 
-            // Nested properties for objects
-            if is_expanded() && !schema.properties.is_empty() {
+            // Discriminator
+            if let Some(discriminator) = &schema.discriminator {
+                div { class: "mt-1",
+                    span { class: "text-xs text-base-content/50", "Discriminator: " }
+                    code { class: "text-xs font-mono text-primary",
+                        "{discriminator.property_name}"
+                    }
+                }
+            }
+
+            // Nested properties for objects, including merged `allOf` branches
+            if is_expanded() && !properties.is_empty() {
                 div { class: "mt-2",
-                    for (name, prop_schema) in &schema.properties {
+                    for property in properties {
                         SchemaViewer {
-                            key: "{name}",
-                            schema: prop_schema.clone(),
+                            key: "{property.name}",
+                            schema: property.schema,
                             depth: props.depth + 1,
-                            name: Some(name.clone()),
-                            required: schema.required.contains(name),
+                            name: Some(property.name),
+                            required: property.required,
+                            source: property.source,
                         }
                     }
                 }
@@ -145,56 +221,82 @@ pub fn SchemaViewer(props: SchemaViewerProps) -> Element {
 
             // Array items
             if is_expanded() {
-                if let Some(items) = &schema.items {
-                    if items.is_complex() {
-                        div { class: "mt-2",
-                            span { class: "text-xs text-base-content/50 ml-4", "Array items:" }
-                            SchemaViewer {
-                                schema: (**items).clone(),
-                                depth: props.depth + 1,
-                            }
+                if let Some(items) = complex_items {
+                    div { class: "mt-2",
+                        span { class: "text-xs text-base-content/50 ml-4", "Array items:" }
+                        SchemaViewer {
+                            schema: (**items).clone(),
+                            depth: props.depth + 1,
                         }
                     }
                 }
             }
 
-            // OneOf/AnyOf/AllOf
+            // OneOf/AnyOf
+            if is_expanded() && !variants.is_empty() {
+                SchemaVariants {
+                    label: variants_label,
+                    variants,
+                    depth: props.depth,
+                }
+            }
+
+            // Not
             if is_expanded() {
-                if !schema.one_of.is_empty() {
+                if let Some(not) = &schema.not {
                     div { class: "mt-2 ml-4",
-                        span { class: "text-xs text-base-content/50 font-semibold", "One of:" }
-                        for (i, variant) in schema.one_of.iter().enumerate() {
-                            SchemaViewer {
-                                key: "{i}",
-                                schema: variant.clone(),
-                                depth: props.depth + 1,
-                            }
+                        span { class: "text-xs text-base-content/50 font-semibold", "Not:" }
+                        SchemaViewer {
+                            schema: (**not).clone(),
+                            depth: props.depth + 1,
                         }
                     }
                 }
-                if !schema.any_of.is_empty() {
-                    div { class: "mt-2 ml-4",
-                        span { class: "text-xs text-base-content/50 font-semibold", "Any of:" }
-                        for (i, variant) in schema.any_of.iter().enumerate() {
-                            SchemaViewer {
-                                key: "{i}",
-                                schema: variant.clone(),
-                                depth: props.depth + 1,
-                            }
+            }
+        }
+    }
+}
+
+/// Props for SchemaVariants component.
+#[derive(Props, Clone, PartialEq)]
+struct SchemaVariantsProps {
+    /// "One of" or "Any of".
+    label: &'static str,
+    /// Variant labels and schemas.
+    variants: Vec<(String, SchemaDefinition)>,
+    /// Depth of the schema owning the variants.
+    depth: usize,
+}
+
+/// `oneOf`/`anyOf` variants behind a tab strip, one shown at a time.
+#[component]
+fn SchemaVariants(props: SchemaVariantsProps) -> Element {
+    let mut selected = use_signal(|| 0usize);
+    let current = selected().min(props.variants.len().saturating_sub(1));
+
+    rsx! {
+        div { class: "mt-2 ml-4",
+            div { class: "flex items-center gap-1 flex-wrap",
+                span { class: "text-xs text-base-content/50 font-semibold mr-1", "{props.label}:" }
+                div { class: "flex gap-1 flex-wrap", role: "tablist",
+                    for (i, (label, _)) in props.variants.iter().enumerate() {
+                        button {
+                            key: "{i}",
+                            class: if i == current { "px-2 py-0.5 rounded text-xs font-mono cursor-pointer bg-primary/10 text-primary" } else { "px-2 py-0.5 rounded text-xs font-mono cursor-pointer text-base-content/60 hover:bg-base-300" },
+                            role: "tab",
+                            "aria-selected": i == current,
+                            onclick: move |_| selected.set(i),
+                            "{label}"
                         }
                     }
                 }
-                if !schema.all_of.is_empty() {
-                    div { class: "mt-2 ml-4",
-                        span { class: "text-xs text-base-content/50 font-semibold", "All of:" }
-                        for (i, variant) in schema.all_of.iter().enumerate() {
-                            SchemaViewer {
-                                key: "{i}",
-                                schema: variant.clone(),
-                                depth: props.depth + 1,
-                            }
-                        }
-                    }
+            }
+            if let Some((label, variant)) = props.variants.get(current) {
+                SchemaViewer {
+                    key: "{current}-{label}",
+                    schema: variant.clone(),
+                    depth: props.depth + 1,
+                    expanded: true,
                 }
             }
         }
@@ -211,11 +313,8 @@ pub struct SchemaTypeLabelProps {
 /// Simple type label without expand/collapse.
 #[component]
 pub fn SchemaTypeLabel(props: SchemaTypeLabelProps) -> Element {
-    let type_display = props.schema.display_type();
-
-    rsx! {
-        span { class: "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70 font-mono",
-            "{type_display}"
-        }
-    }
+    type_badge(
+        &props.schema,
+        "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70 font-mono",
+    )
 }
@@ -0,0 +1,185 @@
+//! Authorization display for API endpoints and spec overviews.
+
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+use dioxus_free_icons::{Icon, icons::ld_icons::*};
+
+use crate::parser::{SecurityRequirement, SecurityScheme, SecuritySchemeKind};
+
+/// Props for SecurityRequirements component.
+#[derive(Props, Clone, PartialEq)]
+pub struct SecurityRequirementsProps {
+    /// Alternative requirements; any one of them authorizes the request.
+    pub requirements: Vec<SecurityRequirement>,
+}
+
+/// How an endpoint is authorized: each alternative lists the schemes it
+/// combines and the scopes it needs.
+#[component]
+pub fn SecurityRequirements(props: SecurityRequirementsProps) -> Element {
+    if props.requirements.is_empty() {
+        return rsx! {};
+    }
+    let alternatives = props.requirements.len() > 1;
+
+    rsx! {
+        div { class: "space-y-3",
+            if alternatives {
+                p { class: "text-sm text-base-content/60", "Any one of the following:" }
+            }
+            for (i, requirement) in props.requirements.iter().enumerate() {
+                div {
+                    key: "{i}",
+                    class: "rounded-lg border border-base-300 divide-y divide-base-300",
+                    if requirement.schemes.is_empty() {
+                        p { class: "px-4 py-3 text-sm text-base-content/60", "No authorization" }
+                    }
+                    for required in requirement.schemes.iter() {
+                        SchemeDetails {
+                            key: "{required.name}",
+                            name: required.name.clone(),
+                            scheme: required.scheme.clone(),
+                            scopes: required.scopes.clone(),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Props for SecuritySummary component.
+#[derive(Props, Clone, PartialEq)]
+pub struct SecuritySummaryProps {
+    /// Every scheme the spec defines.
+    pub schemes: BTreeMap<String, SecurityScheme>,
+    /// The spec's default requirements, to mark which schemes apply by default.
+    #[props(default)]
+    pub requirements: Vec<SecurityRequirement>,
+}
+
+/// Overview of a spec's security schemes.
+#[component]
+pub fn SecuritySummary(props: SecuritySummaryProps) -> Element {
+    if props.schemes.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "mb-4",
+            span { class: "text-sm font-semibold text-base-content/60 flex items-center gap-2",
+                Icon { class: "size-4", icon: LdLock }
+                "Authentication"
+            }
+            div { class: "mt-2 rounded-lg border border-base-300 divide-y divide-base-300",
+                for (name, scheme) in props.schemes.iter() {
+                    {
+                        let default = props
+                            .requirements
+                            .iter()
+                            .any(|r| r.schemes.iter().any(|s| &s.name == name));
+                        rsx! {
+                            SchemeDetails {
+                                key: "{name}",
+                                name: name.clone(),
+                                scheme: scheme.clone(),
+                                default,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Props for SchemeDetails component.
+#[derive(Props, Clone, PartialEq)]
+struct SchemeDetailsProps {
+    name: String,
+    scheme: SecurityScheme,
+    /// Scopes an endpoint needs; empty on the overview, which lists every
+    /// scope the scheme offers instead.
+    #[props(default)]
+    scopes: Vec<String>,
+    /// Whether the scheme applies to every endpoint by default.
+    #[props(default)]
+    default: bool,
+}
+
+/// One security scheme: its type, where the credential goes, and scopes.
+#[component]
+fn SchemeDetails(props: SchemeDetailsProps) -> Element {
+    let scheme = &props.scheme;
+    let (location, key, _) = scheme.credential("");
+
+    rsx! {
+        div { class: "px-4 py-3 space-y-1.5",
+            div { class: "flex items-center gap-2 flex-wrap",
+                code { class: "font-mono font-semibold text-primary", "{props.name}" }
+                span { class: "text-xs px-2 py-0.5 rounded-full bg-base-300 text-base-content/70",
+                    "{scheme.label()}"
+                }
+                if props.default {
+                    span { class: "text-xs px-2 py-0.5 rounded-full bg-primary/10 text-primary",
+                        "default"
+                    }
+                }
+            }
+            p { class: "text-sm text-base-content/70",
+                "Sent in the "
+                span { class: "font-medium", "{location.as_str()}" }
+                " as "
+                code { class: "text-xs font-mono bg-base-200 px-1 py-0.5 rounded", "{key}" }
+            }
+            if let Some(desc) = &scheme.description {
+                p { class: "text-sm text-base-content/70", "{desc}" }
+            }
+            match &scheme.kind {
+                SecuritySchemeKind::OAuth2 { flows } => rsx! {
+                    for flow in flows.iter() {
+                        div { key: "{flow.flow}", class: "text-xs text-base-content/60 space-y-0.5",
+                            div { class: "font-medium text-base-content/70", "{flow.flow}" }
+                            if let Some(url) = &flow.authorization_url {
+                                div { "Authorization URL: " code { class: "font-mono", "{url}" } }
+                            }
+                            if let Some(url) = &flow.token_url {
+                                div { "Token URL: " code { class: "font-mono", "{url}" } }
+                            }
+                            if props.scopes.is_empty() && !flow.scopes.is_empty() {
+                                ul { class: "mt-1 space-y-0.5",
+                                    for (scope, desc) in flow.scopes.iter() {
+                                        li { key: "{scope}",
+                                            code { class: "font-mono text-base-content/80", "{scope}" }
+                                            if !desc.is_empty() { " — {desc}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                SecuritySchemeKind::OpenIdConnect { url } => rsx! {
+                    div { class: "text-xs text-base-content/60",
+                        "Discovery: "
+                        code { class: "font-mono", "{url}" }
+                    }
+                },
+                _ => rsx! {},
+            }
+            if !props.scopes.is_empty() {
+                div { class: "flex items-center gap-1.5 flex-wrap",
+                    span { class: "text-xs text-base-content/60", "Scopes:" }
+                    for scope in props.scopes.iter() {
+                        code {
+                            key: "{scope}",
+                            class: "text-xs font-mono px-1.5 py-0.5 rounded bg-base-200",
+                            "{scope}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
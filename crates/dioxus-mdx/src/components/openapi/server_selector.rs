@@ -0,0 +1,209 @@
+//! Server picker for endpoint pages.
+
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::parser::ApiServer;
+
+/// localStorage key of the server chosen on endpoint pages.
+const SERVER_KEY: &str = "dk-server";
+
+/// Base URL used when a spec declares no servers.
+const FALLBACK_URL: &str = "https://api.example.com";
+
+/// The server picked on endpoint pages and the values of its variables.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(super) struct ServerChoice {
+    /// Template URL of the chosen server, as written in the spec.
+    pub url: String,
+    /// Values entered for its variables.
+    pub variables: BTreeMap<String, String>,
+    /// URL typed into the "Try it" playground, e.g. a local mock server,
+    /// used instead of the chosen server until another one is picked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_url: Option<String>,
+}
+
+impl ServerChoice {
+    /// The chosen server among `servers`, or the first one when the choice
+    /// belongs to another spec.
+    pub fn server<'a>(&self, servers: &'a [ApiServer]) -> Option<&'a ApiServer> {
+        servers
+            .iter()
+            .find(|s| s.url == self.url)
+            .or(servers.first())
+    }
+
+    /// Base URL for request samples and the playground.
+    pub fn base_url(&self, servers: &[ApiServer]) -> String {
+        if let Some(url) = &self.custom_url {
+            return url.clone();
+        }
+        match self.server(servers) {
+            Some(server) => server.resolve_url(&self.variables),
+            None => FALLBACK_URL.to_string(),
+        }
+    }
+}
+
+/// The server choice shared by endpoint pages, restored from localStorage.
+pub(super) fn use_server_choice() -> Signal<ServerChoice> {
+    let mut choice = use_signal(ServerChoice::default);
+
+    use_effect(move || {
+        spawn(async move {
+            let mut eval = document::eval(&format!(
+                r#"
+                let choice = null;
+                try {{ choice = localStorage.getItem('{SERVER_KEY}'); }} catch(e) {{}}
+                dioxus.send(choice || '');
+                "#
+            ));
+            if let Ok(stored) = eval.recv::<String>().await
+                && let Ok(stored) = serde_json::from_str::<ServerChoice>(&stored)
+            {
+                choice.set(stored);
+            }
+        });
+    });
+
+    choice
+}
+
+/// Applies `change` to the shared choice and stores the result.
+pub(super) fn update_server_choice(
+    mut choice: Signal<ServerChoice>,
+    change: impl FnOnce(&mut ServerChoice),
+) {
+    let mut next = choice.peek().clone();
+    change(&mut next);
+    persist(&next);
+    choice.set(next);
+}
+
+fn persist(choice: &ServerChoice) {
+    let json = serde_json::to_string(choice).unwrap_or_default();
+    let _ = document::eval(&format!(
+        "try {{ localStorage.setItem('{SERVER_KEY}', {}); }} catch(e) {{}}",
+        serde_json::to_string(&json).unwrap_or_default()
+    ));
+}
+
+/// Props for ServerSelector component.
+#[derive(Props, Clone, PartialEq)]
+pub(super) struct ServerSelectorProps {
+    /// The spec's servers.
+    servers: Vec<ApiServer>,
+    /// Current choice, shared with the request samples.
+    choice: Signal<ServerChoice>,
+}
+
+/// Base URL row: a server dropdown when there are several servers, and an
+/// input per URL variable (a dropdown for enumerated ones).
+#[component]
+pub(super) fn ServerSelector(props: ServerSelectorProps) -> Element {
+    let choice = props.choice;
+    let current = choice.read().clone();
+    let base_url = current.base_url(&props.servers);
+    let server = current.server(&props.servers).cloned();
+
+    // Picking a server or a variable value drops any playground URL.
+    let update = move |change: &dyn Fn(&mut ServerChoice)| {
+        update_server_choice(choice, |c| {
+            change(c);
+            c.custom_url = None;
+        });
+    };
+
+    rsx! {
+        div { class: "mb-8 space-y-3",
+            div { class: "flex items-center gap-2 flex-wrap",
+                span { class: "text-xs text-base-content/50 font-semibold uppercase tracking-wider",
+                    "Base URL"
+                }
+                if props.servers.len() > 1 {
+                    select {
+                        class: "select select-xs select-bordered",
+                        "aria-label": "Server",
+                        onchange: move |e| {
+                            let url = e.value();
+                            update(&|c: &mut ServerChoice| {
+                                c.url = url.clone();
+                                c.variables.clear();
+                            });
+                        },
+                        for s in props.servers.iter() {
+                            option {
+                                key: "{s.url}",
+                                value: "{s.url}",
+                                selected: server.as_ref().is_some_and(|cur| cur.url == s.url),
+                                "{s.label()}"
+                            }
+                        }
+                    }
+                }
+                code { class: "text-sm font-mono text-base-content/70 bg-base-200 px-2 py-1 rounded break-all",
+                    "{base_url}"
+                }
+            }
+            if let Some(server) = server.filter(|s| !s.variables.is_empty()) {
+                div { class: "flex flex-wrap gap-3",
+                    for var in server.variables.iter() {
+                        {
+                            let name = var.name.clone();
+                            let value = current
+                                .variables
+                                .get(&var.name)
+                                .cloned()
+                                .unwrap_or_else(|| var.default.clone());
+                            let url = server.url.clone();
+                            rsx! {
+                                label {
+                                    key: "{var.name}",
+                                    class: "flex items-center gap-1.5 text-xs",
+                                    title: var.description.clone().unwrap_or_default(),
+                                    code { class: "font-mono text-base-content/70", "{var.name}" }
+                                    if var.enum_values.is_empty() {
+                                        input {
+                                            class: "input input-xs input-bordered w-32 font-mono",
+                                            placeholder: "{var.default}",
+                                            value: "{value}",
+                                            oninput: move |e| {
+                                                let value = e.value();
+                                                update(&|c: &mut ServerChoice| {
+                                                    c.url = url.clone();
+                                                    c.variables.insert(name.clone(), value.clone());
+                                                });
+                                            },
+                                        }
+                                    } else {
+                                        select {
+                                            class: "select select-xs select-bordered font-mono",
+                                            onchange: move |e| {
+                                                let value = e.value();
+                                                update(&|c: &mut ServerChoice| {
+                                                    c.url = url.clone();
+                                                    c.variables.insert(name.clone(), value.clone());
+                                                });
+                                            },
+                                            for option_value in var.enum_values.iter() {
+                                                option {
+                                                    key: "{option_value}",
+                                                    value: "{option_value}",
+                                                    selected: *option_value == value,
+                                                    "{option_value}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -5,10 +5,11 @@ use std::collections::BTreeMap;
 use dioxus::prelude::*;
 use dioxus_free_icons::{Icon, icons::ld_icons::*};
 
-use crate::parser::{ApiOperation, ApiTag, OpenApiSpec, SchemaDefinition};
+use crate::parser::{OpenApiSpec, SchemaDefinition};
 
 use super::schema_viewer::SchemaViewer;
-use super::tag_group::{TagGroup, UngroupedEndpoints};
+use super::security::SecuritySummary;
+use super::tag_group::TagGroup;
 
 /// Props for OpenApiViewer component.
 #[derive(Props, Clone, PartialEq)]
@@ -28,42 +29,47 @@ pub struct OpenApiViewerProps {
 pub fn OpenApiViewer(props: OpenApiViewerProps) -> Element {
     let spec = &props.spec;
 
-    // Group operations by tag
-    let (grouped_ops, ungrouped_ops) = group_operations_by_tag(&spec.operations, &spec.tags);
-
-    // Filter tags if specified
-    let filtered_groups: Vec<_> = if let Some(filter_tags) = &props.tags {
-        grouped_ops
-            .into_iter()
-            .filter(|(tag, _)| {
-                filter_tags
-                    .iter()
-                    .any(|t| t.eq_ignore_ascii_case(&tag.name))
-            })
-            .collect()
-    } else {
-        grouped_ops
-    };
+    // Group operations by tag, nested under `x-tagGroups` headings, keeping
+    // only the filtered tags if specified
+    let mut sections = spec.tag_sections();
+    if let Some(filter) = &props.tags {
+        for section in &mut sections {
+            section
+                .tags
+                .retain(|(tag, _)| filter.iter().any(|t| t.eq_ignore_ascii_case(&tag.name)));
+        }
+        sections.retain(|section| !section.tags.is_empty());
+    }
 
     rsx! {
         div { class: "openapi-viewer",
             // API Info header
             ApiInfoHeader { info: spec.info.clone(), servers: spec.servers.clone() }
 
+            // Security schemes
+            SecuritySummary {
+                schemes: spec.security_schemes.clone(),
+                requirements: spec.security.clone(),
+            }
+
             // Endpoints grouped by tag
             div { class: "mt-6",
-                for (tag, ops) in filtered_groups {
-                    TagGroup {
-                        key: "{tag.name}",
-                        tag: tag.clone(),
-                        operations: ops,
+                for section in sections {
+                    div { key: "{section.heading:?}",
+                        if let Some(heading) = section.heading {
+                            h2 { class: "text-sm font-semibold text-base-content/60 uppercase tracking-wider mt-8 mb-2",
+                                "{heading}"
+                            }
+                        }
+                        for (tag, ops) in section.tags {
+                            TagGroup {
+                                key: "{tag.name}",
+                                tag: tag.clone(),
+                                operations: ops.into_iter().cloned().collect::<Vec<_>>(),
+                            }
+                        }
                     }
                 }
-
-                // Ungrouped endpoints (only show if no tag filter)
-                if props.tags.is_none() {
-                    UngroupedEndpoints { operations: ungrouped_ops }
-                }
             }
 
             // Schema definitions
@@ -74,49 +80,6 @@ pub fn OpenApiViewer(props: OpenApiViewerProps) -> Element {
     }
 }
 
-/// Group operations by their tags.
-fn group_operations_by_tag(
-    operations: &[ApiOperation],
-    tags: &[ApiTag],
-) -> (Vec<(ApiTag, Vec<ApiOperation>)>, Vec<ApiOperation>) {
-    let mut grouped: BTreeMap<String, Vec<ApiOperation>> = BTreeMap::new();
-    let mut ungrouped = Vec::new();
-
-    for op in operations {
-        if op.tags.is_empty() {
-            ungrouped.push(op.clone());
-        } else {
-            for tag_name in &op.tags {
-                grouped
-                    .entry(tag_name.clone())
-                    .or_default()
-                    .push(op.clone());
-            }
-        }
-    }
-
-    // Convert to vec with tag metadata, preserving tag order from spec
-    let mut result = Vec::new();
-    for tag in tags {
-        if let Some(ops) = grouped.remove(&tag.name) {
-            result.push((tag.clone(), ops));
-        }
-    }
-
-    // Add any remaining tags that weren't in the spec's tag list
-    for (tag_name, ops) in grouped {
-        result.push((
-            ApiTag {
-                name: tag_name,
-                description: None,
-            },
-            ops,
-        ));
-    }
-
-    (result, ungrouped)
-}
-
 /// Props for ApiInfoHeader component.
 #[derive(Props, Clone, PartialEq)]
 pub struct ApiInfoHeaderProps {
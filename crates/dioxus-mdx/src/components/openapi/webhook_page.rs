@@ -0,0 +1,183 @@
+//! Two-column reference page for a webhook or operation callback.
+
+use dioxus::prelude::*;
+
+use crate::parser::{ApiOperation, ApiRequestBody, ApiWebhook, WebhookKind};
+
+use super::endpoint_page::code_sample;
+use super::parameters_list::ParametersList;
+use super::request_body::RequestBodySection;
+use super::responses_list::ResponsesList;
+use super::security::SecurityRequirements;
+
+/// Props for WebhookPage component.
+#[derive(Props, Clone, PartialEq)]
+pub struct WebhookPageProps {
+    /// The webhook or callback to display.
+    pub webhook: ApiWebhook,
+}
+
+/// Full-page layout for a webhook or callback, mirroring [`super::EndpointPage`].
+///
+/// Left column: kind, name, and per request its method, description,
+/// parameters, payload and the responses the receiver should send.
+/// Right column (sticky): an example payload per request.
+#[component]
+pub fn WebhookPage(props: WebhookPageProps) -> Element {
+    let webhook = &props.webhook;
+    let (kind_label, registered_by) = match &webhook.kind {
+        WebhookKind::Webhook => ("Webhook", None),
+        WebhookKind::Callback { operation } => ("Callback", Some(operation.as_str())),
+    };
+
+    rsx! {
+        div { class: "flex flex-col lg:flex-row gap-0",
+            // Left column — scrollable content
+            div { class: "flex-1 min-w-0 px-8 py-12 lg:px-12",
+                div { class: "max-w-2xl",
+                    div { class: "flex items-center gap-3 mb-6",
+                        span { class: "badge badge-outline badge-sm uppercase tracking-wider",
+                            "{kind_label}"
+                        }
+                        code { class: "font-mono text-lg text-base-content", "{webhook.name}" }
+                    }
+
+                    h1 { class: "text-3xl font-bold tracking-tight mb-3", "{webhook.title()}" }
+
+                    if let Some(operation) = registered_by {
+                        p { class: "text-sm text-base-content/60 mb-6",
+                            "Sent to the URL registered with "
+                            code { class: "font-mono", "{operation}" }
+                            "."
+                        }
+                    }
+
+                    for op in webhook.operations.iter() {
+                        WebhookRequest {
+                            key: "{op.method.as_str()} {op.path}",
+                            operation: op.clone(),
+                            callback: registered_by.is_some(),
+                        }
+                    }
+                }
+            }
+
+            // Right column — sticky payload examples
+            aside { class: "lg:w-[45%] lg:shrink-0 lg:border-l border-base-300 bg-base-200/20",
+                div { class: "lg:sticky lg:top-16 lg:h-[calc(100vh-4rem)] lg:overflow-y-auto p-6 space-y-6",
+                    for op in webhook.operations.iter() {
+                        if let Some(payload) = op.request_body.as_ref().and_then(payload_example) {
+                            div { key: "{op.method.as_str()} {op.path}",
+                                h3 { class: "text-sm font-semibold text-base-content/70 uppercase tracking-wider mb-3",
+                                    "Payload"
+                                }
+                                div { class: "rounded-lg border border-base-300 overflow-hidden",
+                                    div { class: "px-3 py-2 bg-base-300/50 border-b border-base-300 flex items-center gap-2",
+                                        span { class: "badge badge-sm font-mono font-bold {op.method.badge_class()}",
+                                            "{op.method.as_str()}"
+                                        }
+                                        span { class: "text-xs text-base-content/50", "{payload.0}" }
+                                    }
+                                    div { class: "dk-code-block-body bg-base-200 max-h-[60vh] overflow-y-auto",
+                                        {code_sample(payload.1, "json")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Props for WebhookRequest component.
+#[derive(Props, Clone, PartialEq)]
+struct WebhookRequestProps {
+    operation: ApiOperation,
+    /// Whether `operation.path` is a callback URL expression worth showing.
+    callback: bool,
+}
+
+/// One request of a webhook: method, payload and expected responses.
+#[component]
+fn WebhookRequest(props: WebhookRequestProps) -> Element {
+    let op = &props.operation;
+    let method_bg = op.method.bg_class();
+
+    rsx! {
+        section { class: "mb-10",
+            div { class: "flex items-center gap-3 mb-4",
+                span { class: "px-3 py-1.5 rounded-lg font-mono text-sm font-bold border {method_bg}",
+                    "{op.method.as_str()}"
+                }
+                if props.callback {
+                    code { class: "font-mono text-sm text-base-content/70 break-all", "{op.path}" }
+                }
+                if op.deprecated {
+                    span { class: "badge badge-warning badge-sm", "deprecated" }
+                }
+            }
+
+            if let Some(desc) = &op.description {
+                p { class: "text-base text-base-content/70 mb-6 leading-relaxed", "{desc}" }
+            }
+
+            if !op.security.is_empty() {
+                div { class: "mb-8",
+                    h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                        "Authorization"
+                    }
+                    SecurityRequirements { requirements: op.security.clone() }
+                }
+            }
+
+            if !op.parameters.is_empty() {
+                div { class: "mb-8",
+                    h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                        "Parameters"
+                    }
+                    ParametersList { parameters: op.parameters.clone() }
+                }
+            }
+
+            if let Some(body) = &op.request_body {
+                div { class: "mb-8",
+                    h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                        "Payload"
+                    }
+                    RequestBodySection { body: body.clone() }
+                }
+            }
+
+            if !op.responses.is_empty() {
+                div { class: "mb-8",
+                    h2 { class: "text-lg font-semibold mb-4 pb-2 border-b border-base-300",
+                        "Expected responses"
+                    }
+                    ResponsesList { responses: op.responses.clone() }
+                }
+            }
+        }
+    }
+}
+
+/// Media type and example of a payload: the spec's first named example, its
+/// single `example`, or one generated from the schema. JSON media types win.
+fn payload_example(body: &ApiRequestBody) -> Option<(String, String)> {
+    let content = body
+        .content
+        .iter()
+        .find(|c| c.media_type.contains("json"))
+        .or(body.content.first())?;
+    let example = content
+        .examples
+        .iter()
+        .find_map(|e| e.value.clone())
+        .or_else(|| content.example.clone())
+        .or_else(|| {
+            let schema = content.schema.as_ref()?;
+            serde_json::to_string_pretty(&schema.generate_example_json(0)).ok()
+        })?;
+    Some((content.media_type.clone(), example))
+}
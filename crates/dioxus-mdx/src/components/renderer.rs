@@ -4,11 +4,13 @@ use std::sync::LazyLock;
 
 use dioxus::prelude::*;
 
+use super::inline::markdown_to_html;
 use super::slugify;
 use crate::components::{
-    DocAccordionGroup, DocCallout, DocCardGroup, DocCodeBlock, DocCodeGroup, DocExpandable,
-    DocParamField, DocRequestExample, DocResponseExample, DocResponseField, DocSteps, DocTabs,
-    DocUpdate, OpenApiViewer,
+    DocAccordionGroup, DocApiEndpoint, DocCallout, DocCardGroup, DocCodeBlock, DocCodeGroup,
+    DocDataTable, DocDefinitionList, DocEmbed, DocExpandable, DocFileTree, DocFootnotes,
+    DocParamField, DocRequestExample, DocResponseExample, DocResponseField, DocSchema, DocSteps,
+    DocTabs, DocTerminal, DocUpdate, DocVideo, DocYouTube, GlossaryContext, OpenApiViewer,
 };
 use crate::parser::{CardGroupNode, DocNode, parse_mdx};
 
@@ -41,10 +43,11 @@ pub struct DocNodeRendererProps {
 /// Render a single DocNode.
 #[component]
 pub fn DocNodeRenderer(props: DocNodeRendererProps) -> Element {
+    let glossary = try_use_context::<GlossaryContext>();
+
     match &props.node {
         DocNode::Markdown(md) => {
-            let html = markdown::to_html_with_options(md, &markdown::Options::gfm())
-                .unwrap_or_else(|_| md.clone());
+            let html = markdown_to_html(md, glossary.as_ref());
             let html = inject_heading_ids(&html);
             rsx! {
                 div {
@@ -141,6 +144,58 @@ pub fn DocNodeRenderer(props: DocNodeRendererProps) -> Element {
                 }
             }
         }
+        DocNode::FileTree(tree) => {
+            rsx! {
+                DocFileTree { tree: tree.clone() }
+            }
+        }
+        DocNode::ApiEndpoint(endpoint) => {
+            rsx! {
+                DocApiEndpoint { endpoint: endpoint.clone() }
+            }
+        }
+        DocNode::Schema(schema) => {
+            rsx! {
+                DocSchema { schema: schema.clone() }
+            }
+        }
+        DocNode::Video(video) => {
+            rsx! {
+                DocVideo { video: video.clone() }
+            }
+        }
+        DocNode::YouTube(video) => {
+            rsx! {
+                DocYouTube { video: video.clone() }
+            }
+        }
+        DocNode::Embed(embed) => {
+            rsx! {
+                DocEmbed { embed: embed.clone() }
+            }
+        }
+        DocNode::DefinitionList(list) => {
+            rsx! {
+                DocDefinitionList { list: list.clone() }
+            }
+        }
+        DocNode::Footnotes(footnotes) => {
+            rsx! {
+                DocFootnotes { footnotes: footnotes.clone() }
+            }
+        }
+        // Occurrences already carry their expansion as `<abbr>` tags.
+        DocNode::Abbreviations(_) => rsx! {},
+        DocNode::Terminal(terminal) => {
+            rsx! {
+                DocTerminal { terminal: terminal.clone() }
+            }
+        }
+        DocNode::DataTable(table) => {
+            rsx! {
+                DocDataTable { table: table.clone() }
+            }
+        }
     }
 }
 
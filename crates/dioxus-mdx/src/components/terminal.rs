@@ -0,0 +1,150 @@
+//! Terminal session component.
+
+use dioxus::prelude::*;
+
+use super::code::CopyButton;
+use crate::parser::{TerminalLine, TerminalNode};
+
+/// Props for DocTerminal component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DocTerminalProps {
+    /// Terminal session data.
+    pub terminal: TerminalNode,
+}
+
+/// A line as currently shown: the visible part of its text, and whether the
+/// typing cursor sits at its end.
+struct ShownLine<'a> {
+    line: &'a TerminalLine,
+    text: String,
+    cursor: bool,
+}
+
+/// Terminal window showing prompts, commands and output.
+///
+/// The copy button copies only the commands. Prompts are `select-none`, so a
+/// manual selection skips them too. With `animate`, commands are typed out
+/// once on the client (skipped under `prefers-reduced-motion`); server
+/// rendering always emits the full session.
+#[component]
+pub fn DocTerminal(props: DocTerminalProps) -> Element {
+    let copied = use_signal(|| false);
+    // Command characters typed so far; `None` shows everything.
+    #[allow(unused_mut)]
+    let mut typed = use_signal(|| None::<usize>);
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let animate = props.terminal.animate;
+        let total: usize = props
+            .terminal
+            .lines
+            .iter()
+            .map(|line| match line {
+                TerminalLine::Command { command, .. } => command.chars().count(),
+                TerminalLine::Output(_) => 0,
+            })
+            .sum();
+        use_effect(move || {
+            if !animate {
+                return;
+            }
+            spawn(async move {
+                let reduced = document::eval(
+                    "return window.matchMedia('(prefers-reduced-motion: reduce)').matches",
+                )
+                .join::<bool>()
+                .await
+                .unwrap_or(false);
+                if reduced {
+                    return;
+                }
+                for n in 0..=total {
+                    typed.set(Some(n));
+                    gloo_timers::future::TimeoutFuture::new(35).await;
+                }
+                typed.set(None);
+            });
+        });
+    }
+
+    let shown = shown_lines(&props.terminal.lines, typed());
+    let commands = props.terminal.commands_text();
+
+    rsx! {
+        div { class: "dk-terminal not-prose my-6 relative group rounded-lg border border-base-content/10 overflow-hidden bg-neutral text-neutral-content",
+            div { class: "flex items-center gap-2 px-4 py-2 border-b border-neutral-content/10",
+                span { class: "size-3 rounded-full bg-error/70" }
+                span { class: "size-3 rounded-full bg-warning/70" }
+                span { class: "size-3 rounded-full bg-success/70" }
+                span { class: "flex-1 text-center text-xs font-mono text-neutral-content/60 truncate",
+                    if let Some(title) = &props.terminal.title {
+                        "{title}"
+                    }
+                }
+                if !commands.is_empty() {
+                    CopyButton { code: commands.clone(), copied }
+                }
+            }
+            pre { class: "px-4 py-3 font-mono text-sm leading-relaxed overflow-x-auto",
+                for (i, shown) in shown.iter().enumerate() {
+                    div { key: "{i}", class: "whitespace-pre",
+                        match shown.line {
+                            TerminalLine::Command { prompt, .. } => rsx! {
+                                span { class: "select-none text-success", "{prompt} " }
+                                span { "{shown.text}" }
+                            },
+                            TerminalLine::Output(_) => rsx! {
+                                span { class: "text-neutral-content/70", "{shown.text}" }
+                            },
+                        }
+                        if shown.cursor {
+                            span { class: "inline-block w-2 h-4 align-text-bottom bg-neutral-content/70 animate-pulse" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Lines visible after `typed` command characters; all of them for `None`.
+///
+/// Output appears once the command before it is fully typed.
+fn shown_lines(lines: &[TerminalLine], typed: Option<usize>) -> Vec<ShownLine<'_>> {
+    let mut budget = typed;
+    let mut shown = Vec::new();
+
+    for line in lines {
+        match line {
+            TerminalLine::Command { command, .. } => {
+                let len = command.chars().count();
+                let visible = budget.map_or(len, |b| b.min(len));
+                let cursor = budget.is_some_and(|b| b <= len);
+                shown.push(ShownLine {
+                    line,
+                    text: command.chars().take(visible).collect(),
+                    cursor,
+                });
+                if let Some(b) = budget.as_mut() {
+                    if *b <= len {
+                        break;
+                    }
+                    *b -= len;
+                }
+            }
+            TerminalLine::Output(output) => shown.push(ShownLine {
+                line,
+                // A space keeps blank output lines from collapsing.
+                text: if output.is_empty() {
+                    " ".to_string()
+                } else {
+                    output.clone()
+                },
+                cursor: false,
+            }),
+        }
+    }
+
+    shown
+}
@@ -60,6 +60,15 @@
 //! - **API Docs**: `<ParamField>`, `<ResponseField>`, `<Expandable>`
 //! - **Examples**: `<RequestExample>`, `<ResponseExample>`
 //! - **Changelog**: `<Update>`
+//! - **File Tree**: `<FileTree>`
+//! - **API References**: `<ApiEndpoint>`, `<Schema>` (resolved by the host)
+//! - **Media**: `<Video>`, `<YouTube>`, `<Embed>` (click-to-load facades)
+//! - **Terminal**: `<Terminal>`, ```` ```console ```` fences
+//! - **Term**: inline `<Term id="...">` glossary tooltips (see [`GlossaryContext`])
+//! - **Footnotes**: `[^label]` references with namespaced ids and back-references
+//! - **Definition lists**: `Term` followed by `: definition` lines
+//! - **Abbreviations**: `*[API]: Application Programming Interface`
+//! - **Data Tables**: `<DataTable src="data/errors.csv" />` (loaded by the host)
 //!
 //! ## Styling
 //!
@@ -72,6 +81,8 @@
 //!
 //! ## Features
 //!
+//! - `components` (default): The Dioxus components. Without it only the
+//!   parsers are built, with no `dioxus` dependency.
 //! - `web` (default): Enables web-specific features like clipboard copy
 //!
 //! ## Custom Link Handling
@@ -95,42 +106,56 @@
 //! }
 //! ```
 
+#[cfg(feature = "components")]
 pub mod components;
 pub mod parser;
 
 // Re-export parser types and functions
 pub use parser::{
-    AccordionGroupNode, AccordionNode, ApiInfo, ApiOperation, ApiParameter, ApiRequestBody,
-    ApiResponse, ApiServer, ApiTag, CalloutNode, CalloutType, CardGroupNode, CardNode,
-    CodeBlockNode, CodeGroupNode, DocFrontmatter, DocNode, ExpandableNode, HttpMethod,
-    MediaTypeContent, OpenApiError, OpenApiNode, OpenApiSpec, ParamFieldNode, ParamLocation,
-    ParameterLocation, ParsedDoc, RequestExampleNode, ResponseExampleNode, ResponseFieldNode,
-    SchemaDefinition, SchemaType, StepNode, StepsNode, TabNode, TabsNode, UpdateNode,
-    extract_frontmatter, get_raw_markdown, parse_document, parse_mdx, parse_openapi,
-    strip_leading_h1,
+    Abbreviation, AccordionGroupNode, AccordionNode, ApiChange, ApiChangeKind, ApiEndpointNode,
+    ApiInfo, ApiOperation, ApiParameter, ApiRequestBody, ApiResponse, ApiServer, ApiTag,
+    ApiTagGroup, ApiWebhook, AsyncApiChannel, AsyncApiError, AsyncApiMessage, AsyncApiServer,
+    AsyncApiSpec, CalloutNode, CalloutType, CardGroupNode, CardNode, ChangeTarget,
+    ChannelOperation, CodeBlockNode, CodeGroupNode, CodeSample, ColumnType, DataColumn,
+    DataTableError, DataTableNode, DefinitionItem, DefinitionListNode, DocFrontmatter, DocNode,
+    EmbedNode, ExampleViolation, ExpandableNode, FileTreeEntry, FileTreeNode, FootnoteItem,
+    FootnotesNode, GlossaryEntry, HttpMethod, MediaTypeContent, MessageExample, NamedExample,
+    OAuthFlow, OpenApiError, OpenApiNode, OpenApiSpec, OperationAction, ParamFieldNode,
+    ParamLocation, ParameterLocation, ParsedDoc, PlaygroundError, PlaygroundRequest,
+    RequestExampleNode, RequiredScheme, ResponseExampleNode, ResponseExamples, ResponseFieldNode,
+    ResponseLink, SampleLanguage, SchemaDefinition, SchemaNode, SchemaType, SchemaViolation,
+    SecurityRequirement, SecurityScheme, SecuritySchemeKind, ServerVariable, SpecDiff, StepNode,
+    StepsNode, TabNode, TableData, TabsNode, TagSection, TermRef, TerminalLine, TerminalNode,
+    UpdateNode, VideoNode, WebhookKind, YouTubeNode, bundle_openapi_files, code_spans, diff_specs,
+    extract_frontmatter, find_terms, get_raw_markdown, parse_asyncapi, parse_document, parse_mdx,
+    parse_openapi, parse_openapi_bundle, strip_leading_h1,
 };
 
-// Re-export the syntax-highlighting theme types so consumers can build a
-// `CodeThemeOverride` without depending on `dioxus-code` directly. Only available
-// with the `highlight` feature (default), which pulls in `dioxus-code`.
+// Re-export the syntax-highlighting types so consumers can build a
+// `CodeThemeOverride` or `LanguageAliases` without depending on `dioxus-code`
+// directly. Only available with the `highlight` feature (default), which pulls
+// in `dioxus-code`.
 #[cfg(feature = "highlight")]
-pub use dioxus_code::{CodeTheme, Theme};
+pub use dioxus_code::{CodeTheme, Language, Theme};
 
 // Re-export components
+#[cfg(feature = "components")]
 pub use components::{
-    ApiInfoHeader, DocAccordionGroup, DocAccordionItem, DocCallout, DocCard, DocCardGroup,
-    DocCodeBlock, DocCodeGroup, DocContent, DocExpandable, DocNodeRenderer, DocParamField,
-    DocRequestExample, DocResponseExample, DocResponseField, DocSteps, DocTableOfContents, DocTabs,
-    DocUpdate, EndpointCard, EndpointPage, MdxContent, MdxIcon, MdxRenderer, MethodBadge,
-    OpenApiViewer, ParameterItem, ParametersList, RequestBodySection, ResponseItem, ResponsesList,
-    SchemaDefinitions, SchemaTypeLabel, SchemaViewer, TagGroup, UngroupedEndpoints,
-    extract_headers, slugify,
+    ApiChangelog, ApiInfoHeader, ChannelPage, DocAccordionGroup, DocAccordionItem, DocApiEndpoint,
+    DocCallout, DocCard, DocCardGroup, DocCodeBlock, DocCodeGroup, DocContent, DocDataTable,
+    DocDefinitionList, DocEmbed, DocExpandable, DocFileTree, DocFootnotes, DocNodeRenderer,
+    DocParamField, DocRequestExample, DocResponseExample, DocResponseField, DocSchema, DocSteps,
+    DocTableOfContents, DocTabs, DocTerminal, DocUpdate, DocVideo, DocYouTube, EndpointCard,
+    EndpointPage, GlossaryContext, MdxContent, MdxIcon, MdxRenderer, MethodBadge, OpenApiViewer,
+    ParameterItem, ParametersList, RequestBodySection, ResponseItem, ResponsesList,
+    SchemaDefinitions, SchemaLinks, SchemaPage, SchemaTypeLabel, SchemaViewer, TagGroup,
+    UngroupedEndpoints, WebhookPage, extract_headers, slugify,
 };
 
-// `CodeThemeOverride` wraps a `dioxus-code` type, so it's only available with the
-// `highlight` feature (default).
+// `CodeThemeOverride` and `LanguageAliases` wrap `dioxus-code` types, so they're
+// only available with the `highlight` feature (default).
 #[cfg(feature = "highlight")]
-pub use components::CodeThemeOverride;
+pub use components::{CodeThemeOverride, LanguageAliases};
 
 #[cfg(feature = "mermaid")]
 pub use components::MermaidDiagram;
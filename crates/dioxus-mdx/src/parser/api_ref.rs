@@ -0,0 +1,136 @@
+//! Inline OpenAPI reference parsers.
+//!
+//! Handles `<ApiEndpoint operationId="listPets" />` and `<Schema name="Pet" />`.
+//! Both take an optional `spec="<prefix>"` to pick one of several registered
+//! specs. Resolution happens later, against the specs the host knows about.
+
+use super::utils::{extract_attr, self_closing_tag};
+use crate::parser::openapi_types::{SchemaDefinition, SchemaType};
+use crate::parser::types::*;
+
+/// Try to parse an `<ApiEndpoint operationId="..." />` reference.
+pub(super) fn try_parse_api_endpoint(content: &str) -> Option<(DocNode, &str)> {
+    let (attrs, rest) = self_closing_tag(content, "ApiEndpoint")?;
+    let operation_id = extract_attr(attrs, "operationId")?;
+
+    Some((
+        DocNode::ApiEndpoint(ApiEndpointNode {
+            operation_id,
+            spec: extract_attr(attrs, "spec"),
+            operation: None,
+            href: None,
+        }),
+        rest,
+    ))
+}
+
+/// Try to parse a `<Schema name="..." />` reference.
+pub(super) fn try_parse_schema(content: &str) -> Option<(DocNode, &str)> {
+    let (attrs, rest) = self_closing_tag(content, "Schema")?;
+    let name = extract_attr(attrs, "name")?;
+
+    Some((
+        DocNode::Schema(SchemaNode {
+            name,
+            spec: extract_attr(attrs, "spec"),
+            schema: None,
+        }),
+        rest,
+    ))
+}
+
+/// Plain-text summary of a schema for the raw-markdown fallback.
+pub(super) fn schema_to_markdown(name: &str, schema: &SchemaDefinition) -> String {
+    let mut out = format!("**{name}** _{}_\n\n", schema.display_type());
+    if let Some(desc) = &schema.description {
+        out.push_str(desc);
+        out.push_str("\n\n");
+    }
+    if matches!(schema.schema_type, SchemaType::Object) && !schema.properties.is_empty() {
+        for (prop, prop_schema) in &schema.properties {
+            let required = if schema.required.contains(prop) {
+                " *(required)*"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "- `{prop}` _{}_{required}",
+                prop_schema.display_type()
+            ));
+            if let Some(desc) = &prop_schema.description {
+                out.push_str(&format!(": {desc}"));
+            }
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::content::{get_raw_markdown, parse_mdx};
+
+    #[test]
+    fn parses_api_endpoint_with_optional_spec() {
+        let (node, rest) =
+            try_parse_api_endpoint("<ApiEndpoint operationId=\"listPets\" spec=\"api\" />after")
+                .unwrap();
+        match node {
+            DocNode::ApiEndpoint(ep) => {
+                assert_eq!(ep.operation_id, "listPets");
+                assert_eq!(ep.spec.as_deref(), Some("api"));
+                assert!(ep.operation.is_none());
+            }
+            other => panic!("expected ApiEndpoint, got {other:?}"),
+        }
+        assert_eq!(rest, "after");
+    }
+
+    #[test]
+    fn parses_schema_and_rejects_longer_tag_names() {
+        let (node, _) = try_parse_schema("<Schema name=\"Pet\"/>").unwrap();
+        assert!(matches!(node, DocNode::Schema(s) if s.name == "Pet" && s.spec.is_none()));
+        assert!(try_parse_schema("<SchemaViewer name=\"Pet\" />").is_none());
+        // The identifying attribute is required.
+        assert!(try_parse_schema("<Schema />").is_none());
+    }
+
+    #[test]
+    fn references_are_found_inside_containers() {
+        let nodes = parse_mdx(
+            "Intro\n\n<Tabs>\n<Tab title=\"A\">\n<ApiEndpoint operationId=\"listPets\" />\n</Tab>\n</Tabs>",
+        );
+        let DocNode::Tabs(tabs) = &nodes[1] else {
+            panic!("expected Tabs, got {nodes:?}");
+        };
+        assert!(matches!(tabs.tabs[0].content[0], DocNode::ApiEndpoint(_)));
+    }
+
+    #[test]
+    fn raw_markdown_uses_resolved_data_when_present() {
+        let mut nodes = parse_mdx("<Schema name=\"Pet\" />");
+        assert_eq!(get_raw_markdown(&nodes), "`Pet`\n\n");
+
+        let DocNode::Schema(node) = &mut nodes[0] else {
+            panic!("expected Schema");
+        };
+        let mut schema = SchemaDefinition {
+            schema_type: SchemaType::Object,
+            required: vec!["id".to_string()],
+            ..Default::default()
+        };
+        schema.properties.insert(
+            "id".to_string(),
+            SchemaDefinition {
+                schema_type: SchemaType::Integer,
+                ..Default::default()
+            },
+        );
+        node.schema = Some(schema);
+        let raw = get_raw_markdown(&nodes);
+        assert!(raw.starts_with("**Pet** _object_"), "got: {raw}");
+        assert!(raw.contains("- `id` _integer_ *(required)*"), "got: {raw}");
+    }
+}
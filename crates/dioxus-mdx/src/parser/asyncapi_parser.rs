@@ -0,0 +1,614 @@
+//! AsyncAPI specification parser.
+//!
+//! Parses AsyncAPI 2.x and 3.x YAML or JSON documents into [`AsyncApiSpec`].
+//! The document is walked as a `serde_yaml::Value`, whose mappings keep the
+//! spec's order; payload, header and parameter schemas go through the OpenAPI
+//! schema transform (see [`JsonSchemas`]).
+
+use serde_yaml::Value;
+
+use super::asyncapi_types::*;
+use super::openapi_parser::{JsonSchemas, OpenApiError, format_json_value};
+use super::openapi_types::{ApiInfo, ApiParameter, ParameterLocation, SchemaDefinition};
+
+/// Most `$ref`s followed in a row before giving up on a reference chain.
+const MAX_REF_HOPS: usize = 8;
+
+/// Error type for AsyncAPI parsing.
+#[derive(Debug, Clone)]
+pub enum AsyncApiError {
+    /// YAML/JSON parsing failed.
+    ParseError(String),
+    /// Invalid or unsupported spec structure.
+    InvalidSpec(String),
+}
+
+impl std::fmt::Display for AsyncApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ParseError(msg) => write!(f, "Parse error: {}", msg),
+            Self::InvalidSpec(msg) => write!(f, "Invalid spec: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AsyncApiError {}
+
+/// Parse an AsyncAPI 2.x or 3.x specification from YAML or JSON content.
+///
+/// Only local `$ref`s (`#/components/...`, `#/channels/...`) are resolved.
+pub fn parse_asyncapi(content: &str) -> Result<AsyncApiSpec, AsyncApiError> {
+    // YAML is a superset of JSON, so one parser covers both.
+    let root: Value = serde_yaml::from_str(content).map_err(|e| {
+        let format = if content.trim_start().starts_with('{') {
+            "JSON"
+        } else {
+            "YAML"
+        };
+        AsyncApiError::ParseError(format!("{format}: {e}"))
+    })?;
+
+    let version = root
+        .get("asyncapi")
+        .and_then(Value::as_str)
+        .ok_or_else(|| AsyncApiError::InvalidSpec("missing `asyncapi` version".to_string()))?;
+    let v3 = match version.split('.').next() {
+        Some("2") => false,
+        Some("3") => true,
+        _ => {
+            return Err(AsyncApiError::InvalidSpec(format!(
+                "unsupported AsyncAPI version {version}"
+            )));
+        }
+    };
+
+    let components = root
+        .get("components")
+        .and_then(|c| c.get("schemas"))
+        .map(to_json)
+        .unwrap_or_default();
+    // The schemas go through the OpenAPI transform; report its errors as
+    // AsyncAPI ones.
+    let schemas = JsonSchemas::new(components).map_err(|e| match e {
+        OpenApiError::ParseError(msg) | OpenApiError::InvalidSpec(msg) => {
+            AsyncApiError::InvalidSpec(format!("components.schemas: {msg}"))
+        }
+    })?;
+
+    let parser = Parser {
+        root: &root,
+        schemas: &schemas,
+        default_content_type: text(&root, "defaultContentType"),
+    };
+
+    let info = root.get("info");
+    let info = ApiInfo {
+        title: info.and_then(|i| text(i, "title")).unwrap_or_default(),
+        version: info.and_then(|i| text(i, "version")).unwrap_or_default(),
+        description: info.and_then(|i| text(i, "description")),
+    };
+
+    let servers = entries(root.get("servers"))
+        .map(|(name, server)| {
+            let server = parser.resolve(server);
+            let url = match text(server, "url") {
+                Some(url) => url,
+                None => format!(
+                    "{}{}",
+                    text(server, "host").unwrap_or_default(),
+                    text(server, "pathname").unwrap_or_default()
+                ),
+            };
+            AsyncApiServer {
+                name: name.to_string(),
+                url,
+                protocol: text(server, "protocol").unwrap_or_default(),
+                description: text(server, "description"),
+            }
+        })
+        .collect();
+
+    let channels = if v3 {
+        parser.channels_v3()
+    } else {
+        parser.channels_v2()
+    };
+
+    Ok(AsyncApiSpec {
+        info,
+        servers,
+        channels,
+        schemas: schemas.components(),
+    })
+}
+
+/// Shared state of one parse.
+struct Parser<'a> {
+    root: &'a Value,
+    schemas: &'a JsonSchemas,
+    default_content_type: Option<String>,
+}
+
+impl<'a> Parser<'a> {
+    /// 2.x: channels keyed by address, each with optional `publish` and
+    /// `subscribe` operations whose `message` may be a `oneOf`.
+    fn channels_v2(&self) -> Vec<AsyncApiChannel> {
+        entries(self.root.get("channels"))
+            .map(|(address, item)| {
+                let item = self.resolve(item);
+                let mut operations = Vec::new();
+                let mut messages: Vec<AsyncApiMessage> = Vec::new();
+
+                for (key, action) in [
+                    ("publish", OperationAction::Publish),
+                    ("subscribe", OperationAction::Subscribe),
+                ] {
+                    let Some(op) = item.get(key).map(|op| self.resolve(op)) else {
+                        continue;
+                    };
+                    let id = text(op, "operationId");
+                    let declared: Vec<&Value> = match op.get("message") {
+                        Some(message) => match self.resolve(message).get("oneOf") {
+                            Some(Value::Sequence(variants)) => variants.iter().collect(),
+                            _ => vec![message],
+                        },
+                        None => Vec::new(),
+                    };
+
+                    let mut names = Vec::new();
+                    for message in declared {
+                        let fallback = id.clone().unwrap_or_else(|| key.to_string());
+                        let message = self.message(message, None, &fallback);
+                        names.push(message.name.clone());
+                        if !messages.iter().any(|m| m.name == message.name) {
+                            messages.push(message);
+                        }
+                    }
+
+                    operations.push(ChannelOperation {
+                        id,
+                        action,
+                        summary: text(op, "summary"),
+                        description: text(op, "description"),
+                        messages: names,
+                    });
+                }
+
+                AsyncApiChannel {
+                    id: address.to_string(),
+                    address: address.to_string(),
+                    title: None,
+                    description: text(item, "description"),
+                    parameters: self.parameters(item),
+                    operations,
+                    messages,
+                }
+            })
+            .collect()
+    }
+
+    /// 3.x: channels keyed by id with an `address` and a map of messages;
+    /// operations are declared separately and point at their channel.
+    fn channels_v3(&self) -> Vec<AsyncApiChannel> {
+        let mut channels: Vec<(&Value, AsyncApiChannel)> = entries(self.root.get("channels"))
+            .map(|(id, item)| {
+                let item = self.resolve(item);
+                let messages = entries(item.get("messages"))
+                    .map(|(key, message)| self.message(message, Some(key), key))
+                    .collect();
+                let channel = AsyncApiChannel {
+                    id: id.to_string(),
+                    address: text(item, "address").unwrap_or_else(|| id.to_string()),
+                    title: text(item, "title"),
+                    description: text(item, "description").or_else(|| text(item, "summary")),
+                    parameters: self.parameters(item),
+                    operations: Vec::new(),
+                    messages,
+                };
+                (item, channel)
+            })
+            .collect();
+
+        for (id, op) in entries(self.root.get("operations")) {
+            let op = self.resolve(op);
+            let Some(action) = text(op, "action").and_then(|a| OperationAction::parse(&a)) else {
+                continue;
+            };
+            let Some(target) = op.get("channel").map(|c| self.resolve(c)) else {
+                continue;
+            };
+            let Some((item, channel)) = channels
+                .iter_mut()
+                .find(|(item, _)| std::ptr::eq(*item, target))
+            else {
+                continue;
+            };
+
+            // Messages are `$ref`s into the channel; without any, the
+            // operation covers all of them.
+            let messages = match op.get("messages") {
+                Some(Value::Sequence(refs)) if !refs.is_empty() => refs
+                    .iter()
+                    .filter_map(|message| {
+                        let message = self.resolve(message);
+                        entries(item.get("messages"))
+                            .find(|(_, m)| std::ptr::eq(self.resolve(m), message))
+                            .map(|(key, _)| key.to_string())
+                    })
+                    .collect(),
+                _ => channel.messages.iter().map(|m| m.name.clone()).collect(),
+            };
+
+            channel.operations.push(ChannelOperation {
+                id: Some(id.to_string()),
+                action,
+                summary: text(op, "summary").or_else(|| text(op, "title")),
+                description: text(op, "description"),
+                messages,
+            });
+        }
+
+        channels.into_iter().map(|(_, channel)| channel).collect()
+    }
+
+    /// Transform a message. Its name is `key` when given (3.x channel
+    /// messages), else its `name`, `messageId` or component name, else
+    /// `fallback`.
+    fn message(&self, message: &Value, key: Option<&str>, fallback: &str) -> AsyncApiMessage {
+        let component = ref_name(message);
+        let message = self.resolve(message);
+        let name = key
+            .map(str::to_string)
+            .or_else(|| text(message, "name"))
+            .or_else(|| text(message, "messageId"))
+            .or(component)
+            .unwrap_or_else(|| fallback.to_string());
+
+        let examples = match message.get("examples") {
+            Some(Value::Sequence(examples)) => examples
+                .iter()
+                .map(|example| MessageExample {
+                    name: text(example, "name"),
+                    summary: text(example, "summary"),
+                    headers: example
+                        .get("headers")
+                        .map(|v| format_json_value(&to_json(v))),
+                    payload: example
+                        .get("payload")
+                        .map(|v| format_json_value(&to_json(v))),
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        AsyncApiMessage {
+            name,
+            title: text(message, "title"),
+            summary: text(message, "summary"),
+            description: text(message, "description"),
+            content_type: text(message, "contentType").or(self.default_content_type.clone()),
+            headers: message.get("headers").and_then(|s| self.schema(s)),
+            payload: message.get("payload").and_then(|s| self.schema(s)),
+            examples,
+        }
+    }
+
+    /// Channel parameters as path parameters: 2.x ones carry a `schema`,
+    /// 3.x ones are strings with optional `enum`, `default` and `examples`.
+    fn parameters(&self, channel: &Value) -> Vec<ApiParameter> {
+        entries(channel.get("parameters"))
+            .map(|(name, param)| {
+                let param = self.resolve(param);
+                let schema = match param.get("schema") {
+                    Some(schema) => self.schema(schema),
+                    None => Some(SchemaDefinition {
+                        enum_values: match param.get("enum") {
+                            Some(Value::Sequence(values)) => values
+                                .iter()
+                                .filter_map(|v| v.as_str().map(str::to_string))
+                                .collect(),
+                            _ => Vec::new(),
+                        },
+                        default: text(param, "default"),
+                        ..Default::default()
+                    }),
+                };
+                let example = match param.get("examples") {
+                    Some(Value::Sequence(examples)) => {
+                        examples.first().map(|v| format_json_value(&to_json(v)))
+                    }
+                    _ => None,
+                };
+                ApiParameter {
+                    name: name.to_string(),
+                    location: ParameterLocation::Path,
+                    description: text(param, "description"),
+                    required: true,
+                    deprecated: false,
+                    schema,
+                    example,
+                }
+            })
+            .collect()
+    }
+
+    /// Transform a JSON Schema. A 3.x multi-format schema
+    /// (`{schemaFormat, schema}`) is only read when its format is JSON
+    /// Schema based.
+    fn schema(&self, schema: &Value) -> Option<SchemaDefinition> {
+        let resolved = self.resolve(schema);
+        if let Some(format) = text(resolved, "schemaFormat") {
+            let json_schema = format.starts_with("application/vnd.aai.asyncapi")
+                || format.starts_with("application/schema+");
+            if !json_schema {
+                return None;
+            }
+            return self.schema(resolved.get("schema")?);
+        }
+        // `#/components/schemas/...` refs stay refs, so the transform names them.
+        self.schemas.transform(&to_json(schema))
+    }
+
+    /// Follow local `$ref`s that don't point at component schemas.
+    fn resolve(&self, mut value: &'a Value) -> &'a Value {
+        for _ in 0..MAX_REF_HOPS {
+            let Some(target) = value
+                .get("$ref")
+                .and_then(Value::as_str)
+                .and_then(|r| r.strip_prefix('#'))
+                .and_then(|pointer| lookup(self.root, pointer))
+            else {
+                break;
+            };
+            value = target;
+        }
+        value
+    }
+}
+
+/// Resolve a JSON pointer (`/components/messages/userSignedUp`) in `root`.
+fn lookup<'a>(root: &'a Value, pointer: &str) -> Option<&'a Value> {
+    pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .try_fold(root, |value, segment| match value {
+            Value::Mapping(map) => map.get(segment.as_str()),
+            Value::Sequence(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+/// Last segment of a `$ref`, e.g. `UserSignedUp` for
+/// `#/components/messages/UserSignedUp`.
+fn ref_name(value: &Value) -> Option<String> {
+    let reference = value.get("$ref")?.as_str()?;
+    let name = reference.rsplit('/').next()?;
+    Some(name.replace("~1", "/").replace("~0", "~"))
+}
+
+/// String-keyed entries of a mapping, in document order.
+fn entries(value: Option<&Value>) -> impl Iterator<Item = (&str, &Value)> {
+    value
+        .and_then(Value::as_mapping)
+        .into_iter()
+        .flatten()
+        .filter_map(|(key, value)| Some((key.as_str()?, value)))
+}
+
+/// A string field.
+fn text(value: &Value, key: &str) -> Option<String> {
+    value.get(key)?.as_str().map(str::to_string)
+}
+
+fn to_json(value: &Value) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::SchemaType;
+
+    const V2: &str = r##"
+asyncapi: '2.6.0'
+info: {title: Accounts, version: '1.0.0'}
+defaultContentType: application/json
+servers:
+  production: {url: 'kafka.example.com:9092', protocol: kafka}
+channels:
+  user/{userId}/signedup:
+    description: A user signed up.
+    parameters:
+      userId:
+        description: Id of the user.
+        schema: {type: string, format: uuid}
+    subscribe:
+      operationId: onUserSignedUp
+      summary: User signed up
+      message:
+        $ref: '#/components/messages/UserSignedUp'
+    publish:
+      operationId: signUpUser
+      message:
+        oneOf:
+          - $ref: '#/components/messages/UserSignedUp'
+          - name: SignUpRejected
+            payload: {type: object, properties: {reason: {type: string}}}
+components:
+  messages:
+    UserSignedUp:
+      title: User signed up
+      headers:
+        type: object
+        properties: {traceId: {type: string}}
+      payload:
+        $ref: '#/components/schemas/User'
+      examples:
+        - name: alice
+          payload: {id: 42, email: alice@example.com}
+  schemas:
+    User:
+      type: object
+      required: [id]
+      properties:
+        id: {type: integer}
+        email: {type: [string, 'null'], format: email}
+"##;
+
+    const V3: &str = r##"
+asyncapi: 3.0.0
+info: {title: Orders, version: '2.0.0'}
+servers:
+  production: {host: broker.example.com, pathname: /v1, protocol: amqp}
+channels:
+  orderCreated:
+    address: 'orders.{region}.created'
+    title: Order created
+    parameters:
+      region:
+        enum: [eu, us]
+        default: eu
+        examples: [eu]
+    messages:
+      OrderCreated:
+        $ref: '#/components/messages/OrderCreated'
+      OrderRejected:
+        payload: {type: string}
+  orderArchive:
+    address: null
+operations:
+  receiveOrder:
+    action: receive
+    channel: {$ref: '#/channels/orderCreated'}
+    messages:
+      - $ref: '#/channels/orderCreated/messages/OrderCreated'
+  sendOrder:
+    action: send
+    summary: Publish a new order
+    channel: {$ref: '#/channels/orderCreated'}
+components:
+  messages:
+    OrderCreated:
+      contentType: application/json
+      payload:
+        schemaFormat: application/vnd.aai.asyncapi+json;version=3.0.0
+        schema: {$ref: '#/components/schemas/Order'}
+  schemas:
+    Order:
+      type: object
+      properties:
+        total: {type: number}
+"##;
+
+    #[test]
+    fn parses_asyncapi_2_channels_and_messages() {
+        let spec = parse_asyncapi(V2).unwrap();
+        assert_eq!(spec.info.title, "Accounts");
+        assert_eq!(spec.servers[0].url, "kafka.example.com:9092");
+        assert_eq!(spec.servers[0].protocol, "kafka");
+
+        let channel = &spec.channels[0];
+        assert_eq!(channel.address, "user/{userId}/signedup");
+        assert_eq!(channel.slug(), "user-user-id-signedup");
+        assert_eq!(channel.title(), "User signed up");
+        assert_eq!(
+            channel.actions(),
+            [OperationAction::Publish, OperationAction::Subscribe]
+        );
+
+        let param = &channel.parameters[0];
+        assert_eq!(param.name, "userId");
+        assert_eq!(param.location, ParameterLocation::Path);
+        assert_eq!(
+            param.schema.as_ref().unwrap().format.as_deref(),
+            Some("uuid")
+        );
+
+        // The shared message appears once; the inline one is named by `name`.
+        let names: Vec<_> = channel.messages.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["UserSignedUp", "SignUpRejected"]);
+        assert_eq!(
+            channel.operations[0].messages,
+            ["UserSignedUp", "SignUpRejected"]
+        );
+
+        let message = channel.message("UserSignedUp").unwrap();
+        assert_eq!(message.content_type.as_deref(), Some("application/json"));
+        let payload = message.payload.as_ref().unwrap();
+        assert_eq!(payload.ref_name.as_deref(), Some("User"));
+        assert_eq!(payload.required, ["id"]);
+        let email = &payload.properties["email"];
+        assert_eq!(email.schema_type, SchemaType::String);
+        assert!(email.nullable);
+        assert!(
+            message
+                .headers
+                .as_ref()
+                .unwrap()
+                .properties
+                .contains_key("traceId")
+        );
+        assert!(
+            message
+                .example_payload()
+                .unwrap()
+                .contains("alice@example.com")
+        );
+        assert!(spec.schemas.contains_key("User"));
+    }
+
+    #[test]
+    fn parses_asyncapi_3_operations_onto_their_channels() {
+        let spec = parse_asyncapi(V3).unwrap();
+        assert_eq!(spec.servers[0].url, "broker.example.com/v1");
+
+        let ids: Vec<_> = spec.channels.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["orderCreated", "orderArchive"]);
+        assert_eq!(spec.channels[1].address, "orderArchive");
+
+        let channel = &spec.channels[0];
+        assert_eq!(channel.address, "orders.{region}.created");
+        assert_eq!(channel.slug(), "order-created");
+        assert_eq!(channel.title(), "Order created");
+
+        let param = &channel.parameters[0];
+        let schema = param.schema.as_ref().unwrap();
+        assert_eq!(schema.enum_values, ["eu", "us"]);
+        assert_eq!(schema.default.as_deref(), Some("eu"));
+        assert_eq!(param.example.as_deref(), Some("eu"));
+
+        let receive = &channel.operations[0];
+        assert_eq!(receive.action, OperationAction::Receive);
+        assert_eq!(receive.messages, ["OrderCreated"]);
+        let send = &channel.operations[1];
+        assert_eq!(send.action, OperationAction::Send);
+        assert_eq!(send.summary.as_deref(), Some("Publish a new order"));
+        assert_eq!(send.messages, ["OrderCreated", "OrderRejected"]);
+
+        let payload = channel.messages[0].payload.as_ref().unwrap();
+        assert_eq!(payload.ref_name.as_deref(), Some("Order"));
+        assert!(payload.properties.contains_key("total"));
+        let example = channel.messages[0].example_payload().unwrap();
+        assert!(example.contains("\"total\""));
+    }
+
+    #[test]
+    fn rejects_unsupported_versions_and_non_json_schemas() {
+        assert!(matches!(
+            parse_asyncapi("asyncapi: 1.2.0\ninfo: {title: Old, version: '1'}\n"),
+            Err(AsyncApiError::InvalidSpec(_))
+        ));
+        assert!(matches!(
+            parse_asyncapi("openapi: 3.0.0\n"),
+            Err(AsyncApiError::InvalidSpec(_))
+        ));
+
+        let avro = V3.replace(
+            "application/vnd.aai.asyncapi+json;version=3.0.0",
+            "application/vnd.apache.avro;version=1.9.0",
+        );
+        let spec = parse_asyncapi(&avro).unwrap();
+        assert!(spec.channels[0].messages[0].payload.is_none());
+    }
+}
@@ -0,0 +1,230 @@
+//! AsyncAPI model: channels, the operations performed on them and the
+//! messages they carry.
+//!
+//! Payload, header and parameter schemas are [`SchemaDefinition`]s, so they
+//! render with the same components as OpenAPI schemas.
+
+use std::collections::BTreeMap;
+
+use super::openapi_types::{ApiInfo, ApiParameter, SchemaDefinition};
+
+/// A parsed AsyncAPI 2.x or 3.x document.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AsyncApiSpec {
+    /// API metadata.
+    pub info: ApiInfo,
+    /// Brokers the application connects to, in spec order.
+    pub servers: Vec<AsyncApiServer>,
+    /// Channels in spec order.
+    pub channels: Vec<AsyncApiChannel>,
+    /// Component schemas by name.
+    pub schemas: BTreeMap<String, SchemaDefinition>,
+}
+
+/// A message broker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsyncApiServer {
+    /// Server name, its key under `servers`.
+    pub name: String,
+    /// Broker URL: 2.x `url`, or 3.x `host` followed by `pathname`.
+    pub url: String,
+    /// Protocol, e.g. `kafka` or `amqp`.
+    pub protocol: String,
+    /// Server description.
+    pub description: Option<String>,
+}
+
+/// A channel (topic, queue, routing key...) and what happens on it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsyncApiChannel {
+    /// Channel id: its key under `channels` (the address itself in 2.x).
+    pub id: String,
+    /// Address on the broker, e.g. `user/{userId}/signedup`.
+    pub address: String,
+    /// Channel title (3.x).
+    pub title: Option<String>,
+    /// Channel description.
+    pub description: Option<String>,
+    /// Parameters of the address, as path parameters.
+    pub parameters: Vec<ApiParameter>,
+    /// Operations on the channel, in spec order.
+    pub operations: Vec<ChannelOperation>,
+    /// Messages the channel carries, in spec order.
+    pub messages: Vec<AsyncApiMessage>,
+}
+
+impl AsyncApiChannel {
+    /// URL slug, kebab-cased from the id: `userSignedUp` → `user-signed-up`,
+    /// `orders.{region}.created` → `orders-region-created`.
+    pub fn slug(&self) -> String {
+        let mut slug = String::new();
+        let mut prev_lower = false;
+        for ch in self.id.chars() {
+            if ch.is_alphanumeric() {
+                if ch.is_uppercase() && prev_lower {
+                    slug.push('-');
+                }
+                prev_lower = ch.is_lowercase() || ch.is_ascii_digit();
+                slug.extend(ch.to_lowercase());
+            } else {
+                if !slug.is_empty() && !slug.ends_with('-') {
+                    slug.push('-');
+                }
+                prev_lower = false;
+            }
+        }
+        slug.trim_end_matches('-').to_string()
+    }
+
+    /// Display title: the channel's title, else the first operation summary,
+    /// else the address.
+    pub fn title(&self) -> String {
+        self.title
+            .clone()
+            .or_else(|| self.operations.iter().find_map(|op| op.summary.clone()))
+            .unwrap_or_else(|| self.address.clone())
+    }
+
+    /// The channel's description, else the first operation's.
+    pub fn summary(&self) -> Option<&str> {
+        self.description.as_deref().or_else(|| {
+            self.operations
+                .iter()
+                .find_map(|op| op.description.as_deref())
+        })
+    }
+
+    /// Actions performed on the channel, without repeats.
+    pub fn actions(&self) -> Vec<OperationAction> {
+        let mut actions = Vec::new();
+        for op in &self.operations {
+            if !actions.contains(&op.action) {
+                actions.push(op.action);
+            }
+        }
+        actions
+    }
+
+    /// Look up a message by name.
+    pub fn message(&self, name: &str) -> Option<&AsyncApiMessage> {
+        self.messages.iter().find(|m| m.name == name)
+    }
+}
+
+/// Something the application does on a channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelOperation {
+    /// `operationId` (2.x) or the key under `operations` (3.x).
+    pub id: Option<String>,
+    /// What the application does.
+    pub action: OperationAction,
+    /// Short summary.
+    pub summary: Option<String>,
+    /// Longer description.
+    pub description: Option<String>,
+    /// Names of the channel messages involved.
+    pub messages: Vec<String>,
+}
+
+/// Direction of a channel operation, as the spec words it.
+///
+/// In 2.x `publish` means other applications publish to the channel for this
+/// one to receive; in 3.x `send` and `receive` describe the application
+/// itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationAction {
+    Publish,
+    Subscribe,
+    Send,
+    Receive,
+}
+
+impl OperationAction {
+    /// Parse from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "publish" => Some(Self::Publish),
+            "subscribe" => Some(Self::Subscribe),
+            "send" => Some(Self::Send),
+            "receive" => Some(Self::Receive),
+            _ => None,
+        }
+    }
+
+    /// Display label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Publish => "PUB",
+            Self::Subscribe => "SUB",
+            Self::Send => "SEND",
+            Self::Receive => "RECV",
+        }
+    }
+
+    /// DaisyUI badge class: outgoing and incoming messages get their own
+    /// colors.
+    pub fn badge_class(&self) -> &'static str {
+        match self {
+            Self::Publish | Self::Send => "badge-soft badge-primary",
+            Self::Subscribe | Self::Receive => "badge-soft badge-success",
+        }
+    }
+
+    /// Tailwind background class for the action.
+    pub fn bg_class(&self) -> &'static str {
+        match self {
+            Self::Publish | Self::Send => "bg-primary/10 border-primary/30 text-primary",
+            Self::Subscribe | Self::Receive => "bg-success/10 border-success/30 text-success",
+        }
+    }
+}
+
+/// A message sent over a channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsyncApiMessage {
+    /// Message name: its key in the channel (3.x), else its `name`,
+    /// `messageId` or component name.
+    pub name: String,
+    /// Human-friendly title.
+    pub title: Option<String>,
+    /// Short summary.
+    pub summary: Option<String>,
+    /// Longer description.
+    pub description: Option<String>,
+    /// Content type, falling back to the spec's `defaultContentType`.
+    pub content_type: Option<String>,
+    /// Schema of the message headers.
+    pub headers: Option<SchemaDefinition>,
+    /// Schema of the payload.
+    pub payload: Option<SchemaDefinition>,
+    /// Examples from the spec.
+    pub examples: Vec<MessageExample>,
+}
+
+impl AsyncApiMessage {
+    /// An example payload: the spec's first one, else one generated from the
+    /// payload schema.
+    pub fn example_payload(&self) -> Option<String> {
+        self.examples
+            .iter()
+            .find_map(|e| e.payload.clone())
+            .or_else(|| {
+                let schema = self.payload.as_ref()?;
+                serde_json::to_string_pretty(&schema.generate_example_json(0)).ok()
+            })
+    }
+}
+
+/// A message example. Values are stored as text: strings as-is, anything
+/// else as pretty-printed JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageExample {
+    /// Example name.
+    pub name: Option<String>,
+    /// Short summary.
+    pub summary: Option<String>,
+    /// Example headers.
+    pub headers: Option<String>,
+    /// Example payload.
+    pub payload: Option<String>,
+}
@@ -5,15 +5,23 @@ use std::sync::LazyLock;
 use regex::Regex;
 
 use super::accordion::{try_parse_accordion_group, try_parse_standalone_accordion};
+use super::api_ref::{schema_to_markdown, try_parse_api_endpoint, try_parse_schema};
 use super::callout::try_parse_callout;
 use super::card::{try_parse_card_group, try_parse_columns, try_parse_standalone_card};
 use super::code_group::{
     try_parse_code_group, try_parse_request_example, try_parse_response_example,
 };
+use super::data_table::{table_to_markdown, try_parse_data_table};
+use super::definition_list::split_definition_lists;
 use super::fields::{try_parse_expandable, try_parse_param_field, try_parse_response_field};
+use super::file_tree::{file_tree_to_text, try_parse_file_tree};
+use super::footnotes::{apply_notes, extract_notes, strip_notes};
+use super::media::{try_parse_embed, try_parse_video, try_parse_youtube};
 use super::openapi_tag::try_parse_openapi;
 use super::steps::try_parse_steps;
 use super::tabs::try_parse_tabs;
+use super::term::strip_terms;
+use super::terminal::{is_terminal_language, parse_session, try_parse_terminal};
 use super::update::try_parse_update;
 use super::utils::find_fenced_blocks;
 use crate::parser::frontmatter::extract_frontmatter;
@@ -40,7 +48,10 @@ pub fn parse_mdx(content: &str) -> Vec<DocNode> {
 pub(super) fn parse_body(content: &str) -> Vec<DocNode> {
     let content = strip_imports(content);
     let content = strip_helpful_widget(&content);
-    parse_content(&content)
+    let (content, notes) = extract_notes(&content);
+    let mut nodes = parse_content(&content);
+    apply_notes(&mut nodes, notes, &content);
+    nodes
 }
 
 /// Strip import statements from MDX content, leaving fenced code blocks alone.
@@ -121,6 +132,30 @@ pub(super) fn parse_content(content: &str) -> Vec<DocNode> {
         } else if let Some((node, rest)) = try_parse_openapi(remaining) {
             nodes.push(node);
             remaining = rest.trim();
+        } else if let Some((node, rest)) = try_parse_file_tree(remaining) {
+            nodes.push(node);
+            remaining = rest.trim();
+        } else if let Some((node, rest)) = try_parse_api_endpoint(remaining) {
+            nodes.push(node);
+            remaining = rest.trim();
+        } else if let Some((node, rest)) = try_parse_schema(remaining) {
+            nodes.push(node);
+            remaining = rest.trim();
+        } else if let Some((node, rest)) = try_parse_video(remaining) {
+            nodes.push(node);
+            remaining = rest.trim();
+        } else if let Some((node, rest)) = try_parse_youtube(remaining) {
+            nodes.push(node);
+            remaining = rest.trim();
+        } else if let Some((node, rest)) = try_parse_embed(remaining) {
+            nodes.push(node);
+            remaining = rest.trim();
+        } else if let Some((node, rest)) = try_parse_terminal(remaining) {
+            nodes.push(node);
+            remaining = rest.trim();
+        } else if let Some((node, rest)) = try_parse_data_table(remaining) {
+            nodes.push(node);
+            remaining = rest.trim();
         } else {
             // Collect markdown until next component or end
             let next_component_idx = find_next_component(remaining);
@@ -161,16 +196,24 @@ fn extract_code_blocks_from_markdown(content: &str) -> Vec<DocNode> {
         if block.start > last_end {
             let before = &content[last_end..block.start];
             if !before.trim().is_empty() {
-                nodes.push(DocNode::Markdown(before.trim().to_string()));
+                nodes.extend(split_definition_lists(before.trim()));
             }
         }
 
-        // Add the code block
-        nodes.push(DocNode::CodeBlock(CodeBlockNode {
-            language: block.language.map(str::to_string),
-            filename: block.filename.map(str::to_string),
-            code: block.code.trim().to_string(),
-        }));
+        // Add the code block; console sessions get the terminal treatment
+        if block.language.is_some_and(is_terminal_language) {
+            nodes.push(DocNode::Terminal(parse_session(
+                block.code.trim_matches('\n').trim_end(),
+                block.filename.map(str::to_string),
+                false,
+            )));
+        } else {
+            nodes.push(DocNode::CodeBlock(CodeBlockNode {
+                language: block.language.map(str::to_string),
+                filename: block.filename.map(str::to_string),
+                code: block.code.trim().to_string(),
+            }));
+        }
 
         last_end = block.end;
     }
@@ -179,13 +222,13 @@ fn extract_code_blocks_from_markdown(content: &str) -> Vec<DocNode> {
     if last_end < content.len() {
         let after = &content[last_end..];
         if !after.trim().is_empty() {
-            nodes.push(DocNode::Markdown(after.trim().to_string()));
+            nodes.extend(split_definition_lists(after.trim()));
         }
     }
 
     // If no code blocks were found, return the original content as markdown
     if nodes.is_empty() && !content.trim().is_empty() {
-        nodes.push(DocNode::Markdown(content.trim().to_string()));
+        nodes.extend(split_definition_lists(content.trim()));
     }
 
     nodes
@@ -216,6 +259,14 @@ fn find_next_component(content: &str) -> Option<usize> {
         "<CodeGroup>",
         "<Update",
         "<OpenAPI",
+        "<FileTree>",
+        "<ApiEndpoint",
+        "<Schema",
+        "<Video",
+        "<YouTube",
+        "<Embed",
+        "<Terminal",
+        "<DataTable",
     ];
 
     let fences: Vec<(usize, usize)> = find_fenced_blocks(content)
@@ -242,7 +293,7 @@ pub fn get_raw_markdown(nodes: &[DocNode]) -> String {
     for node in nodes {
         match node {
             DocNode::Markdown(md) => {
-                output.push_str(md);
+                output.push_str(&strip_notes(&strip_terms(md)));
                 output.push_str("\n\n");
             }
             DocNode::Callout(c) => {
@@ -355,6 +406,78 @@ pub fn get_raw_markdown(nodes: &[DocNode]) -> String {
                     }
                 }
             }
+            DocNode::FileTree(tree) => {
+                output.push_str("```text\n");
+                output.push_str(&file_tree_to_text(&tree.entries));
+                output.push_str("```\n\n");
+            }
+            DocNode::ApiEndpoint(ep) => match &ep.operation {
+                Some(op) => {
+                    output.push_str(&format!("**{}** `{}`", op.method.as_str(), op.path));
+                    if let Some(summary) = &op.summary {
+                        output.push_str(&format!(" — {summary}"));
+                    }
+                    output.push_str("\n\n");
+                }
+                None => output.push_str(&format!("`{}`\n\n", ep.operation_id)),
+            },
+            DocNode::Schema(s) => match &s.schema {
+                Some(schema) => output.push_str(&schema_to_markdown(&s.name, schema)),
+                None => output.push_str(&format!("`{}`\n\n", s.name)),
+            },
+            DocNode::Video(v) => {
+                let label = v
+                    .title
+                    .as_deref()
+                    .map_or_else(|| "Video".to_string(), |t| format!("Video: {t}"));
+                output.push_str(&format!("[{label}]({})\n\n", v.src));
+            }
+            DocNode::YouTube(y) => {
+                let label = y
+                    .title
+                    .as_deref()
+                    .map_or_else(|| "YouTube video".to_string(), |t| format!("YouTube: {t}"));
+                output.push_str(&format!("[{label}]({})\n\n", y.watch_url()));
+            }
+            DocNode::Embed(e) => {
+                let label = e.title.as_deref().unwrap_or("Embedded content");
+                output.push_str(&format!("[{label}]({})\n\n", e.src));
+            }
+            DocNode::Terminal(t) => {
+                output.push_str(&format!("```console\n{}\n```\n\n", t.to_text()));
+            }
+            DocNode::DefinitionList(list) => {
+                for item in &list.items {
+                    output.push_str(&strip_notes(&strip_terms(&item.term)));
+                    output.push('\n');
+                    for definition in &item.definitions {
+                        let definition = strip_notes(&strip_terms(definition));
+                        output.push_str(&format!(": {}\n", definition.replace('\n', "\n  ")));
+                    }
+                }
+                output.push('\n');
+            }
+            DocNode::Footnotes(footnotes) => {
+                output.push_str("## Footnotes\n\n");
+                for item in &footnotes.items {
+                    output.push_str(&format!(
+                        "[^{}]: {}\n",
+                        item.label,
+                        item.content.replace('\n', "\n    ")
+                    ));
+                }
+                output.push('\n');
+            }
+            DocNode::Abbreviations(abbreviations) => {
+                for a in abbreviations {
+                    output.push_str(&format!("*[{}]: {}\n", a.abbr, a.title));
+                }
+                output.push('\n');
+            }
+            DocNode::DataTable(table) => match &table.data {
+                Some(data) => output.push_str(&table_to_markdown(data)),
+                None => output.push_str(&format!("`{}`\n\n", table.src)),
+            },
         }
     }
 
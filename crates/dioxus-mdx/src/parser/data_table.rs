@@ -0,0 +1,527 @@
+//! Data table parser and CSV/JSON loader.
+//!
+//! ```text
+//! <DataTable src="data/errors.csv" />
+//! <DataTable src="data/regions.json" types="launched:date,docs:link" filter="false" />
+//! ```
+//!
+//! The tag only records the source path. The host that embeds the data files
+//! (e.g. `DocsRegistry`) loads them through [`DataTableNode::load`].
+
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use super::utils::{extract_attr, self_closing_tag};
+use crate::parser::types::*;
+
+/// Error produced when loading a data table's source file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataTableError {
+    /// The file extension is not `.csv`, `.tsv` or `.json`.
+    UnsupportedFormat(String),
+    /// Malformed CSV or TSV.
+    Csv {
+        /// 1-based line of the offending record.
+        line: usize,
+        /// What went wrong.
+        message: String,
+    },
+    /// Malformed JSON, or JSON that isn't an array of rows.
+    Json(String),
+    /// `types="..."` names an unknown column or type.
+    InvalidType(String),
+}
+
+impl std::fmt::Display for DataTableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedFormat(src) => {
+                write!(
+                    f,
+                    "unsupported data file \"{src}\" (expected .csv, .tsv or .json)"
+                )
+            }
+            Self::Csv { line, message } => write!(f, "CSV line {line}: {message}"),
+            Self::Json(msg) => write!(f, "JSON: {msg}"),
+            Self::InvalidType(msg) => write!(f, "types: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DataTableError {}
+
+/// Try to parse a `<DataTable src="..." />` component.
+pub(super) fn try_parse_data_table(content: &str) -> Option<(DocNode, &str)> {
+    let (attrs, rest) = self_closing_tag(content, "DataTable")?;
+    let src = extract_attr(attrs, "src")?;
+
+    Some((
+        DocNode::DataTable(DataTableNode {
+            src,
+            types: extract_attr(attrs, "types").unwrap_or_default(),
+            filterable: extract_attr(attrs, "filter").is_none_or(|v| v != "false"),
+            sortable: extract_attr(attrs, "sortable").is_none_or(|v| v != "false"),
+            data: None,
+        }),
+        rest,
+    ))
+}
+
+impl DataTableNode {
+    /// Parse the source file's contents into `data`, inferring column types
+    /// and then applying the `types` overrides.
+    pub fn load(&mut self, text: &str) -> Result<(), DataTableError> {
+        let extension = self
+            .src
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase());
+        let (columns, rows) = match extension.as_deref() {
+            Some("csv") => parse_delimited(text, ',')?,
+            Some("tsv") => parse_delimited(text, '\t')?,
+            Some("json") => parse_json(text)?,
+            _ => return Err(DataTableError::UnsupportedFormat(self.src.clone())),
+        };
+
+        let mut columns: Vec<DataColumn> = columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| DataColumn {
+                column_type: ColumnType::infer(rows.iter().map(|row| row[i].as_str())),
+                name,
+            })
+            .collect();
+
+        for spec in self.types.split(',').filter(|s| !s.trim().is_empty()) {
+            let (name, ty) = spec.split_once(':').ok_or_else(|| {
+                DataTableError::InvalidType(format!("expected `column:type`, got \"{spec}\""))
+            })?;
+            let (name, ty) = (name.trim(), ty.trim());
+            let column_type = ColumnType::parse(ty)
+                .ok_or_else(|| DataTableError::InvalidType(format!("unknown type \"{ty}\"")))?;
+            let column = columns
+                .iter_mut()
+                .find(|c| c.name == name)
+                .ok_or_else(|| DataTableError::InvalidType(format!("unknown column \"{name}\"")))?;
+            column.column_type = column_type;
+        }
+
+        self.data = Some(TableData { columns, rows });
+        Ok(())
+    }
+
+    /// Anchor ids of the loaded rows: the file stem plus the row's first
+    /// cell, so search results deep-link to the row (e.g. `errors-e1042`).
+    ///
+    /// Rows whose first cell is empty or shared with another row add their
+    /// 1-based row number (`errors-e1042-3`, `errors-4`), so every id is
+    /// unique.
+    pub fn row_anchors(&self) -> Vec<String> {
+        let Some(data) = &self.data else {
+            return Vec::new();
+        };
+        let stem = self
+            .src
+            .rsplit('/')
+            .next()
+            .and_then(|file| file.split('.').next())
+            .unwrap_or("table");
+        let stem = anchor_part(stem);
+
+        let keys: Vec<String> = data
+            .rows
+            .iter()
+            .map(|row| {
+                row.first()
+                    .map(|cell| anchor_part(cell))
+                    .unwrap_or_default()
+            })
+            .collect();
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for key in &keys {
+            *counts.entry(key.as_str()).or_default() += 1;
+        }
+
+        let mut seen = HashSet::new();
+        keys.iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let mut anchor = match key.as_str() {
+                    "" => format!("{stem}-{}", i + 1),
+                    key if counts[key] > 1 => format!("{stem}-{key}-{}", i + 1),
+                    key => format!("{stem}-{key}"),
+                };
+                // A numeric first cell can still clash with a numbered row.
+                if !seen.insert(anchor.clone()) {
+                    anchor = format!("{anchor}-{}", i + 1);
+                    seen.insert(anchor.clone());
+                }
+                anchor
+            })
+            .collect()
+    }
+}
+
+fn anchor_part(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+impl ColumnType {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "number" => Some(Self::Number),
+            "boolean" => Some(Self::Boolean),
+            "date" => Some(Self::Date),
+            "link" => Some(Self::Link),
+            "code" => Some(Self::Code),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Date => "date",
+            Self::Link => "link",
+            Self::Code => "code",
+        }
+    }
+
+    /// Most specific type every non-empty value fits. `Code` is never
+    /// inferred.
+    pub fn infer<'a>(values: impl Iterator<Item = &'a str>) -> Self {
+        let values: Vec<&str> = values.map(str::trim).filter(|v| !v.is_empty()).collect();
+        if values.is_empty() {
+            return Self::Text;
+        }
+        let all = |f: fn(&str) -> bool| values.iter().all(|v| f(v));
+        if all(|v| v.parse::<f64>().is_ok()) {
+            Self::Number
+        } else if all(|v| parse_bool(v).is_some()) {
+            Self::Boolean
+        } else if all(is_iso_date) {
+            Self::Date
+        } else if all(|v| v.starts_with("https://") || v.starts_with("http://")) {
+            Self::Link
+        } else {
+            Self::Text
+        }
+    }
+}
+
+/// `true`/`false`/`yes`/`no`, case-insensitively.
+pub(crate) fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "yes" => Some(true),
+        "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// `YYYY-MM-DD`, optionally followed by a time.
+fn is_iso_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() >= 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && [0..4, 5..7, 8..10]
+            .into_iter()
+            .all(|r| bytes[r].iter().all(u8::is_ascii_digit))
+        && (bytes.len() == 10 || matches!(bytes[10], b'T' | b' '))
+}
+
+type Rows = (Vec<String>, Vec<Vec<String>>);
+
+/// Parse CSV/TSV with a header row. Quoted fields may contain the delimiter,
+/// doubled quotes and line breaks. Short rows are padded with empty cells.
+fn parse_delimited(text: &str, delimiter: char) -> Result<Rows, DataTableError> {
+    let mut records: Vec<(usize, Vec<String>)> = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => in_quotes = true,
+            '\n' if in_quotes => {
+                line += 1;
+                field.push('\n');
+            }
+            '\r' if !in_quotes => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.trim().is_empty()) {
+                    records.push((record_line, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                record_line = line;
+            }
+            c if c == delimiter && !in_quotes => record.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(DataTableError::Csv {
+            line: record_line,
+            message: "unterminated quoted field".to_string(),
+        });
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.trim().is_empty()) {
+        records.push((record_line, record));
+    }
+
+    let mut records = records.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Err(DataTableError::Csv {
+            line: 1,
+            message: "missing header row".to_string(),
+        });
+    };
+    let columns: Vec<String> = header.into_iter().map(|h| h.trim().to_string()).collect();
+
+    let rows = records
+        .map(|(line, mut row)| {
+            if row.len() > columns.len() {
+                return Err(DataTableError::Csv {
+                    line,
+                    message: format!("{} fields, but the header has {}", row.len(), columns.len()),
+                });
+            }
+            row.resize(columns.len(), String::new());
+            Ok(row
+                .into_iter()
+                .map(|cell| cell.trim().to_string())
+                .collect())
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((columns, rows))
+}
+
+/// Parse a JSON array of objects (columns in first-seen key order) or of
+/// arrays (the first one is the header).
+fn parse_json(text: &str) -> Result<Rows, DataTableError> {
+    let value: Value =
+        serde_json::from_str(text).map_err(|e| DataTableError::Json(e.to_string()))?;
+    let Value::Array(items) = value else {
+        return Err(DataTableError::Json(
+            "expected an array of rows".to_string(),
+        ));
+    };
+
+    if let Some(Value::Array(_)) = items.first() {
+        let mut rows = items.iter().map(|item| match item {
+            Value::Array(cells) => Ok(cells.iter().map(cell_text).collect()),
+            _ => Err(DataTableError::Json(
+                "every row must be an array when the first one is".to_string(),
+            )),
+        });
+        let columns: Vec<String> = rows.next().transpose()?.unwrap_or_default();
+        let rows = rows
+            .enumerate()
+            .map(|(i, row)| {
+                let mut row: Vec<String> = row?;
+                if row.len() > columns.len() {
+                    return Err(DataTableError::Json(format!(
+                        "row {} has {} cells, but the header has {}",
+                        i + 1,
+                        row.len(),
+                        columns.len()
+                    )));
+                }
+                row.resize(columns.len(), String::new());
+                Ok(row)
+            })
+            .collect::<Result<_, _>>()?;
+        return Ok((columns, rows));
+    }
+
+    // Read the objects again as ordered maps: `serde_json::Map` sorts keys,
+    // and columns should follow the file.
+    let items: Vec<IndexMap<String, Value>> = serde_json::from_str(text)
+        .map_err(|_| DataTableError::Json("expected an array of objects or arrays".to_string()))?;
+    let mut columns: Vec<String> = Vec::new();
+    for item in &items {
+        for key in item.keys() {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+    let rows = items
+        .iter()
+        .map(|item| {
+            columns
+                .iter()
+                .map(|column| item.get(column).map(cell_text).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    Ok((columns, rows))
+}
+
+/// Display text of a JSON value: strings unquoted, `null` empty, nested
+/// values as compact JSON.
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// GFM table for the raw-markdown fallback.
+pub(super) fn table_to_markdown(data: &TableData) -> String {
+    let escape = |cell: &str| cell.replace('|', "\\|").replace('\n', " ");
+    let mut out = String::new();
+    let header: Vec<String> = data.columns.iter().map(|c| escape(&c.name)).collect();
+    out.push_str(&format!("| {} |\n", header.join(" | ")));
+    out.push_str(&format!("|{}\n", "---|".repeat(data.columns.len())));
+    for row in &data.rows {
+        let cells: Vec<String> = row.iter().map(|c| escape(c)).collect();
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::content::{get_raw_markdown, parse_mdx};
+
+    fn node(src: &str, types: &str) -> DataTableNode {
+        DataTableNode {
+            src: src.to_string(),
+            types: types.to_string(),
+            filterable: true,
+            sortable: true,
+            data: None,
+        }
+    }
+
+    #[test]
+    fn parses_tag_attributes() {
+        let (node, rest) =
+            try_parse_data_table("<DataTable src=\"data/errors.csv\" filter=\"false\" />after")
+                .unwrap();
+        let DocNode::DataTable(table) = node else {
+            panic!("expected DataTable, got {node:?}");
+        };
+        assert_eq!(table.src, "data/errors.csv");
+        assert!(!table.filterable);
+        assert!(table.sortable);
+        assert!(table.data.is_none());
+        assert_eq!(rest, "after");
+    }
+
+    #[test]
+    fn loads_csv_with_quotes_and_infers_types() {
+        let mut table = node("data/errors.csv", "code:code");
+        table
+            .load("code,status,retryable,message\r\nE1042,429,yes,\"Slow down, \"\"please\"\"\"\nE2001,500,no,\"Multi\nline\"\n\n")
+            .unwrap();
+        let data = table.data.as_ref().unwrap();
+        let types: Vec<ColumnType> = data.columns.iter().map(|c| c.column_type).collect();
+        assert_eq!(
+            types,
+            [
+                ColumnType::Code,
+                ColumnType::Number,
+                ColumnType::Boolean,
+                ColumnType::Text
+            ]
+        );
+        assert_eq!(data.rows.len(), 2);
+        assert_eq!(data.rows[0][3], "Slow down, \"please\"");
+        assert_eq!(data.rows[1][3], "Multi\nline");
+        assert_eq!(table.row_anchors()[0], "errors-e1042");
+    }
+
+    #[test]
+    fn csv_errors_report_the_line() {
+        let mut table = node("t.csv", "");
+        let err = table.load("a,b\n1,2\n1,2,3\n").unwrap_err();
+        assert!(matches!(err, DataTableError::Csv { line: 3, .. }), "{err}");
+        let err = node("t.csv", "nope:number").load("a\n1").unwrap_err();
+        assert!(matches!(err, DataTableError::InvalidType(_)), "{err}");
+    }
+
+    #[test]
+    fn loads_json_objects_in_key_order() {
+        let mut table = node("regions.json", "");
+        table
+            .load(r#"[{"region": "eu-west-1", "launched": "2021-04-01", "zones": 3}, {"region": "us-east-1", "zones": 6, "beta": true}]"#)
+            .unwrap();
+        let data = table.data.as_ref().unwrap();
+        let names: Vec<&str> = data.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["region", "launched", "zones", "beta"]);
+        assert_eq!(data.columns[1].column_type, ColumnType::Date);
+        assert_eq!(data.rows[1], ["us-east-1", "", "6", "true"]);
+    }
+
+    #[test]
+    fn json_array_rows_longer_than_the_header_fail() {
+        let err = node("t.json", "")
+            .load(r#"[["a", "b"], [1, 2], [1, 2, 3]]"#)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DataTableError::Json("row 2 has 3 cells, but the header has 2".to_string())
+        );
+    }
+
+    #[test]
+    fn row_anchors_are_unique() {
+        let mut table = node("data/errors.csv", "");
+        table
+            .load("code,message\nE1,a\nE1,b\n,c\nE2,d\n3,e\n")
+            .unwrap();
+        assert_eq!(
+            table.row_anchors(),
+            [
+                "errors-e1-1",
+                "errors-e1-2",
+                "errors-3",
+                "errors-e2",
+                "errors-3-5"
+            ]
+        );
+    }
+
+    #[test]
+    fn raw_markdown_renders_a_table_once_loaded() {
+        let mut nodes = parse_mdx("<DataTable src=\"t.csv\" />");
+        assert_eq!(get_raw_markdown(&nodes), "`t.csv`\n\n");
+
+        let DocNode::DataTable(table) = &mut nodes[0] else {
+            panic!("expected DataTable");
+        };
+        table.load("a,b\nx|y,2").unwrap();
+        assert_eq!(
+            get_raw_markdown(&nodes),
+            "| a | b |\n|---|---|\n| x\\|y | 2 |\n\n"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Definition list parser.
+//!
+//! A term on its own line followed by one or more `: definition` lines:
+//!
+//! ```text
+//! Idempotent
+//! : Sending the request twice has the same effect as sending it once.
+//!   Continuation lines are indented.
+//! ```
+//!
+//! Blank lines may separate consecutive terms of one list.
+
+use crate::parser::types::*;
+
+/// Split a markdown chunk into markdown and definition-list nodes.
+pub(super) fn split_definition_lists(markdown: &str) -> Vec<DocNode> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut nodes = Vec::new();
+    let mut text: Vec<&str> = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let starts_block = text.last().is_none_or(|l| l.trim().is_empty());
+        if !(starts_block && is_term_at(&lines, i)) {
+            text.push(lines[i]);
+            i += 1;
+            continue;
+        }
+
+        let mut items = Vec::new();
+        while is_term_at(&lines, i) {
+            let term = lines[i].trim().to_string();
+            i += 1;
+            let mut definitions: Vec<String> = Vec::new();
+            while let Some(line) = lines.get(i) {
+                if let Some(definition) = definition_line(line) {
+                    definitions.push(definition.to_string());
+                } else if line.starts_with("  ") && !line.trim().is_empty() {
+                    // Continuation of the current definition.
+                    if let Some(last) = definitions.last_mut() {
+                        last.push('\n');
+                        last.push_str(line.trim());
+                    }
+                } else {
+                    break;
+                }
+                i += 1;
+            }
+            items.push(DefinitionItem { term, definitions });
+
+            // Blank lines between terms keep the list going.
+            let next = (i..lines.len()).find(|&j| !lines[j].trim().is_empty());
+            match next {
+                Some(j) if is_term_at(&lines, j) => i = j,
+                _ => break,
+            }
+        }
+
+        flush_markdown(&mut nodes, &mut text);
+        nodes.push(DocNode::DefinitionList(DefinitionListNode { items }));
+    }
+
+    flush_markdown(&mut nodes, &mut text);
+    nodes
+}
+
+/// Whether line `i` is a term: plain text directly followed by a definition.
+fn is_term_at(lines: &[&str], i: usize) -> bool {
+    let Some(line) = lines.get(i) else {
+        return false;
+    };
+    let trimmed = line.trim();
+    let block_marker = trimmed.starts_with(['#', '>', '|', '-', '*', '+', '<', ':'])
+        || trimmed
+            .split_once(". ")
+            .is_some_and(|(n, _)| n.parse::<u32>().is_ok());
+    !trimmed.is_empty()
+        && !line.starts_with("    ")
+        && !block_marker
+        && lines.get(i + 1).and_then(|l| definition_line(l)).is_some()
+}
+
+fn definition_line(line: &str) -> Option<&str> {
+    line.strip_prefix(": ")
+        .or_else(|| line.strip_prefix(":\t"))
+        .map(str::trim)
+}
+
+fn flush_markdown(nodes: &mut Vec<DocNode>, text: &mut Vec<&str>) {
+    let markdown = text.join("\n");
+    if !markdown.trim().is_empty() {
+        nodes.push(DocNode::Markdown(markdown.trim().to_string()));
+    }
+    text.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::content::{get_raw_markdown, parse_mdx};
+
+    #[test]
+    fn splits_terms_and_definitions_out_of_markdown() {
+        let nodes = parse_mdx(
+            "Intro.\n\nIdempotent\n: Same effect when repeated.\n  Even on retries.\n\nSafe\n: Read-only.\n: Cacheable.\n\nOutro.",
+        );
+        assert_eq!(nodes.len(), 3);
+        assert!(matches!(&nodes[0], DocNode::Markdown(m) if m == "Intro."));
+        let DocNode::DefinitionList(list) = &nodes[1] else {
+            panic!("expected definition list, got {:?}", nodes[1]);
+        };
+        assert_eq!(list.items.len(), 2);
+        assert_eq!(list.items[0].term, "Idempotent");
+        assert_eq!(
+            list.items[0].definitions,
+            vec!["Same effect when repeated.\nEven on retries.".to_string()]
+        );
+        assert_eq!(list.items[1].definitions.len(), 2);
+        assert!(matches!(&nodes[2], DocNode::Markdown(m) if m == "Outro."));
+    }
+
+    #[test]
+    fn paragraph_continuations_and_lists_are_not_terms() {
+        let nodes = parse_mdx("A paragraph\nthat wraps\n: not a definition\n\n- item\n: nope");
+        assert!(nodes.iter().all(|n| matches!(n, DocNode::Markdown(_))));
+    }
+
+    #[test]
+    fn raw_markdown_keeps_definition_list_syntax() {
+        let raw = get_raw_markdown(&parse_mdx("Term\n: First.\n: Second."));
+        assert_eq!(raw, "Term\n: First.\n: Second.\n\n");
+    }
+}
@@ -0,0 +1,262 @@
+//! FileTree component parser.
+//!
+//! The body is either a nested markdown list or bare indented names:
+//!
+//! ```text
+//! <FileTree>
+//! - src/
+//!   - main.rs
+//!   - **lib.rs**
+//! - Cargo.toml
+//! </FileTree>
+//! ```
+//!
+//! Indentation sets the nesting. A trailing `/` marks a folder (an entry with
+//! children is one anyway), `**name**` highlights an entry, and a name with
+//! inner slashes (`src/bin/cli.rs`) expands into nested folders, merging with
+//! folders already listed at that level.
+
+use super::utils::find_closing_tag;
+use crate::parser::types::*;
+
+/// Try to parse a FileTree component.
+pub(super) fn try_parse_file_tree(content: &str) -> Option<(DocNode, &str)> {
+    if !content.starts_with("<FileTree>") {
+        return None;
+    }
+
+    let after_open = &content["<FileTree>".len()..];
+    let close_idx = find_closing_tag(after_open, "FileTree")?;
+    let inner = &after_open[..close_idx];
+    let rest = &after_open[close_idx + "</FileTree>".len()..];
+
+    let entries = parse_entries(inner);
+
+    Some((DocNode::FileTree(FileTreeNode { entries }), rest))
+}
+
+/// One non-empty line of the tree body.
+struct TreeLine<'a> {
+    indent: usize,
+    name: &'a str,
+    is_dir: bool,
+    highlighted: bool,
+}
+
+/// Parse the tree body into nested entries.
+fn parse_entries(content: &str) -> Vec<FileTreeEntry> {
+    let lines: Vec<TreeLine> = content.lines().filter_map(parse_line).collect();
+    build_entries(&lines)
+}
+
+/// Split a line into its indentation, name and markers.
+fn parse_line(line: &str) -> Option<TreeLine<'_>> {
+    let body = line.trim_start();
+    if body.trim().is_empty() {
+        return None;
+    }
+    // A tab counts as one indentation step of a typical two-space list.
+    let indent = line[..line.len() - body.len()]
+        .chars()
+        .map(|c| if c == '\t' { 2 } else { 1 })
+        .sum();
+
+    let name = body
+        .strip_prefix("- ")
+        .or_else(|| body.strip_prefix("* "))
+        .or_else(|| body.strip_prefix("+ "))
+        .unwrap_or(body)
+        .trim();
+
+    let (name, highlighted) = match name.strip_prefix("**").and_then(|n| n.strip_suffix("**")) {
+        Some(inner) if !inner.trim().is_empty() => (inner.trim(), true),
+        _ => (name, false),
+    };
+
+    let is_dir = name.ends_with('/');
+    let name = name.trim_end_matches('/');
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(TreeLine {
+        indent,
+        name,
+        is_dir,
+        highlighted,
+    })
+}
+
+/// Build entries from lines: each line owns the following, deeper-indented lines.
+fn build_entries(lines: &[TreeLine]) -> Vec<FileTreeEntry> {
+    let mut entries = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = &lines[i];
+        let end = lines[i + 1..]
+            .iter()
+            .position(|l| l.indent <= line.indent)
+            .map_or(lines.len(), |offset| i + 1 + offset);
+        let children = build_entries(&lines[i + 1..end]);
+        insert_entry(&mut entries, line, children);
+        i = end;
+    }
+
+    entries
+}
+
+/// Insert a line's entry, expanding `a/b/c` into nested folders.
+fn insert_entry(entries: &mut Vec<FileTreeEntry>, line: &TreeLine, children: Vec<FileTreeEntry>) {
+    let mut segments: Vec<&str> = line.name.split('/').filter(|s| !s.is_empty()).collect();
+    let Some(leaf) = segments.pop() else {
+        return;
+    };
+
+    let mut level = entries;
+    for segment in segments {
+        let idx = match level.iter().position(|e| e.is_dir && e.name == segment) {
+            Some(idx) => idx,
+            None => {
+                level.push(FileTreeEntry {
+                    name: segment.to_string(),
+                    is_dir: true,
+                    highlighted: false,
+                    children: Vec::new(),
+                });
+                level.len() - 1
+            }
+        };
+        level = &mut level[idx].children;
+    }
+
+    merge_entry(
+        level,
+        FileTreeEntry {
+            name: leaf.to_string(),
+            is_dir: line.is_dir || !children.is_empty(),
+            highlighted: line.highlighted,
+            children,
+        },
+    );
+}
+
+/// Add an entry to a level, merging a folder listed again (e.g. `src/` bare,
+/// then with children) into the existing one.
+fn merge_entry(level: &mut Vec<FileTreeEntry>, entry: FileTreeEntry) {
+    if entry.is_dir
+        && let Some(existing) = level.iter_mut().find(|e| e.is_dir && e.name == entry.name)
+    {
+        existing.highlighted |= entry.highlighted;
+        for child in entry.children {
+            merge_entry(&mut existing.children, child);
+        }
+        return;
+    }
+    level.push(entry);
+}
+
+/// Render a tree as `tree`-style plain text for the raw-markdown fallback.
+pub(super) fn file_tree_to_text(entries: &[FileTreeEntry]) -> String {
+    let mut out = String::new();
+    push_text_lines(&mut out, entries, "");
+    out
+}
+
+fn push_text_lines(out: &mut String, entries: &[FileTreeEntry], prefix: &str) {
+    for (i, entry) in entries.iter().enumerate() {
+        let last = i + 1 == entries.len();
+        out.push_str(prefix);
+        out.push_str(if last { "└── " } else { "├── " });
+        out.push_str(&entry.name);
+        if entry.is_dir {
+            out.push('/');
+        }
+        out.push('\n');
+        let child_prefix = format!("{prefix}{}", if last { "    " } else { "│   " });
+        push_text_lines(out, &entry.children, &child_prefix);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::content::{get_raw_markdown, parse_mdx};
+
+    fn tree(content: &str) -> Vec<FileTreeEntry> {
+        match parse_mdx(content).into_iter().next() {
+            Some(DocNode::FileTree(t)) => t.entries,
+            other => panic!("expected FileTree, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_nested_list_with_folders_and_highlight() {
+        let entries =
+            tree("<FileTree>\n- src/\n  - main.rs\n  - **lib.rs**\n- Cargo.toml\n</FileTree>");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "src");
+        assert!(entries[0].is_dir);
+        assert_eq!(entries[0].children.len(), 2);
+        assert!(!entries[0].children[0].highlighted);
+        assert_eq!(entries[0].children[1].name, "lib.rs");
+        assert!(entries[0].children[1].highlighted);
+        assert_eq!(entries[1].name, "Cargo.toml");
+        assert!(!entries[1].is_dir);
+    }
+
+    #[test]
+    fn parses_indented_names_without_list_markers() {
+        let entries = tree("<FileTree>\ndocs\n    _nav.json\n    guides/\nbuild.rs\n</FileTree>");
+        assert_eq!(entries.len(), 2);
+        // A folder without a trailing slash is inferred from its children.
+        assert!(entries[0].is_dir);
+        assert_eq!(entries[0].children[1].name, "guides");
+        assert!(entries[0].children[1].is_dir);
+        assert!(entries[0].children[1].children.is_empty());
+    }
+
+    #[test]
+    fn slash_paths_expand_and_merge_into_folders() {
+        let entries = tree("<FileTree>\nsrc/main.rs\nsrc/bin/cli.rs\n</FileTree>");
+        assert_eq!(entries.len(), 1);
+        let src = &entries[0];
+        assert_eq!(src.name, "src");
+        let names: Vec<&str> = src.children.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["main.rs", "bin"]);
+        assert_eq!(src.children[1].children[0].name, "cli.rs");
+    }
+
+    #[test]
+    fn folders_listed_twice_are_merged() {
+        let entries = tree(
+            "<FileTree>\n- src/\n- Cargo.toml\n- src/\n  - main.rs\n- src/lib.rs\n</FileTree>",
+        );
+        assert_eq!(entries.len(), 2, "got: {entries:?}");
+        assert_eq!(entries[0].name, "src");
+        let names: Vec<_> = entries[0]
+            .children
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["main.rs", "lib.rs"]);
+        assert_eq!(entries[1].name, "Cargo.toml");
+    }
+
+    #[test]
+    fn raw_markdown_falls_back_to_plain_text_tree() {
+        let nodes = parse_mdx("<FileTree>\n- src/\n  - main.rs\n- Cargo.toml\n</FileTree>");
+        let raw = get_raw_markdown(&nodes);
+        assert!(
+            raw.contains("├── src/\n│   └── main.rs\n└── Cargo.toml\n"),
+            "got: {raw}"
+        );
+    }
+
+    #[test]
+    fn file_tree_is_found_after_markdown() {
+        let nodes = parse_mdx("Layout:\n\n<FileTree>\n- a.rs\n</FileTree>\n\nAfter.");
+        assert_eq!(nodes.len(), 3, "got: {nodes:?}");
+        assert!(matches!(nodes[1], DocNode::FileTree(_)));
+    }
+}
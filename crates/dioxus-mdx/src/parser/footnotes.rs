@@ -0,0 +1,446 @@
+//! Footnote and abbreviation parser.
+//!
+//! `[^label]: text` footnote definitions and `*[API]: Application Programming
+//! Interface` abbreviation definitions are collected from the whole page, so a
+//! reference inside a `<Tab>` still finds a definition at the bottom. In the
+//! page's markdown, references become inline tags the renderer turns into links
+//! and `<abbr>`s:
+//!
+//! ```text
+//! <FootnoteRef ns="3fa2c1" label="note" n="1" />
+//! <Abbr title="Application Programming Interface">API</Abbr>
+//! ```
+//!
+//! Footnote ids carry a namespace derived from the page source, so two
+//! documents rendered on one page don't collide.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::term::{code_spans, find_terms};
+use super::utils::find_fenced_blocks;
+use crate::parser::types::*;
+
+static FOOTNOTE_DEF_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^ {0,3}\[\^([^\]\s]+)\]:[ \t]?(.*)$").unwrap());
+static ABBR_DEF_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^ {0,3}\*\[([^\]]+)\]:[ \t]*(.+)$").unwrap());
+static FOOTNOTE_REF_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[\^([^\]\s]+)\]").unwrap());
+static FOOTNOTE_REF_TAG_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"<FootnoteRef ns="([^"]+)" label="([^"]+)" n="(\d+)"( repeat)? />"#).unwrap()
+});
+static ABBR_TAG_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<Abbr title="([^"]*)">([^<]*)</Abbr>"#).unwrap());
+/// Links, raw tags and bare URLs: abbreviations inside them stay plain text.
+static PROTECTED_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"!?\[[^\]]*\]\([^)]*\)|<[^>]+>|https?://\S+").unwrap());
+
+/// Footnote and abbreviation definitions removed from a page.
+#[derive(Debug, Default)]
+pub(super) struct Notes {
+    /// `(label, markdown)` in definition order.
+    footnotes: Vec<(String, String)>,
+    abbreviations: Vec<Abbreviation>,
+}
+
+/// An inline tag written by [`apply_notes`].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum NoteTag {
+    /// Reference to footnote `label`; `repeat` for all but its first reference.
+    FootnoteRef {
+        namespace: String,
+        label: String,
+        number: usize,
+        repeat: bool,
+    },
+    /// An abbreviation occurrence.
+    Abbr { abbr: String, title: String },
+}
+
+/// Remove footnote and abbreviation definitions (outside fenced code).
+pub(super) fn extract_notes(content: &str) -> (String, Notes) {
+    let mut notes = Notes::default();
+    let mut out = String::with_capacity(content.len());
+    let mut last_end = 0;
+
+    for fence in find_fenced_blocks(content) {
+        out.push_str(&extract_from_text(
+            &content[last_end..fence.start],
+            &mut notes,
+        ));
+        out.push_str(&content[fence.start..fence.end]);
+        last_end = fence.end;
+    }
+    out.push_str(&extract_from_text(&content[last_end..], &mut notes));
+
+    (out, notes)
+}
+
+fn extract_from_text(text: &str, notes: &mut Notes) -> String {
+    let lines: Vec<&str> = text.split('\n').collect();
+    let mut kept: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        if let Some(caps) = ABBR_DEF_RE.captures(line) {
+            notes.abbreviations.push(Abbreviation {
+                abbr: caps[1].trim().to_string(),
+                title: caps[2].trim().to_string(),
+            });
+            i += 1;
+        } else if let Some(caps) = FOOTNOTE_DEF_RE.captures(line) {
+            let mut body = caps[2].to_string();
+            i += 1;
+            // Indented lines (and blank lines between them) continue the note.
+            while i < lines.len() {
+                if let Some(rest) = indented(lines[i]) {
+                    body.push('\n');
+                    body.push_str(rest);
+                    i += 1;
+                } else if lines[i].trim().is_empty()
+                    && lines
+                        .get(i + 1)
+                        .is_some_and(|next| indented(next).is_some())
+                {
+                    body.push('\n');
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            notes
+                .footnotes
+                .push((caps[1].to_string(), body.trim().to_string()));
+        } else {
+            kept.push(line);
+            i += 1;
+        }
+    }
+
+    kept.join("\n")
+}
+
+fn indented(line: &str) -> Option<&str> {
+    line.strip_prefix("    ")
+        .or_else(|| line.strip_prefix('\t'))
+        .filter(|rest| !rest.trim().is_empty())
+}
+
+/// Rewrite footnote references and abbreviations in every markdown node, then
+/// append the `Footnotes` and `Abbreviations` nodes.
+pub(super) fn apply_notes(nodes: &mut Vec<DocNode>, notes: Notes, source: &str) {
+    if notes.footnotes.is_empty() && notes.abbreviations.is_empty() {
+        return;
+    }
+
+    let mut abbreviations = notes.abbreviations.clone();
+    abbreviations.sort_by_key(|a| std::cmp::Reverse(a.abbr.len()));
+    let mut ctx = Rewrite {
+        namespace: namespace(source),
+        defined: notes
+            .footnotes
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .collect(),
+        order: Vec::new(),
+        abbreviations: &abbreviations,
+    };
+    rewrite_nodes(nodes, &mut ctx);
+
+    if !notes.footnotes.is_empty() {
+        // Referenced notes in order of first reference, then the rest.
+        let mut labels: Vec<String> = ctx.order.clone();
+        for (label, _) in &notes.footnotes {
+            if !labels.contains(label) {
+                labels.push(label.clone());
+            }
+        }
+        let contents: HashMap<&str, &str> = notes
+            .footnotes
+            .iter()
+            .map(|(label, content)| (label.as_str(), content.as_str()))
+            .collect();
+        let items = labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| FootnoteItem {
+                label: label.clone(),
+                number: i + 1,
+                content: contents[label.as_str()].to_string(),
+            })
+            .collect();
+        nodes.push(DocNode::Footnotes(FootnotesNode {
+            namespace: ctx.namespace,
+            items,
+        }));
+    }
+
+    if !notes.abbreviations.is_empty() {
+        nodes.push(DocNode::Abbreviations(notes.abbreviations));
+    }
+}
+
+struct Rewrite<'a> {
+    namespace: String,
+    defined: Vec<&'a str>,
+    /// Referenced labels in order of first reference.
+    order: Vec<String>,
+    abbreviations: &'a [Abbreviation],
+}
+
+fn rewrite_nodes(nodes: &mut [DocNode], ctx: &mut Rewrite) {
+    for node in nodes {
+        match node {
+            DocNode::Markdown(md) => *md = rewrite_markdown(md, ctx),
+            DocNode::DefinitionList(list) => {
+                for item in &mut list.items {
+                    item.term = rewrite_markdown(&item.term, ctx);
+                    for definition in &mut item.definitions {
+                        *definition = rewrite_markdown(definition, ctx);
+                    }
+                }
+            }
+            other => {
+                for children in other.children_mut() {
+                    rewrite_nodes(children, ctx);
+                }
+            }
+        }
+    }
+}
+
+fn rewrite_markdown(md: &str, ctx: &mut Rewrite) -> String {
+    let mut protected: Vec<(usize, usize)> = code_spans(md);
+    protected.extend(find_terms(md).iter().map(|t| (t.start, t.end)));
+    let is_protected = |protected: &[(usize, usize)], start: usize, end: usize| {
+        protected.iter().any(|&(s, e)| start < e && s < end)
+    };
+
+    // (start, end, replacement)
+    let mut edits: Vec<(usize, usize, String)> = Vec::new();
+
+    for caps in FOOTNOTE_REF_RE.captures_iter(md) {
+        let whole = caps.get(0).unwrap();
+        let label = &caps[1];
+        if !ctx.defined.contains(&label) || is_protected(&protected, whole.start(), whole.end()) {
+            continue;
+        }
+        let (number, repeat) = match ctx.order.iter().position(|l| l == label) {
+            Some(i) => (i + 1, true),
+            None => {
+                ctx.order.push(label.to_string());
+                (ctx.order.len(), false)
+            }
+        };
+        edits.push((
+            whole.start(),
+            whole.end(),
+            format!(
+                "<FootnoteRef ns=\"{}\" label=\"{label}\" n=\"{number}\"{} />",
+                ctx.namespace,
+                if repeat { " repeat" } else { "" }
+            ),
+        ));
+    }
+
+    protected.extend(PROTECTED_RE.find_iter(md).map(|m| (m.start(), m.end())));
+    protected.extend(edits.iter().map(|&(s, e, _)| (s, e)));
+
+    for abbreviation in ctx.abbreviations {
+        for (start, _) in md.match_indices(abbreviation.abbr.as_str()) {
+            let end = start + abbreviation.abbr.len();
+            let word_start = !md[..start]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric);
+            let word_end = !md[end..].chars().next().is_some_and(char::is_alphanumeric);
+            if !word_start || !word_end || is_protected(&protected, start, end) {
+                continue;
+            }
+            protected.push((start, end));
+            edits.push((
+                start,
+                end,
+                format!(
+                    "<Abbr title=\"{}\">{}</Abbr>",
+                    abbreviation.title.replace('"', "&quot;"),
+                    abbreviation.abbr
+                ),
+            ));
+        }
+    }
+
+    if edits.is_empty() {
+        return md.to_string();
+    }
+
+    edits.sort_by_key(|(start, _, _)| *start);
+    let mut out = String::with_capacity(md.len());
+    let mut last = 0;
+    for (start, end, replacement) in edits {
+        out.push_str(&md[last..start]);
+        out.push_str(&replacement);
+        last = end;
+    }
+    out.push_str(&md[last..]);
+    out
+}
+
+/// Short stable hash of the page source (FNV-1a), used as the id namespace.
+fn namespace(source: &str) -> String {
+    let hash = source.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("{:06x}", hash & 0x00ff_ffff)
+}
+
+/// Find `<FootnoteRef>` and `<Abbr>` tags, skipping inline code spans.
+pub(crate) fn find_note_tags(markdown: &str) -> Vec<(Range<usize>, NoteTag)> {
+    let code = code_spans(markdown);
+    let in_code = |start: usize| code.iter().any(|&(s, e)| start >= s && start < e);
+
+    let refs = FOOTNOTE_REF_TAG_RE.captures_iter(markdown).map(|caps| {
+        (
+            caps.get(0).unwrap().range(),
+            NoteTag::FootnoteRef {
+                namespace: caps[1].to_string(),
+                label: caps[2].to_string(),
+                number: caps[3].parse().unwrap_or(0),
+                repeat: caps.get(4).is_some(),
+            },
+        )
+    });
+    let abbrs = ABBR_TAG_RE.captures_iter(markdown).map(|caps| {
+        (
+            caps.get(0).unwrap().range(),
+            NoteTag::Abbr {
+                abbr: caps[2].to_string(),
+                title: caps[1].replace("&quot;", "\""),
+            },
+        )
+    });
+
+    let mut tags: Vec<_> = refs
+        .chain(abbrs)
+        .filter(|(r, _)| !in_code(r.start))
+        .collect();
+    tags.sort_by_key(|(range, _)| range.start);
+    tags
+}
+
+/// Turn `<FootnoteRef>` tags back into `[^label]` and `<Abbr>` into its text.
+pub(super) fn strip_notes(markdown: &str) -> String {
+    let tags = find_note_tags(markdown);
+    if tags.is_empty() {
+        return markdown.to_string();
+    }
+
+    let mut out = String::with_capacity(markdown.len());
+    let mut last = 0;
+    for (range, tag) in tags {
+        out.push_str(&markdown[last..range.start]);
+        match tag {
+            NoteTag::FootnoteRef { label, .. } => out.push_str(&format!("[^{label}]")),
+            NoteTag::Abbr { abbr, .. } => out.push_str(&abbr),
+        }
+        last = range.end;
+    }
+    out.push_str(&markdown[last..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::content::{get_raw_markdown, parse_mdx};
+
+    const PAGE: &str = "Uses the API[^src] twice[^src].\n\n<Tabs>\n<Tab title=\"A\">\nSee the API note[^other].\n</Tab>\n</Tabs>\n\n[^other]: Second note.\n[^src]: First note,\n    over two lines.\n\n*[API]: Application Programming Interface";
+
+    #[test]
+    fn references_are_numbered_and_namespaced_across_nested_content() {
+        let nodes = parse_mdx(PAGE);
+        let DocNode::Markdown(md) = &nodes[0] else {
+            panic!("expected markdown, got {:?}", nodes[0]);
+        };
+        let Some(DocNode::Footnotes(footnotes)) =
+            nodes.iter().find(|n| matches!(n, DocNode::Footnotes(_)))
+        else {
+            panic!("expected footnotes node");
+        };
+        assert_eq!(footnotes.namespace.len(), 6);
+
+        let tags: Vec<NoteTag> = find_note_tags(md).into_iter().map(|(_, t)| t).collect();
+        assert_eq!(
+            tags[1],
+            NoteTag::FootnoteRef {
+                namespace: footnotes.namespace.clone(),
+                label: "src".to_string(),
+                number: 1,
+                repeat: false,
+            }
+        );
+        assert!(matches!(
+            &tags[2],
+            NoteTag::FootnoteRef {
+                number: 1,
+                repeat: true,
+                ..
+            }
+        ));
+        assert_ne!(
+            footnotes.namespace,
+            namespace("another page"),
+            "namespaces differ between documents"
+        );
+        assert_eq!(footnotes.items[0].label, "src");
+        assert_eq!(footnotes.items[0].content, "First note,\nover two lines.");
+        assert_eq!(footnotes.items[1].label, "other");
+        assert_eq!(footnotes.items[1].number, 2);
+    }
+
+    #[test]
+    fn abbreviations_wrap_whole_words_outside_code_and_links() {
+        let nodes = parse_mdx(
+            "The API and `API` and [API](/api) and APIs.\n\n*[API]: Application \"Programming\" Interface",
+        );
+        let DocNode::Markdown(md) = &nodes[0] else {
+            panic!("expected markdown");
+        };
+        assert_eq!(
+            md,
+            "The <Abbr title=\"Application &quot;Programming&quot; Interface\">API</Abbr> and `API` and [API](/api) and APIs."
+        );
+        assert!(matches!(nodes.last(), Some(DocNode::Abbreviations(a)) if a[0].abbr == "API"));
+    }
+
+    #[test]
+    fn raw_markdown_restores_references_and_lists_definitions() {
+        let raw = get_raw_markdown(&parse_mdx(PAGE));
+        assert!(
+            raw.starts_with("Uses the API[^src] twice[^src]."),
+            "got: {raw}"
+        );
+        assert!(
+            raw.contains(
+                "## Footnotes\n\n[^src]: First note,\n    over two lines.\n[^other]: Second note.\n"
+            ),
+            "got: {raw}"
+        );
+        assert!(
+            raw.contains("*[API]: Application Programming Interface"),
+            "got: {raw}"
+        );
+    }
+
+    #[test]
+    fn definitions_inside_code_fences_are_kept() {
+        let nodes = parse_mdx("```md\n[^a]: not a note\n```");
+        assert_eq!(nodes.len(), 1);
+        assert!(matches!(&nodes[0], DocNode::CodeBlock(c) if c.code == "[^a]: not a note"));
+    }
+}
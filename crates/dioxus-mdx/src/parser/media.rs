@@ -0,0 +1,111 @@
+//! Video and embed component parsers.
+//!
+//! ```text
+//! <Video src="/media/demo.mp4" poster="/media/demo.jpg" captions="/media/demo.vtt" />
+//! <YouTube id="dQw4w9WgXcQ" title="Intro" start="42" />
+//! <Embed src="https://codesandbox.io/embed/abc" title="Sandbox" height="500" />
+//! ```
+
+use super::utils::{extract_attr, self_closing_tag};
+use crate::parser::types::*;
+
+/// Try to parse a `<Video src="..." />` component.
+pub(super) fn try_parse_video(content: &str) -> Option<(DocNode, &str)> {
+    let (attrs, rest) = self_closing_tag(content, "Video")?;
+    let src = extract_attr(attrs, "src")?;
+
+    Some((
+        DocNode::Video(VideoNode {
+            src,
+            title: extract_attr(attrs, "title"),
+            poster: extract_attr(attrs, "poster"),
+            captions: extract_attr(attrs, "captions"),
+            captions_lang: extract_attr(attrs, "captionsLang").unwrap_or_else(|| "en".to_string()),
+        }),
+        rest,
+    ))
+}
+
+/// Try to parse a `<YouTube id="..." />` component.
+pub(super) fn try_parse_youtube(content: &str) -> Option<(DocNode, &str)> {
+    let (attrs, rest) = self_closing_tag(content, "YouTube")?;
+    let id = extract_attr(attrs, "id")?;
+
+    Some((
+        DocNode::YouTube(YouTubeNode {
+            id,
+            title: extract_attr(attrs, "title"),
+            start: extract_attr(attrs, "start").and_then(|s| s.parse().ok()),
+        }),
+        rest,
+    ))
+}
+
+/// Try to parse an `<Embed src="..." />` component.
+pub(super) fn try_parse_embed(content: &str) -> Option<(DocNode, &str)> {
+    let (attrs, rest) = self_closing_tag(content, "Embed")?;
+    let src = extract_attr(attrs, "src")?;
+
+    Some((
+        DocNode::Embed(EmbedNode {
+            src,
+            title: extract_attr(attrs, "title"),
+            height: extract_attr(attrs, "height").and_then(|h| h.parse().ok()),
+        }),
+        rest,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::content::{get_raw_markdown, parse_mdx};
+
+    #[test]
+    fn parses_video_with_poster_and_captions() {
+        let (node, rest) = try_parse_video(
+            "<Video src=\"/m/demo.mp4\" poster=\"/m/demo.jpg\" captions=\"/m/demo.vtt\" captionsLang=\"de\" />after",
+        )
+        .unwrap();
+        let DocNode::Video(video) = node else {
+            panic!("expected Video, got {node:?}");
+        };
+        assert_eq!(video.src, "/m/demo.mp4");
+        assert_eq!(video.poster.as_deref(), Some("/m/demo.jpg"));
+        assert_eq!(video.captions.as_deref(), Some("/m/demo.vtt"));
+        assert_eq!(video.captions_lang, "de");
+        assert_eq!(rest, "after");
+    }
+
+    #[test]
+    fn parses_youtube_and_embed_attributes() {
+        let nodes = parse_mdx(
+            "<YouTube id=\"abc123\" start=\"42\" />\n\n<Embed src=\"https://example.com/x\" height=\"500\" />",
+        );
+        assert!(
+            matches!(&nodes[0], DocNode::YouTube(y) if y.id == "abc123" && y.start == Some(42))
+        );
+        assert!(
+            matches!(&nodes[1], DocNode::Embed(e) if e.height == Some(500) && e.title.is_none())
+        );
+        // `<VideoPlayer>` is not a `<Video>`.
+        assert!(try_parse_video("<VideoPlayer src=\"a.mp4\" />").is_none());
+    }
+
+    #[test]
+    fn raw_markdown_degrades_to_links() {
+        let nodes = parse_mdx(
+            "<Video src=\"/m/demo.mp4\" title=\"Demo\" />\n\n<YouTube id=\"abc123\" start=\"42\" />\n\n<Embed src=\"https://example.com/x\" />",
+        );
+        let raw = get_raw_markdown(&nodes);
+        assert!(raw.contains("[Video: Demo](/m/demo.mp4)"), "got: {raw}");
+        assert!(
+            raw.contains("[YouTube video](https://www.youtube.com/watch?v=abc123&t=42s)"),
+            "got: {raw}"
+        );
+        assert!(
+            raw.contains("[Embedded content](https://example.com/x)"),
+            "got: {raw}"
+        );
+    }
+}
@@ -5,27 +5,61 @@
 //! Steps, and Callouts into an intermediate representation for rendering.
 
 mod accordion;
+mod api_ref;
+mod asyncapi_parser;
+mod asyncapi_types;
 mod callout;
 mod card;
 mod code_group;
 mod content;
+mod data_table;
+mod definition_list;
 mod fields;
+mod file_tree;
+mod footnotes;
 mod frontmatter;
 mod heading;
+mod media;
+mod openapi_31;
+mod openapi_bundle;
+mod openapi_diff;
+mod openapi_groups;
+mod openapi_models;
 mod openapi_parser;
+mod openapi_playground;
+mod openapi_samples;
+mod openapi_security;
 mod openapi_tag;
 mod openapi_types;
+mod openapi_validate;
 mod steps;
+mod swagger;
 mod tabs;
+mod term;
+mod terminal;
 mod types;
 mod update;
 mod utils;
 
+pub use asyncapi_parser::{AsyncApiError, parse_asyncapi};
+pub use asyncapi_types::*;
 pub use content::{get_raw_markdown, parse_mdx};
+pub use data_table::DataTableError;
+#[cfg(feature = "components")]
+pub(crate) use data_table::parse_bool;
+#[cfg(feature = "components")]
+pub(crate) use footnotes::{NoteTag, find_note_tags};
 pub use frontmatter::extract_frontmatter;
 pub use heading::strip_leading_h1;
+pub use openapi_bundle::{bundle_openapi_files, parse_openapi_bundle};
+pub use openapi_diff::{ApiChange, ApiChangeKind, ChangeTarget, SpecDiff, diff_specs};
+pub use openapi_groups::TagSection;
 pub use openapi_parser::{OpenApiError, parse_openapi};
+pub use openapi_playground::{PlaygroundError, PlaygroundRequest};
+pub use openapi_samples::SampleLanguage;
 pub use openapi_types::*;
+pub use openapi_validate::{ExampleViolation, SchemaViolation};
+pub use term::{TermRef, code_spans, find_terms};
 pub use types::*;
 
 /// Parse a complete MDX document, extracting frontmatter and content.
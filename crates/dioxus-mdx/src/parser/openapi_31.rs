@@ -0,0 +1,223 @@
+//! OpenAPI 3.1 support.
+//!
+//! `openapiv3` only models 3.0, so a 3.1 document is rewritten into its 3.0
+//! equivalent before deserializing. The rewrite covers the JSON Schema
+//! 2020-12 keywords that change shape between the versions:
+//!
+//! - `type: [string, "null"]` becomes `type: string` + `nullable: true`, and
+//!   several non-null types become an `anyOf`
+//! - `anyOf`/`oneOf` with a `{type: "null"}` branch drop it and set `nullable`
+//! - `const: x` becomes `enum: [x]`
+//! - schema `examples: [...]` becomes `example` (the first one)
+//! - numeric `exclusiveMinimum`/`exclusiveMaximum` become the 3.0 boolean form
+//! - a missing `paths` object is filled in
+//! - `webhooks` moves to the `x-webhooks` extension, where 3.0 specs declare
+//!   them
+//!
+//! Keywords 3.0 has no place for (`$defs`, `prefixItems`, ...) are left alone
+//! and ignored by the 3.0 model.
+
+use serde_json::{Map, Value, json};
+
+/// Whether `document` declares OpenAPI 3.1.
+pub(super) fn is_openapi_31(document: &Value) -> bool {
+    document
+        .get("openapi")
+        .and_then(Value::as_str)
+        .is_some_and(|v| v.starts_with("3.1"))
+}
+
+/// Rewrite a 3.1 document in place into the shape `openapiv3` accepts.
+pub(super) fn downgrade(document: &mut Value) {
+    if let Some(root) = document.as_object_mut() {
+        root.entry("paths").or_insert_with(|| json!({}));
+        if let Some(webhooks) = root.remove("webhooks") {
+            root.entry("x-webhooks").or_insert(webhooks);
+        }
+    }
+    downgrade_schema(document);
+}
+
+/// Rewrite a standalone JSON Schema in place, as [`downgrade`] does for the
+/// schemas in a document.
+pub(super) fn downgrade_schema(schema: &mut Value) {
+    walk(schema, false);
+}
+
+/// Keys whose value is a map of names to schemas, not a schema itself.
+const NAME_MAPS: &[&str] = &[
+    "properties",
+    "patternProperties",
+    "$defs",
+    "definitions",
+    "schemas",
+];
+
+/// Keys holding example or literal data, never schemas.
+const DATA_KEYS: &[&str] = &["example", "examples", "default", "enum", "const", "value"];
+
+fn walk(value: &mut Value, name_map: bool) {
+    match value {
+        Value::Object(object) => {
+            if !name_map {
+                rewrite_schema_keywords(object);
+            }
+            for (key, child) in object.iter_mut() {
+                if DATA_KEYS.contains(&key.as_str()) {
+                    continue;
+                }
+                walk(child, NAME_MAPS.contains(&key.as_str()));
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                walk(item, false);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn rewrite_schema_keywords(object: &mut Map<String, Value>) {
+    if let Some(Value::Array(types)) = object.get("type") {
+        let nullable = types.iter().any(|t| t == "null");
+        let mut rest: Vec<Value> = types.iter().filter(|t| *t != "null").cloned().collect();
+        match rest.len() {
+            0 => {
+                object.insert("type".into(), json!("null"));
+            }
+            1 => {
+                object.insert("type".into(), rest.remove(0));
+            }
+            _ => {
+                object.remove("type");
+                let branches = rest.into_iter().map(|t| json!({ "type": t })).collect();
+                object.insert("anyOf".into(), Value::Array(branches));
+            }
+        }
+        if nullable && object.get("type") != Some(&json!("null")) {
+            object.insert("nullable".into(), json!(true));
+        }
+    }
+
+    for key in ["anyOf", "oneOf"] {
+        if let Some(Value::Array(branches)) = object.get_mut(key) {
+            let before = branches.len();
+            branches.retain(|b| b.get("type") != Some(&json!("null")));
+            if branches.len() < before && !branches.is_empty() {
+                object.insert("nullable".into(), json!(true));
+            }
+        }
+    }
+
+    if let Some(value) = object.remove("const") {
+        if !object.contains_key("type")
+            && let Some(ty) = json_type(&value)
+        {
+            object.insert("type".into(), json!(ty));
+        }
+        object.entry("enum").or_insert_with(|| json!([value]));
+    }
+
+    if let Some(Value::Array(examples)) = object.get("examples") {
+        if let Some(first) = examples.first().cloned() {
+            object.entry("example").or_insert(first);
+        }
+        object.remove("examples");
+    }
+
+    for (exclusive, bound) in [
+        ("exclusiveMinimum", "minimum"),
+        ("exclusiveMaximum", "maximum"),
+    ] {
+        if let Some(limit) = object.get(exclusive).filter(|v| v.is_number()).cloned() {
+            object.insert(bound.into(), limit);
+            object.insert(exclusive.into(), json!(true));
+        }
+    }
+}
+
+/// JSON Schema type name of a literal value.
+fn json_type(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::String(_) => Some("string"),
+        Value::Bool(_) => Some("boolean"),
+        Value::Number(n) if n.is_i64() || n.is_u64() => Some("integer"),
+        Value::Number(_) => Some("number"),
+        Value::Array(_) => Some("array"),
+        Value::Object(_) => Some("object"),
+        Value::Null => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::openapi_parser::parse_openapi;
+    use crate::parser::openapi_types::*;
+
+    const FIXTURE: &str = include_str!("fixtures/openapi-3.1.yaml");
+
+    #[test]
+    fn type_arrays_become_nullable_types() {
+        let mut schema = json!({ "type": ["string", "null"], "format": "email" });
+        downgrade_schema(&mut schema);
+        assert_eq!(
+            schema,
+            json!({ "type": "string", "nullable": true, "format": "email" })
+        );
+    }
+
+    #[test]
+    fn literal_data_and_property_names_are_left_alone() {
+        let mut doc = json!({
+            "paths": {},
+            "properties": { "const": { "type": "string" } },
+            "example": { "type": ["a", "b"] }
+        });
+        let before = doc.clone();
+        downgrade(&mut doc);
+        assert_eq!(doc, before);
+    }
+
+    #[test]
+    fn fixture_parses_into_the_common_model() {
+        let spec = parse_openapi(FIXTURE).unwrap();
+        assert_eq!(spec.info.title, "Fleet API");
+        assert_eq!(spec.operations.len(), 2);
+
+        let vehicle = &spec.schemas["Vehicle"];
+        let nickname = &vehicle.properties["nickname"];
+        assert_eq!(nickname.schema_type, SchemaType::String);
+        assert!(nickname.nullable);
+
+        let kind = &vehicle.properties["kind"];
+        assert_eq!(kind.schema_type, SchemaType::String);
+        assert_eq!(kind.enum_values, ["vehicle"]);
+
+        let mileage = &vehicle.properties["mileage"];
+        assert_eq!(mileage.schema_type, SchemaType::Integer);
+        assert_eq!(mileage.example.as_deref(), Some("12000"));
+
+        let id = &vehicle.properties["id"];
+        assert_eq!(id.any_of.len(), 2);
+
+        let owner = &vehicle.properties["owner"];
+        assert!(owner.nullable);
+        assert_eq!(owner.any_of.len(), 1);
+        assert_eq!(owner.any_of[0].ref_name.as_deref(), Some("Owner"));
+
+        assert_eq!(spec.schemas["Nothing"].schema_type, SchemaType::Null);
+    }
+
+    #[test]
+    fn webhooks_only_document_parses() {
+        let spec = parse_openapi(
+            "openapi: 3.1.0\ninfo: {title: Hooks, version: '1'}\nwebhooks:\n  ping:\n    post:\n      responses:\n        '200': {description: OK}\n",
+        )
+        .unwrap();
+        assert!(spec.operations.is_empty());
+        assert_eq!(spec.webhooks[0].name, "ping");
+        assert_eq!(spec.webhooks[0].operations[0].method, HttpMethod::Post);
+    }
+}
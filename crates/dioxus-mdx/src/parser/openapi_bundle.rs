@@ -0,0 +1,545 @@
+//! Multi-file OpenAPI specs.
+//!
+//! Relative `$ref`s (`./schemas/pet.yaml`, `common.yaml#/components/schemas/Error`)
+//! are followed through a set of already-loaded documents, or through a
+//! callback that reads them (see [`bundle_openapi_files`]). Each target is
+//! hoisted into the root document's `components` section (`definitions`,
+//! `parameters` and `responses` for Swagger 2.0) under its own name, so the
+//! result is a single spec with only local references. Path items, and
+//! anything else without a components section, are inlined in place.
+//!
+//! Remote `http(s)://` references are left untouched.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::{Value, json};
+
+use super::openapi_parser::{OpenApiError, from_document};
+use super::openapi_types::OpenApiSpec;
+use super::swagger::is_swagger_2;
+
+/// Parse a spec split across several files.
+///
+/// `documents` maps file paths (e.g. `"openapi.yaml"`, `"schemas/pet.yaml"`)
+/// to their YAML/JSON content, and `root` is the path of the entry document.
+/// Relative `$ref`s resolve against the referencing file's directory; a
+/// reference to a file missing from `documents` is an
+/// [`OpenApiError::InvalidSpec`].
+pub fn parse_openapi_bundle(
+    root: &str,
+    documents: &HashMap<&str, &str>,
+) -> Result<OpenApiSpec, OpenApiError> {
+    from_document(bundle(root, documents)?)
+}
+
+/// Merge `root` and every document it references into one self-contained
+/// document.
+pub(super) fn bundle(root: &str, documents: &HashMap<&str, &str>) -> Result<Value, OpenApiError> {
+    let documents: HashMap<String, &str> = documents
+        .iter()
+        .map(|(path, text)| (normalize(path), *text))
+        .collect();
+    bundle_openapi_files(root, &mut |file| {
+        documents
+            .get(file)
+            .map(|text| text.to_string())
+            .ok_or_else(|| "not in the document set".to_string())
+    })
+}
+
+/// Merge a spec split across several files into one self-contained document,
+/// reading each file through `read`.
+///
+/// `read` gets paths normalized against `root` (`"api/openapi.yaml"`,
+/// `"api/schemas/pet.yaml"`) and is called once per file. Its errors are
+/// reported as an [`OpenApiError::InvalidSpec`] naming the referencing file.
+/// This is what `dioxus-docs-kit-build`'s `bundle_openapi` runs on the files
+/// on disk.
+pub fn bundle_openapi_files(
+    root: &str,
+    read: &mut dyn FnMut(&str) -> Result<String, String>,
+) -> Result<Value, OpenApiError> {
+    let root = normalize(root);
+    let mut bundler = Bundler {
+        read,
+        loaded: HashMap::new(),
+        root: root.clone(),
+        swagger: false,
+        hoisted: HashMap::new(),
+        taken: HashSet::new(),
+        components: Vec::new(),
+        inlining: Vec::new(),
+    };
+
+    let mut document = bundler.load(&root, &root)?.clone();
+    bundler.swagger = is_swagger_2(&document);
+    for kind in Kind::ALL {
+        if let Some(section) = bundler.section(kind)
+            && let Some(existing) = lookup(&document, section).and_then(Value::as_object)
+        {
+            for name in existing.keys() {
+                bundler.taken.insert((section, name.clone()));
+            }
+        }
+    }
+
+    bundler.walk(&mut document, &root, Kind::Other)?;
+
+    for (section, name, value) in bundler.components {
+        let mut target = &mut document;
+        for segment in section {
+            target = target
+                .as_object_mut()
+                .expect("sections are nested objects")
+                .entry(*segment)
+                .or_insert_with(|| json!({}));
+        }
+        if let Some(target) = target.as_object_mut() {
+            target.insert(name, value);
+        }
+    }
+    Ok(document)
+}
+
+/// What a `$ref` at some position points to, which decides where its target
+/// is hoisted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Schema,
+    Parameter,
+    Response,
+    RequestBody,
+    Header,
+    Example,
+    Link,
+    Callback,
+    PathItem,
+    Other,
+}
+
+impl Kind {
+    const ALL: [Kind; 10] = [
+        Kind::Schema,
+        Kind::Parameter,
+        Kind::Response,
+        Kind::RequestBody,
+        Kind::Header,
+        Kind::Example,
+        Kind::Link,
+        Kind::Callback,
+        Kind::PathItem,
+        Kind::Other,
+    ];
+}
+
+/// Path of a components section, e.g. `["components", "schemas"]`.
+type Section = &'static [&'static str];
+
+struct Bundler<'a> {
+    read: &'a mut dyn FnMut(&str) -> Result<String, String>,
+    loaded: HashMap<String, Value>,
+    root: String,
+    swagger: bool,
+    /// `(file, JSON pointer)` → local `$ref` it was hoisted to.
+    hoisted: HashMap<(String, String), String>,
+    /// Component names in use, per section.
+    taken: HashSet<(Section, String)>,
+    /// Hoisted targets, inserted into the root once the walk is done.
+    components: Vec<(Section, String, Value)>,
+    /// Targets currently being inlined, to report cycles.
+    inlining: Vec<(String, String)>,
+}
+
+impl Bundler<'_> {
+    fn load(&mut self, file: &str, referrer: &str) -> Result<&Value, OpenApiError> {
+        if !self.loaded.contains_key(file) {
+            let text = (self.read)(file).map_err(|e| {
+                OpenApiError::InvalidSpec(format!("{referrer}: $ref to \"{file}\": {e}"))
+            })?;
+            let value = serde_yaml::from_str::<serde_yaml::Value>(&text)
+                .map_err(|e| e.to_string())
+                .and_then(|v| serde_json::to_value(v).map_err(|e| e.to_string()))
+                .map_err(|e| OpenApiError::ParseError(format!("{file}: {e}")))?;
+            self.loaded.insert(file.to_string(), value);
+        }
+        Ok(&self.loaded[file])
+    }
+
+    fn section(&self, kind: Kind) -> Option<Section> {
+        if self.swagger {
+            return match kind {
+                Kind::Schema => Some(&["definitions"]),
+                Kind::Parameter => Some(&["parameters"]),
+                Kind::Response => Some(&["responses"]),
+                _ => None,
+            };
+        }
+        match kind {
+            Kind::Schema => Some(&["components", "schemas"]),
+            Kind::Parameter => Some(&["components", "parameters"]),
+            Kind::Response => Some(&["components", "responses"]),
+            Kind::RequestBody => Some(&["components", "requestBodies"]),
+            Kind::Header => Some(&["components", "headers"]),
+            Kind::Example => Some(&["components", "examples"]),
+            Kind::Link => Some(&["components", "links"]),
+            Kind::Callback => Some(&["components", "callbacks"]),
+            Kind::PathItem | Kind::Other => None,
+        }
+    }
+
+    /// Resolve external references in `value`, which sits in `file` at a
+    /// position holding a `kind`.
+    fn walk(&mut self, value: &mut Value, file: &str, kind: Kind) -> Result<(), OpenApiError> {
+        if value.get("$ref").is_some_and(Value::is_string) {
+            return self.resolve_ref(value, file, kind);
+        }
+
+        match value {
+            Value::Object(object) if kind == Kind::Callback => {
+                for child in object.values_mut() {
+                    self.walk(child, file, Kind::PathItem)?;
+                }
+            }
+            Value::Object(object) => {
+                for (key, child) in object.iter_mut() {
+                    match key.as_str() {
+                        // Literal data, never references.
+                        "example" | "default" | "enum" | "const" | "value" => {}
+                        "schema"
+                        | "items"
+                        | "not"
+                        | "additionalProperties"
+                        | "contains"
+                        | "if"
+                        | "then"
+                        | "else"
+                        | "propertyNames" => self.walk(child, file, Kind::Schema)?,
+                        "allOf" | "anyOf" | "oneOf" | "prefixItems" | "properties"
+                        | "patternProperties" | "$defs" | "definitions" | "schemas" => {
+                            self.walk_entries(child, file, Kind::Schema)?
+                        }
+                        "parameters" => self.walk_entries(child, file, Kind::Parameter)?,
+                        "responses" => self.walk_entries(child, file, Kind::Response)?,
+                        "requestBody" => self.walk(child, file, Kind::RequestBody)?,
+                        "requestBodies" => self.walk_entries(child, file, Kind::RequestBody)?,
+                        "headers" => self.walk_entries(child, file, Kind::Header)?,
+                        "examples" => self.walk_entries(child, file, Kind::Example)?,
+                        "links" => self.walk_entries(child, file, Kind::Link)?,
+                        "callbacks" => self.walk_entries(child, file, Kind::Callback)?,
+                        "paths" | "webhooks" | "pathItems" => {
+                            self.walk_entries(child, file, Kind::PathItem)?
+                        }
+                        _ => self.walk(child, file, Kind::Other)?,
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.walk(item, file, Kind::Other)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Walk each value of a map, or each item of a list, as a `kind`.
+    fn walk_entries(
+        &mut self,
+        value: &mut Value,
+        file: &str,
+        kind: Kind,
+    ) -> Result<(), OpenApiError> {
+        match value {
+            Value::Object(object) => {
+                for child in object.values_mut() {
+                    self.walk(child, file, kind)?;
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.walk(item, file, kind)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn resolve_ref(
+        &mut self,
+        value: &mut Value,
+        file: &str,
+        kind: Kind,
+    ) -> Result<(), OpenApiError> {
+        let reference = value["$ref"].as_str().unwrap_or_default().to_string();
+        if reference.contains("://") {
+            return Ok(());
+        }
+        let (path, pointer) = reference.split_once('#').unwrap_or((&reference, ""));
+        let target_file = if path.is_empty() {
+            file.to_string()
+        } else {
+            resolve(file, path)
+        };
+
+        if target_file == self.root {
+            // Local to the root (or pointing back into it): already in place.
+            value["$ref"] = json!(format!("#{pointer}"));
+            return Ok(());
+        }
+
+        let key = (target_file.clone(), pointer.to_string());
+        if let Some(local) = self.hoisted.get(&key) {
+            value["$ref"] = json!(local);
+            return Ok(());
+        }
+
+        let mut target = self
+            .load(&target_file, file)?
+            .pointer(pointer)
+            .cloned()
+            .ok_or_else(|| {
+                OpenApiError::InvalidSpec(format!("{file}: $ref \"{reference}\" points at nothing"))
+            })?;
+
+        match self.section(kind) {
+            Some(section) => {
+                let name = self.unique_name(section, &name_hint(&target_file, pointer));
+                let local = format!(
+                    "#/{}/{}",
+                    section.join("/"),
+                    name.replace('~', "~0").replace('/', "~1")
+                );
+                // Registered before walking the target, so cycles through it
+                // resolve to the local name.
+                self.hoisted.insert(key, local.clone());
+                self.walk(&mut target, &target_file, kind)?;
+                self.components.push((section, name, target));
+                value["$ref"] = json!(local);
+            }
+            None => {
+                if self.inlining.contains(&key) {
+                    return Err(OpenApiError::InvalidSpec(format!(
+                        "{file}: circular $ref \"{reference}\""
+                    )));
+                }
+                self.inlining.push(key);
+                self.walk(&mut target, &target_file, kind)?;
+                self.inlining.pop();
+                *value = target;
+            }
+        }
+        Ok(())
+    }
+
+    /// `hint`, or `hint2`, `hint3`, ... if a section already uses it.
+    fn unique_name(&mut self, section: Section, hint: &str) -> String {
+        let mut name = hint.to_string();
+        let mut n = 2;
+        while self.taken.contains(&(section, name.clone())) {
+            name = format!("{hint}{n}");
+            n += 1;
+        }
+        self.taken.insert((section, name.clone()));
+        name
+    }
+}
+
+/// Component name for a target: the last pointer segment, or the file stem
+/// for a whole-file reference.
+fn name_hint(file: &str, pointer: &str) -> String {
+    let raw = match pointer.rsplit('/').next().filter(|s| !s.is_empty()) {
+        Some(segment) => segment.replace("~1", "/").replace("~0", "~"),
+        None => {
+            let name = file.rsplit('/').next().unwrap_or(file);
+            name.split_once('.')
+                .map_or(name, |(stem, _)| stem)
+                .to_string()
+        }
+    };
+    // Component names are limited to `[A-Za-z0-9._-]`.
+    raw.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// The value at `section` in `document`.
+fn lookup(document: &Value, section: Section) -> Option<&Value> {
+    section
+        .iter()
+        .try_fold(document, |value, segment| value.get(*segment))
+}
+
+/// Resolve `relative` against the directory of `file`.
+fn resolve(file: &str, relative: &str) -> String {
+    match file.rsplit_once('/') {
+        Some((dir, _)) => normalize(&format!("{dir}/{relative}")),
+        None => normalize(relative),
+    }
+}
+
+/// Collapse `.`/`..` segments and repeated slashes. A leading slash is kept,
+/// so absolute paths stay absolute.
+fn normalize(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    let normalized = segments.join("/");
+    if path.starts_with('/') {
+        format!("/{normalized}")
+    } else {
+        normalized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::openapi_types::*;
+
+    fn fixture() -> HashMap<&'static str, &'static str> {
+        HashMap::from([
+            ("openapi.yaml", include_str!("fixtures/bundle/openapi.yaml")),
+            (
+                "paths/pets.yaml",
+                include_str!("fixtures/bundle/paths/pets.yaml"),
+            ),
+            (
+                "schemas/pet.yaml",
+                include_str!("fixtures/bundle/schemas/pet.yaml"),
+            ),
+            ("common.yaml", include_str!("fixtures/bundle/common.yaml")),
+        ])
+    }
+
+    #[test]
+    fn external_refs_are_hoisted_into_components() {
+        let bundled = bundle("openapi.yaml", &fixture()).unwrap();
+        let schemas = &bundled["components"]["schemas"];
+        // Whole-file refs are named after the file, fragment refs after the
+        // last pointer segment.
+        assert_eq!(
+            schemas["pet"]["properties"]["owner"]["$ref"],
+            "#/components/schemas/Owner"
+        );
+        // `Error` already exists in the root, so the imported one is renamed.
+        assert_eq!(schemas["Error"]["properties"]["code"]["type"], "integer");
+        assert!(schemas["Error2"].is_object());
+        assert_eq!(
+            bundled["components"]["responses"]["NotFound"]["description"],
+            "Not found"
+        );
+        // Path items are inlined.
+        assert_eq!(bundled["paths"]["/pets"]["get"]["operationId"], "listPets");
+    }
+
+    #[test]
+    fn bundle_parses_with_resolved_schemas() {
+        let spec = parse_openapi_bundle("./openapi.yaml", &fixture()).unwrap();
+        let list = &spec.operations[0];
+        assert_eq!(list.operation_id.as_deref(), Some("listPets"));
+        let schema = list.responses[0].content[0].schema.as_ref().unwrap();
+        let items = schema.items.as_ref().unwrap();
+        assert_eq!(items.ref_name.as_deref(), Some("pet"));
+        assert_eq!(items.properties["name"].schema_type, SchemaType::String);
+        // Self-referencing schemas stay references rather than looping.
+        assert_eq!(items.properties["parent"].ref_name.as_deref(), Some("pet"));
+        assert!(list.responses.iter().any(|r| r.description == "Not found"));
+    }
+
+    #[test]
+    fn missing_files_and_pointers_are_errors() {
+        let mut documents = fixture();
+        documents.remove("common.yaml");
+        let err = bundle("openapi.yaml", &documents).unwrap_err();
+        assert!(err.to_string().contains("common.yaml"), "{err}");
+
+        let documents = HashMap::from([
+            (
+                "api.yaml",
+                "openapi: 3.0.0\ninfo: {title: T, version: '1'}\npaths:\n  /a:\n    $ref: 'other.yaml#/nope'\n",
+            ),
+            ("other.yaml", "{}"),
+        ]);
+        let err = bundle("api.yaml", &documents).unwrap_err();
+        assert!(err.to_string().contains("points at nothing"), "{err}");
+    }
+
+    #[test]
+    fn file_reader_is_called_once_per_file_and_its_errors_name_the_referrer() {
+        let files = HashMap::from([
+            (
+                "api/openapi.yaml",
+                "openapi: 3.0.3\ninfo: {title: Split, version: '1'}\npaths:\n  /pets:\n    $ref: paths/pets.yaml\n",
+            ),
+            (
+                "api/paths/pets.yaml",
+                "get:\n  responses:\n    '200':\n      description: OK\n      content:\n        application/json:\n          schema:\n            $ref: ../schemas/pet.yaml\n",
+            ),
+            (
+                "api/schemas/pet.yaml",
+                "type: object\nproperties:\n  parent:\n    $ref: ./pet.yaml\n",
+            ),
+        ]);
+
+        let mut reads = Vec::new();
+        let bundled = bundle_openapi_files("./api/openapi.yaml", &mut |path| {
+            reads.push(path.to_string());
+            files
+                .get(path)
+                .map(|s| s.to_string())
+                .ok_or_else(|| "not found".to_string())
+        })
+        .unwrap();
+        assert_eq!(
+            reads,
+            [
+                "api/openapi.yaml",
+                "api/paths/pets.yaml",
+                "api/schemas/pet.yaml"
+            ]
+        );
+        let schema = &bundled["paths"]["/pets"]["get"]["responses"]["200"]["content"]["application/json"]
+            ["schema"];
+        assert_eq!(schema["$ref"], "#/components/schemas/pet");
+        assert_eq!(
+            bundled["components"]["schemas"]["pet"]["properties"]["parent"]["$ref"],
+            "#/components/schemas/pet"
+        );
+
+        let err = bundle_openapi_files("api/openapi.yaml", &mut |path| match path {
+            "api/schemas/pet.yaml" => Err("not found".to_string()),
+            _ => Ok(files[path].to_string()),
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid spec: api/paths/pets.yaml: $ref to \"api/schemas/pet.yaml\": not found"
+        );
+    }
+
+    #[test]
+    fn paths_resolve_against_the_referencing_file() {
+        assert_eq!(
+            resolve("paths/pets.yaml", "../schemas/pet.yaml"),
+            "schemas/pet.yaml"
+        );
+        assert_eq!(resolve("openapi.yaml", "./common.yaml"), "common.yaml");
+        assert_eq!(normalize("./a//b/../c.yaml"), "a/c.yaml");
+        assert_eq!(resolve("/docs/api.yaml", "./pet.yaml"), "/docs/pet.yaml");
+    }
+}
@@ -0,0 +1,638 @@
+//! Differences between two versions of an OpenAPI spec, classified by
+//! whether they break existing clients.
+//!
+//! Operations are matched by method and path; path parameter names don't
+//! matter (`/pets/{id}` matches `/pets/{petId}`). Request and response bodies
+//! are compared through their JSON (else first) media type.
+
+use std::fmt;
+
+use super::content::parse_mdx;
+use super::openapi_types::{
+    ApiOperation, ApiParameter, HttpMethod, MediaTypeContent, OpenApiSpec, ParameterLocation,
+    SchemaDefinition, SchemaType,
+};
+use super::types::UpdateNode;
+
+/// Changes from one version of a spec to the next.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SpecDiff {
+    /// Changes in the new spec's operation order; removed operations last.
+    pub changes: Vec<ApiChange>,
+}
+
+/// One change to an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiChange {
+    /// HTTP method of the operation.
+    pub method: HttpMethod,
+    /// Path of the operation, as written in the new spec (the old one for
+    /// removed operations).
+    pub path: String,
+    /// What changed.
+    pub kind: ApiChangeKind,
+}
+
+/// What changed about an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiChangeKind {
+    /// The operation is new.
+    OperationAdded,
+    /// The operation is gone.
+    OperationRemoved,
+    /// A parameter or field is new.
+    Added {
+        target: ChangeTarget,
+        /// Whether clients must send it (always `false` for responses).
+        required: bool,
+    },
+    /// A parameter or field is gone.
+    Removed { target: ChangeTarget },
+    /// An optional parameter or request field became required.
+    NowRequired { target: ChangeTarget },
+    /// The type of a parameter or field changed.
+    TypeChanged {
+        target: ChangeTarget,
+        from: String,
+        to: String,
+    },
+}
+
+/// The parameter or body field a change applies to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeTarget {
+    /// An operation parameter.
+    Parameter {
+        name: String,
+        location: ParameterLocation,
+    },
+    /// A request body field, as a dotted path (`owner.name`, `tags[]`); empty
+    /// for the body itself.
+    RequestField { field: String },
+    /// A response body field of the response with `status`.
+    ResponseField { status: String, field: String },
+    /// The response with `status` as a whole.
+    Response { status: String },
+}
+
+impl fmt::Display for ChangeTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parameter { name, location } => {
+                write!(f, "{} parameter `{name}`", location.as_str())
+            }
+            Self::RequestField { field } if field.is_empty() => write!(f, "request body"),
+            Self::RequestField { field } => write!(f, "request field `{field}`"),
+            Self::ResponseField { status, field } if field.is_empty() => {
+                write!(f, "{status} response body")
+            }
+            Self::ResponseField { status, field } => {
+                write!(f, "{status} response field `{field}`")
+            }
+            Self::Response { status } => write!(f, "{status} response"),
+        }
+    }
+}
+
+impl ApiChange {
+    /// Whether clients written against the old spec may fail against the
+    /// new one: removed operations, responses or response fields, newly
+    /// required parameters, request bodies or request fields, and type
+    /// changes.
+    pub fn is_breaking(&self) -> bool {
+        match &self.kind {
+            ApiChangeKind::OperationAdded => false,
+            ApiChangeKind::OperationRemoved => true,
+            ApiChangeKind::Added { required, .. } => *required,
+            ApiChangeKind::Removed { target } => {
+                matches!(
+                    target,
+                    ChangeTarget::ResponseField { .. } | ChangeTarget::Response { .. }
+                )
+            }
+            ApiChangeKind::NowRequired { .. } | ApiChangeKind::TypeChanged { .. } => true,
+        }
+    }
+
+    /// One-line description, with names in backticks.
+    pub fn description(&self) -> String {
+        match &self.kind {
+            ApiChangeKind::OperationAdded => "Added endpoint".to_string(),
+            ApiChangeKind::OperationRemoved => "Removed endpoint".to_string(),
+            ApiChangeKind::Added { target, required } if *required => {
+                format!("Added required {target}")
+            }
+            ApiChangeKind::Added { target, .. } => format!("Added {target}"),
+            ApiChangeKind::Removed { target } => format!("Removed {target}"),
+            ApiChangeKind::NowRequired { target } => format!("Made {target} required"),
+            ApiChangeKind::TypeChanged { target, from, to } => {
+                format!("Changed type of {target} from `{from}` to `{to}`")
+            }
+        }
+    }
+}
+
+impl SpecDiff {
+    /// Whether the specs describe the same operations.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Whether any change breaks existing clients.
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes.iter().any(ApiChange::is_breaking)
+    }
+
+    /// Changelog in Markdown: a "Breaking changes" list, then the others.
+    pub fn to_markdown(&self) -> String {
+        if self.is_empty() {
+            return "No changes to the API.\n".to_string();
+        }
+        let mut out = String::new();
+        for (heading, breaking) in [("Breaking changes", true), ("Other changes", false)] {
+            let changes: Vec<_> = self
+                .changes
+                .iter()
+                .filter(|c| c.is_breaking() == breaking)
+                .collect();
+            if changes.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("**{heading}**\n\n"));
+            for change in changes {
+                out.push_str(&format!(
+                    "- **{}** `{}` — {}\n",
+                    change.method.as_str(),
+                    change.path,
+                    change.description()
+                ));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The changelog as an [`UpdateNode`], as written with `<Update>` in MDX.
+    pub fn to_update(&self, label: &str, description: &str) -> UpdateNode {
+        UpdateNode {
+            label: label.to_string(),
+            description: description.to_string(),
+            content: parse_mdx(&self.to_markdown()),
+        }
+    }
+}
+
+/// Compare two versions of a spec.
+pub fn diff_specs(old: &OpenApiSpec, new: &OpenApiSpec) -> SpecDiff {
+    let mut changes = Vec::new();
+
+    for op in &new.operations {
+        let mut push = |kind| {
+            changes.push(ApiChange {
+                method: op.method,
+                path: op.path.clone(),
+                kind,
+            })
+        };
+        match old.operations.iter().find(|o| same_operation(o, op)) {
+            Some(previous) => diff_operation(previous, op, &mut push),
+            None => push(ApiChangeKind::OperationAdded),
+        }
+    }
+
+    for op in &old.operations {
+        if !new.operations.iter().any(|o| same_operation(o, op)) {
+            changes.push(ApiChange {
+                method: op.method,
+                path: op.path.clone(),
+                kind: ApiChangeKind::OperationRemoved,
+            });
+        }
+    }
+
+    SpecDiff { changes }
+}
+
+/// Same method and path, ignoring path parameter names.
+fn same_operation(a: &ApiOperation, b: &ApiOperation) -> bool {
+    a.method == b.method && path_shape(&a.path) == path_shape(&b.path)
+}
+
+fn path_shape(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if segment.starts_with('{') {
+                "{}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn diff_operation(old: &ApiOperation, new: &ApiOperation, push: &mut impl FnMut(ApiChangeKind)) {
+    diff_parameters(&old.parameters, &new.parameters, push);
+
+    let body = || ChangeTarget::RequestField {
+        field: String::new(),
+    };
+    match (&old.request_body, &new.request_body) {
+        (None, Some(added)) => push(ApiChangeKind::Added {
+            target: body(),
+            required: added.required,
+        }),
+        (Some(_), None) => push(ApiChangeKind::Removed { target: body() }),
+        (Some(previous), Some(current)) => {
+            if current.required && !previous.required {
+                push(ApiChangeKind::NowRequired { target: body() });
+            }
+            if let (Some(old_body), Some(new_body)) = (
+                body_schema(&previous.content),
+                body_schema(&current.content),
+            ) {
+                diff_schema(old_body, new_body, "", &Side::Request, push);
+            }
+        }
+        (None, None) => {}
+    }
+
+    for response in &new.responses {
+        let Some(previous) = old
+            .responses
+            .iter()
+            .find(|r| r.status_code == response.status_code)
+        else {
+            continue;
+        };
+        if let (Some(old_body), Some(new_body)) = (
+            body_schema(&previous.content),
+            body_schema(&response.content),
+        ) {
+            let side = Side::Response(&response.status_code);
+            diff_schema(old_body, new_body, "", &side, push);
+        }
+    }
+
+    for response in &old.responses {
+        if !new
+            .responses
+            .iter()
+            .any(|r| r.status_code == response.status_code)
+        {
+            push(ApiChangeKind::Removed {
+                target: ChangeTarget::Response {
+                    status: response.status_code.clone(),
+                },
+            });
+        }
+    }
+}
+
+fn diff_parameters(
+    old: &[ApiParameter],
+    new: &[ApiParameter],
+    push: &mut impl FnMut(ApiChangeKind),
+) {
+    let target = |p: &ApiParameter| ChangeTarget::Parameter {
+        name: p.name.clone(),
+        location: p.location,
+    };
+    for param in new {
+        let Some(previous) = old
+            .iter()
+            .find(|p| p.name == param.name && p.location == param.location)
+        else {
+            push(ApiChangeKind::Added {
+                target: target(param),
+                required: param.required,
+            });
+            continue;
+        };
+        if param.required && !previous.required {
+            push(ApiChangeKind::NowRequired {
+                target: target(param),
+            });
+        }
+        if let (Some(from), Some(to)) = (&previous.schema, &param.schema) {
+            let (from, to) = (type_label(from), type_label(to));
+            if from != to {
+                push(ApiChangeKind::TypeChanged {
+                    target: target(param),
+                    from,
+                    to,
+                });
+            }
+        }
+    }
+    for param in old {
+        if !new
+            .iter()
+            .any(|p| p.name == param.name && p.location == param.location)
+        {
+            push(ApiChangeKind::Removed {
+                target: target(param),
+            });
+        }
+    }
+}
+
+/// Schema of the JSON media type, else of the first one.
+fn body_schema(content: &[MediaTypeContent]) -> Option<&SchemaDefinition> {
+    content
+        .iter()
+        .find(|c| c.media_type.contains("json"))
+        .or(content.first())?
+        .schema
+        .as_ref()
+}
+
+/// Which body a schema belongs to.
+enum Side<'a> {
+    Request,
+    Response(&'a str),
+}
+
+impl Side<'_> {
+    fn target(&self, field: &str) -> ChangeTarget {
+        match self {
+            Side::Request => ChangeTarget::RequestField {
+                field: field.to_string(),
+            },
+            Side::Response(status) => ChangeTarget::ResponseField {
+                status: status.to_string(),
+                field: field.to_string(),
+            },
+        }
+    }
+}
+
+fn diff_schema(
+    old: &SchemaDefinition,
+    new: &SchemaDefinition,
+    field: &str,
+    side: &Side,
+    push: &mut impl FnMut(ApiChangeKind),
+) {
+    let (from, to) = (type_label(old), type_label(new));
+    if from != to {
+        push(ApiChangeKind::TypeChanged {
+            target: side.target(field),
+            from,
+            to,
+        });
+        return;
+    }
+
+    if let (Some(old_items), Some(new_items)) = (&old.items, &new.items) {
+        diff_schema(old_items, new_items, &format!("{field}[]"), side, push);
+        return;
+    }
+
+    let request = matches!(side, Side::Request);
+    let old_props = old.flattened_properties();
+    let new_props = new.flattened_properties();
+    let join = |name: &str| match field {
+        "" => name.to_string(),
+        parent => format!("{parent}.{name}"),
+    };
+
+    for prop in &new_props {
+        let path = join(&prop.name);
+        match old_props.iter().find(|p| p.name == prop.name) {
+            Some(previous) => {
+                if request && prop.required && !previous.required {
+                    push(ApiChangeKind::NowRequired {
+                        target: side.target(&path),
+                    });
+                }
+                diff_schema(&previous.schema, &prop.schema, &path, side, push);
+            }
+            None => push(ApiChangeKind::Added {
+                target: side.target(&path),
+                required: request && prop.required,
+            }),
+        }
+    }
+    for prop in &old_props {
+        if !new_props.iter().any(|p| p.name == prop.name) {
+            push(ApiChangeKind::Removed {
+                target: side.target(&join(&prop.name)),
+            });
+        }
+    }
+}
+
+/// Structural type of a schema, ignoring `$ref` names so renaming a schema
+/// isn't reported: `integer (int64)`, `array<string>`.
+fn type_label(schema: &SchemaDefinition) -> String {
+    match (&schema.schema_type, &schema.items) {
+        (SchemaType::Array, Some(items)) => format!("array<{}>", type_label(items)),
+        (other, _) => match &schema.format {
+            Some(format) => format!("{} ({format})", other.as_str()),
+            None => other.as_str().to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::openapi_parser::parse_openapi;
+
+    const OLD: &str = r##"
+openapi: 3.0.3
+info: {title: Pets, version: '1'}
+paths:
+  /pets:
+    get:
+      parameters:
+        - {name: limit, in: query, schema: {type: integer}}
+        - {name: cursor, in: query, schema: {type: string}}
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  type: object
+                  properties:
+                    id: {type: integer}
+                    name: {type: string}
+                    nickname: {type: string}
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              properties:
+                name: {type: string}
+                age: {type: string}
+      responses:
+        '201': {description: Created}
+  /pets/{id}:
+    delete:
+      responses:
+        '204': {description: Deleted}
+"##;
+
+    const NEW: &str = r##"
+openapi: 3.0.3
+info: {title: Pets, version: '2'}
+paths:
+  /pets:
+    get:
+      parameters:
+        - {name: limit, in: query, required: true, schema: {type: integer}}
+        - {name: region, in: header, required: true, schema: {type: string}}
+        - {name: sort, in: query, schema: {type: string}}
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  type: object
+                  properties:
+                    id: {type: string}
+                    name: {type: string}
+                    tag: {type: string}
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              required: [name, owner]
+              properties:
+                name: {type: string}
+                age: {type: integer}
+                owner: {type: string}
+      responses:
+        '201': {description: Created}
+  /pets/{petId}/photos:
+    get:
+      responses:
+        '200': {description: OK}
+"##;
+
+    fn diff() -> SpecDiff {
+        diff_specs(&parse_openapi(OLD).unwrap(), &parse_openapi(NEW).unwrap())
+    }
+
+    fn lines(changes: &[&ApiChange]) -> Vec<String> {
+        changes
+            .iter()
+            .map(|c| format!("{} {}: {}", c.method.as_str(), c.path, c.description()))
+            .collect()
+    }
+
+    #[test]
+    fn classifies_breaking_changes() {
+        let diff = diff();
+        let breaking: Vec<_> = diff.changes.iter().filter(|c| c.is_breaking()).collect();
+        assert_eq!(
+            lines(&breaking),
+            [
+                "GET /pets: Made query parameter `limit` required",
+                "GET /pets: Added required header parameter `region`",
+                "GET /pets: Changed type of 200 response field `[].id` from `integer` to `string`",
+                "GET /pets: Removed 200 response field `[].nickname`",
+                "POST /pets: Changed type of request field `age` from `string` to `integer`",
+                "POST /pets: Made request field `name` required",
+                "POST /pets: Added required request field `owner`",
+                "DELETE /pets/{id}: Removed endpoint",
+            ]
+        );
+        assert!(diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn classifies_non_breaking_changes() {
+        let diff = diff();
+        let other: Vec<_> = diff.changes.iter().filter(|c| !c.is_breaking()).collect();
+        assert_eq!(
+            lines(&other),
+            [
+                "GET /pets: Added query parameter `sort`",
+                "GET /pets: Removed query parameter `cursor`",
+                "GET /pets: Added 200 response field `[].tag`",
+                "GET /pets/{petId}/photos: Added endpoint",
+            ]
+        );
+    }
+
+    #[test]
+    fn request_bodies_and_response_statuses_are_compared() {
+        let post_body = "      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              properties:
+                name: {type: string}
+                age: {type: string}
+";
+        let old = OLD.replace(
+            "'204': {description: Deleted}",
+            "'204': {description: Deleted}\n        '404': {description: Not found}",
+        );
+        let new = OLD.replace(post_body, "").replace(
+            "    delete:\n",
+            "    delete:\n      requestBody:\n        required: true\n        content:\n          application/json:\n            schema: {type: object}\n",
+        );
+        let diff = diff_specs(&parse_openapi(&old).unwrap(), &parse_openapi(&new).unwrap());
+        let changes: Vec<_> = diff.changes.iter().collect();
+        assert_eq!(
+            lines(&changes),
+            [
+                "POST /pets: Removed request body",
+                "DELETE /pets/{id}: Added required request body",
+                "DELETE /pets/{id}: Removed 404 response",
+            ]
+        );
+        let breaking: Vec<_> = diff.changes.iter().map(ApiChange::is_breaking).collect();
+        assert_eq!(breaking, [false, true, true]);
+
+        let optional = new.replace("        required: true\n", "");
+        let diff = diff_specs(
+            &parse_openapi(&new).unwrap(),
+            &parse_openapi(&optional).unwrap(),
+        );
+        assert!(diff.is_empty());
+        let diff = diff_specs(
+            &parse_openapi(&optional).unwrap(),
+            &parse_openapi(&new).unwrap(),
+        );
+        assert_eq!(
+            lines(&diff.changes.iter().collect::<Vec<_>>()),
+            ["DELETE /pets/{id}: Made request body required"]
+        );
+    }
+
+    #[test]
+    fn path_parameter_renames_and_identical_specs_are_not_changes() {
+        let old = parse_openapi(OLD).unwrap();
+        assert!(diff_specs(&old, &old).is_empty());
+
+        let renamed = parse_openapi(&OLD.replace("/pets/{id}", "/pets/{petId}")).unwrap();
+        assert!(diff_specs(&old, &renamed).is_empty());
+    }
+
+    #[test]
+    fn changelog_lists_breaking_changes_first() {
+        let update = diff().to_update("v2", "October 2026");
+        assert_eq!(update.label, "v2");
+        let markdown = diff().to_markdown();
+        let breaking = markdown.find("**Breaking changes**").unwrap();
+        let other = markdown.find("**Other changes**").unwrap();
+        assert!(breaking < other);
+        assert!(markdown.contains("- **DELETE** `/pets/{id}` — Removed endpoint\n"));
+        assert!(!update.content.is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+//! Navigation grouping of operations: by tag, untagged ones by their first
+//! path segment, and tags nested under `x-tagGroups` headings.
+
+use super::openapi_types::{ApiOperation, ApiTag, OpenApiSpec};
+
+/// Group name of untagged operations whose path has no usable first segment.
+const FALLBACK_GROUP: &str = "Other";
+
+/// Tags under one navigation heading, each with its operations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagSection<'a> {
+    /// The `x-tagGroups` heading, or `None` for tags outside any group (and
+    /// for every tag when the spec declares no groups).
+    pub heading: Option<&'a str>,
+    /// Tags in display order, each with its operations in spec order.
+    pub tags: Vec<(ApiTag, Vec<&'a ApiOperation>)>,
+}
+
+impl ApiOperation {
+    /// Navigation groups of the operation: its tags, or for an untagged
+    /// operation the first segment of its path (`/pets/{id}` → `pets`).
+    pub fn group_names(&self) -> Vec<&str> {
+        if !self.tags.is_empty() {
+            return self.tags.iter().map(String::as_str).collect();
+        }
+        let segment = self
+            .path
+            .trim_start_matches('/')
+            .split('/')
+            .next()
+            .filter(|s| !s.is_empty() && !s.starts_with('{'));
+        vec![segment.unwrap_or(FALLBACK_GROUP)]
+    }
+}
+
+impl OpenApiSpec {
+    /// Operations grouped by tag: declared tags in spec order, then
+    /// undeclared tags and path-segment groups in order of appearance.
+    /// Empty tags are left out.
+    pub fn operation_groups(&self) -> Vec<(ApiTag, Vec<&ApiOperation>)> {
+        let mut groups: Vec<(ApiTag, Vec<&ApiOperation>)> = self
+            .tags
+            .iter()
+            .map(|tag| (tag.clone(), Vec::new()))
+            .collect();
+
+        for op in &self.operations {
+            for name in op.group_names() {
+                match groups.iter_mut().find(|(tag, _)| tag.name == name) {
+                    Some((_, ops)) => ops.push(op),
+                    None => groups.push((
+                        ApiTag {
+                            name: name.to_string(),
+                            description: None,
+                        },
+                        vec![op],
+                    )),
+                }
+            }
+        }
+
+        groups.retain(|(_, ops)| !ops.is_empty());
+        groups
+    }
+
+    /// [`Self::operation_groups`] nested under the spec's `x-tagGroups`
+    /// headings, in their order. Tags no heading lists follow in a last
+    /// section without a heading, so no operation goes missing.
+    pub fn tag_sections(&self) -> Vec<TagSection<'_>> {
+        let mut groups = self.operation_groups();
+        let mut sections = Vec::new();
+
+        for group in &self.tag_groups {
+            let tags: Vec<_> = group
+                .tags
+                .iter()
+                .filter_map(|name| {
+                    let index = groups.iter().position(|(tag, _)| tag.name == *name)?;
+                    Some(groups.remove(index))
+                })
+                .collect();
+            if !tags.is_empty() {
+                sections.push(TagSection {
+                    heading: Some(&group.name),
+                    tags,
+                });
+            }
+        }
+
+        if !groups.is_empty() {
+            sections.push(TagSection {
+                heading: None,
+                tags: groups,
+            });
+        }
+        sections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::openapi_parser::parse_openapi;
+    use crate::parser::{ApiOperation, ApiTag};
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: {title: Store, version: '1'}
+x-tagGroups:
+  - name: Catalog
+    tags: [pets, toys]
+  - name: Empty
+    tags: [missing]
+tags:
+  - name: pets
+  - name: toys
+  - name: store
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      tags: [pets]
+      responses: {'200': {description: OK}}
+  /orders:
+    get:
+      operationId: listOrders
+      tags: [store]
+      responses: {'200': {description: OK}}
+  /users/{id}:
+    get:
+      operationId: getUser
+      responses: {'200': {description: OK}}
+  /{tenant}/status:
+    get:
+      operationId: status
+      responses: {'200': {description: OK}}
+  /admin:
+    x-internal: true
+    get:
+      operationId: adminOnly
+      responses: {'200': {description: OK}}
+  /debug:
+    get:
+      operationId: debug
+      tags: [pets]
+      x-hidden: true
+      responses: {'200': {description: OK}}
+    post:
+      operationId: debugDump
+      tags: [experimental]
+      responses: {'200': {description: OK}}
+"##;
+
+    fn names(groups: &[(ApiTag, Vec<&ApiOperation>)]) -> Vec<String> {
+        groups.iter().map(|(tag, _)| tag.name.clone()).collect()
+    }
+
+    #[test]
+    fn internal_and_hidden_operations_are_dropped() {
+        let spec = parse_openapi(SPEC).unwrap();
+        let ids: Vec<_> = spec
+            .operations
+            .iter()
+            .filter_map(|op| op.operation_id.as_deref())
+            .collect();
+        assert_eq!(
+            ids,
+            ["listPets", "listOrders", "getUser", "status", "debugDump"]
+        );
+    }
+
+    #[test]
+    fn untagged_operations_group_by_first_path_segment() {
+        let spec = parse_openapi(SPEC).unwrap();
+        let groups = spec.operation_groups();
+        assert_eq!(
+            names(&groups),
+            ["pets", "store", "users", "Other", "experimental"]
+        );
+        let users = &groups[2].1;
+        assert_eq!(users[0].operation_id.as_deref(), Some("getUser"));
+    }
+
+    #[test]
+    fn tag_groups_nest_tags_under_headings() {
+        let spec = parse_openapi(SPEC).unwrap();
+        assert_eq!(spec.tag_groups[0].name, "Catalog");
+        assert_eq!(spec.tag_groups[0].tags, ["pets", "toys"]);
+
+        let sections = spec.tag_sections();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].heading, Some("Catalog"));
+        assert_eq!(names(&sections[0].tags), ["pets"]);
+        assert_eq!(sections[1].heading, None);
+        assert_eq!(
+            names(&sections[1].tags),
+            ["store", "users", "Other", "experimental"]
+        );
+    }
+
+    #[test]
+    fn specs_without_tag_groups_have_one_section() {
+        let spec = parse_openapi(&SPEC.replace("x-tagGroups:", "x-unused:")).unwrap();
+        assert!(spec.tag_groups.is_empty());
+        let sections = spec.tag_sections();
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].heading, None);
+    }
+}
@@ -0,0 +1,140 @@
+//! Lookups behind the schema ("model") reference pages: which named schema a
+//! type links to, and which operations use a schema.
+//!
+//! The parser inlines `$ref`s but keeps their `ref_name`, so a schema is used
+//! wherever a node with its name appears in an operation's trees.
+
+use super::openapi_types::{ApiOperation, OpenApiSpec, SchemaDefinition};
+
+impl SchemaDefinition {
+    /// Name of the component schema this type links to: its own `ref_name`,
+    /// or that of its array items (`array<Pet>` links to `Pet`).
+    pub fn linked_ref(&self) -> Option<&str> {
+        match &self.ref_name {
+            Some(name) => Some(name),
+            None => self.items.as_deref()?.linked_ref(),
+        }
+    }
+
+    /// Whether the schema is, or anywhere contains, the component schema
+    /// `name`.
+    pub fn references(&self, name: &str) -> bool {
+        self.ref_name.as_deref() == Some(name)
+            || self.items.as_deref().is_some_and(|s| s.references(name))
+            || self.properties.values().any(|s| s.references(name))
+            || self
+                .additional_properties
+                .as_deref()
+                .is_some_and(|s| s.references(name))
+            || self
+                .one_of
+                .iter()
+                .chain(&self.any_of)
+                .chain(&self.all_of)
+                .any(|s| s.references(name))
+            || self.not.as_deref().is_some_and(|s| s.references(name))
+    }
+}
+
+impl ApiOperation {
+    /// Whether a parameter, the request body, or a response (body or
+    /// header) uses the component schema `name`.
+    pub fn uses_schema(&self, name: &str) -> bool {
+        let parameters = self.parameters.iter().filter_map(|p| p.schema.as_ref());
+        let bodies = self
+            .request_body
+            .iter()
+            .flat_map(|body| &body.content)
+            .chain(self.responses.iter().flat_map(|r| &r.content))
+            .filter_map(|content| content.schema.as_ref());
+        let headers = self
+            .responses
+            .iter()
+            .flat_map(|r| &r.headers)
+            .filter_map(|h| h.schema.as_ref());
+        parameters
+            .chain(bodies)
+            .chain(headers)
+            .any(|schema| schema.references(name))
+    }
+}
+
+impl OpenApiSpec {
+    /// Operations that use the component schema `name`, in spec order.
+    pub fn schema_users(&self, name: &str) -> Vec<&ApiOperation> {
+        self.operations
+            .iter()
+            .filter(|op| op.uses_schema(name))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::openapi_parser::parse_openapi;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: {title: Pets, version: '1'}
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items: {$ref: '#/components/schemas/Pet'}
+  /owners:
+    post:
+      operationId: createOwner
+      requestBody:
+        content:
+          application/json:
+            schema: {$ref: '#/components/schemas/Owner'}
+      responses:
+        '201': {description: Created}
+  /health:
+    get:
+      operationId: health
+      responses:
+        '200': {description: OK}
+components:
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name: {type: string}
+    Owner:
+      type: object
+      properties:
+        pets:
+          type: array
+          items: {$ref: '#/components/schemas/Pet'}
+"##;
+
+    fn ids(ops: Vec<&crate::parser::ApiOperation>) -> Vec<&str> {
+        ops.iter()
+            .filter_map(|op| op.operation_id.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn schema_users_include_nested_references() {
+        let spec = parse_openapi(SPEC).unwrap();
+        assert_eq!(ids(spec.schema_users("Pet")), ["listPets", "createOwner"]);
+        assert_eq!(ids(spec.schema_users("Owner")), ["createOwner"]);
+        assert!(spec.schema_users("Missing").is_empty());
+    }
+
+    #[test]
+    fn array_types_link_to_their_item_schema() {
+        let spec = parse_openapi(SPEC).unwrap();
+        let pets = &spec.schemas["Owner"].properties["pets"];
+        assert_eq!(pets.linked_ref(), Some("Pet"));
+        assert_eq!(spec.schemas["Pet"].properties["name"].linked_ref(), None);
+    }
+}
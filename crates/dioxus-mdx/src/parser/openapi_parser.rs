@@ -1,15 +1,21 @@
 //! OpenAPI specification parser.
 //!
-//! Parses OpenAPI 3.0/3.1 YAML or JSON specs into internal types for rendering.
+//! Parses OpenAPI 3.0/3.1 and Swagger 2.0 YAML or JSON specs into internal types for rendering.
 
 use std::collections::BTreeMap;
 
 use openapiv3::{
-    OpenAPI, Operation, Parameter, ParameterSchemaOrContent, PathItem, ReferenceOr, RequestBody,
-    Response, Schema, SchemaKind, StatusCode, Type, VariantOrUnknownOrEmpty,
+    APIKeyLocation, OpenAPI, Operation, Parameter, ParameterSchemaOrContent, PathItem, ReferenceOr,
+    RequestBody, Response, Schema, SchemaKind, StatusCode, Type, VariantOrUnknownOrEmpty,
 };
 
+use serde_json::Value;
+
+use super::openapi_31::{downgrade, downgrade_schema, is_openapi_31};
+use super::openapi_playground::playground_enabled;
+use super::openapi_samples::SampleLanguage;
 use super::openapi_types::*;
+use super::swagger::{convert, is_swagger_2};
 
 /// Error type for OpenAPI parsing.
 #[derive(Debug, Clone)]
@@ -31,8 +37,18 @@ impl std::fmt::Display for OpenApiError {
 
 impl std::error::Error for OpenApiError {}
 
-/// Parse an OpenAPI specification from YAML or JSON content.
+/// Parse an OpenAPI 3.0/3.1 or Swagger 2.0 specification from YAML or JSON
+/// content.
 pub fn parse_openapi(content: &str) -> Result<OpenApiSpec, OpenApiError> {
+    // Swagger 2.0 and OpenAPI 3.1 documents are rewritten into 3.0 form first
+    // (see `swagger` and `openapi_31`).
+    if let Ok(document) = serde_yaml::from_str::<serde_yaml::Value>(content)
+        && let Ok(document) = serde_json::to_value(document)
+        && (is_swagger_2(&document) || is_openapi_31(&document))
+    {
+        return from_document(document);
+    }
+
     // Try YAML first, then JSON
     let spec: OpenAPI = match serde_yaml::from_str(content) {
         Ok(s) => s,
@@ -54,6 +70,22 @@ pub fn parse_openapi(content: &str) -> Result<OpenApiSpec, OpenApiError> {
     Ok(transform_spec(&spec))
 }
 
+/// Parse an already-loaded YAML/JSON document of any supported version.
+pub(super) fn from_document(document: Value) -> Result<OpenApiSpec, OpenApiError> {
+    let (version, document) = if is_swagger_2(&document) {
+        ("Swagger 2.0", convert(document)?)
+    } else if is_openapi_31(&document) {
+        let mut document = document;
+        downgrade(&mut document);
+        ("OpenAPI 3.1", document)
+    } else {
+        ("OpenAPI", document)
+    };
+    let spec: OpenAPI = serde_json::from_value(document)
+        .map_err(|e| OpenApiError::ParseError(format!("{version}: {e}")))?;
+    Ok(transform_spec(&spec))
+}
+
 /// Transform an openapiv3 spec into our internal representation.
 fn transform_spec(spec: &OpenAPI) -> OpenApiSpec {
     let info = ApiInfo {
@@ -68,6 +100,17 @@ fn transform_spec(spec: &OpenAPI) -> OpenApiSpec {
         .map(|s| ApiServer {
             url: s.url.clone(),
             description: s.description.clone(),
+            variables: s
+                .variables
+                .iter()
+                .flatten()
+                .map(|(name, var)| ServerVariable {
+                    name: name.clone(),
+                    default: var.default.clone(),
+                    enum_values: var.enumeration.clone(),
+                    description: var.description.clone(),
+                })
+                .collect(),
         })
         .collect();
 
@@ -80,15 +123,46 @@ fn transform_spec(spec: &OpenAPI) -> OpenApiSpec {
         })
         .collect();
 
+    let tag_groups = transform_tag_groups(spec.extensions.get("x-tagGroups"));
+
+    let security_schemes = transform_security_schemes(spec);
+    let security = transform_security(spec.security.as_deref(), &security_schemes);
+
     // Collect all operations from paths
     let mut operations = Vec::new();
     for (path, item) in &spec.paths.paths {
         if let ReferenceOr::Item(path_item) = item {
-            extract_operations(path, path_item, spec, &mut operations);
+            extract_operations(
+                path,
+                path_item,
+                spec,
+                &security_schemes,
+                spec.security.as_deref(),
+                &mut operations,
+            );
         }
     }
 
-    // Extract schemas
+    let webhooks = transform_webhooks(spec, &operations, &security_schemes);
+
+    let schemas = transform_component_schemas(spec);
+
+    OpenApiSpec {
+        info,
+        servers,
+        operations,
+        tags,
+        tag_groups,
+        schemas,
+        security_schemes,
+        security,
+        playground: playground_enabled(spec.extensions.get("x-playground")),
+        webhooks,
+    }
+}
+
+/// Transform every schema under `components.schemas`.
+fn transform_component_schemas(spec: &OpenAPI) -> BTreeMap<String, SchemaDefinition> {
     let mut schemas = BTreeMap::new();
     if let Some(components) = &spec.components {
         for (name, schema_ref) in &components.schemas {
@@ -100,14 +174,153 @@ fn transform_spec(spec: &OpenAPI) -> OpenApiSpec {
             }
         }
     }
+    schemas
+}
 
-    OpenApiSpec {
-        info,
-        servers,
-        operations,
-        tags,
-        schemas,
+/// JSON Schemas that live outside an OpenAPI document, such as AsyncAPI
+/// message payloads, with the component schemas their `$ref`s point into.
+///
+/// The schemas are rewritten like OpenAPI 3.1 ones (see `openapi_31`), so
+/// draft-07 and 2020-12 keywords map the same way.
+pub(super) struct JsonSchemas {
+    /// A document holding nothing but the component schemas.
+    spec: OpenAPI,
+}
+
+impl JsonSchemas {
+    /// Load `components`, a map of schema names to schemas.
+    pub(super) fn new(components: Value) -> Result<Self, OpenApiError> {
+        let components = match components {
+            Value::Object(map) => Value::Object(map),
+            _ => Value::Object(Default::default()),
+        };
+        let mut document = serde_json::json!({
+            "openapi": "3.1.0",
+            "info": { "title": "", "version": "" },
+            "components": { "schemas": components },
+        });
+        downgrade(&mut document);
+        let spec = serde_json::from_value(document)
+            .map_err(|e| OpenApiError::InvalidSpec(format!("components.schemas: {e}")))?;
+        Ok(Self { spec })
+    }
+
+    /// The component schemas, by name.
+    pub(super) fn components(&self) -> BTreeMap<String, SchemaDefinition> {
+        transform_component_schemas(&self.spec)
     }
+
+    /// Transform one schema, or `None` if it isn't a valid schema.
+    pub(super) fn transform(&self, schema: &Value) -> Option<SchemaDefinition> {
+        let mut schema = schema.clone();
+        downgrade_schema(&mut schema);
+        let schema: ReferenceOr<Schema> = serde_json::from_value(schema).ok()?;
+        Some(resolve_and_transform(&schema, &self.spec, &mut Vec::new()))
+    }
+}
+
+/// Read the root `x-tagGroups` extension: a list of `{name, tags}` headings.
+/// Malformed entries are skipped.
+fn transform_tag_groups(value: Option<&Value>) -> Vec<ApiTagGroup> {
+    let Some(Value::Array(groups)) = value else {
+        return Vec::new();
+    };
+    groups
+        .iter()
+        .filter_map(|group| {
+            let name = group.get("name")?.as_str()?;
+            let tags = group
+                .get("tags")?
+                .as_array()?
+                .iter()
+                .filter_map(|tag| Some(tag.as_str()?.to_string()))
+                .collect();
+            Some(ApiTagGroup {
+                name: name.to_string(),
+                tags,
+            })
+        })
+        .collect()
+}
+
+/// Whether `x-internal: true` or `x-hidden: true` keeps an operation or path
+/// item off the public pages.
+fn is_internal<'a>(mut extensions: impl Iterator<Item = (&'a String, &'a Value)>) -> bool {
+    extensions.any(|(key, value)| {
+        matches!(key.as_str(), "x-internal" | "x-hidden") && value.as_bool() == Some(true)
+    })
+}
+
+/// Collect `x-webhooks` (3.1 `webhooks` are moved there by `downgrade`) and
+/// every operation's callbacks.
+///
+/// These are requests the API sends, so the spec's default security doesn't
+/// apply to them; only their own `security` does.
+fn transform_webhooks(
+    spec: &OpenAPI,
+    operations: &[ApiOperation],
+    security_schemes: &BTreeMap<String, SecurityScheme>,
+) -> Vec<ApiWebhook> {
+    let mut webhooks = Vec::new();
+
+    if let Some(Value::Object(declared)) = spec.extensions.get("x-webhooks") {
+        for (name, item) in declared {
+            // Path item `$ref`s are left to the bundler; skip what's unresolved.
+            let Ok(ReferenceOr::Item(item)) =
+                serde_json::from_value::<ReferenceOr<PathItem>>(item.clone())
+            else {
+                continue;
+            };
+            let mut requests = Vec::new();
+            extract_operations(name, &item, spec, security_schemes, None, &mut requests);
+            if !requests.is_empty() {
+                webhooks.push(ApiWebhook {
+                    name: name.clone(),
+                    kind: WebhookKind::Webhook,
+                    operations: requests,
+                });
+            }
+        }
+    }
+
+    // Callbacks, in the same order as the operations that register them.
+    for (path, item) in &spec.paths.paths {
+        let ReferenceOr::Item(item) = item else {
+            continue;
+        };
+        for (method, op) in path_operations(item) {
+            let Some(parent) = operations
+                .iter()
+                .find(|o| o.method == method && o.path == *path)
+            else {
+                continue;
+            };
+            for (name, callback) in &op.callbacks {
+                let mut requests = Vec::new();
+                for (expression, item) in callback {
+                    extract_operations(
+                        expression,
+                        item,
+                        spec,
+                        security_schemes,
+                        None,
+                        &mut requests,
+                    );
+                }
+                if !requests.is_empty() {
+                    webhooks.push(ApiWebhook {
+                        name: name.clone(),
+                        kind: WebhookKind::Callback {
+                            operation: parent.slug(),
+                        },
+                        operations: requests,
+                    });
+                }
+            }
+        }
+    }
+
+    webhooks
 }
 
 /// Extract operations from a path item.
@@ -115,9 +328,28 @@ fn extract_operations(
     path: &str,
     item: &PathItem,
     spec: &OpenAPI,
+    security_schemes: &BTreeMap<String, SecurityScheme>,
+    default_security: Option<&[openapiv3::SecurityRequirement]>,
     operations: &mut Vec<ApiOperation>,
 ) {
-    let methods = [
+    for (method, op) in path_operations(item) {
+        operations.push(transform_operation(
+            path,
+            method,
+            op,
+            &item.parameters,
+            spec,
+            security_schemes,
+            default_security,
+        ));
+    }
+}
+
+/// The public operations of a path item, in display order. Internal
+/// operations, or all of them when the path item is internal, are left out.
+fn path_operations(item: &PathItem) -> impl Iterator<Item = (HttpMethod, &Operation)> {
+    let hidden = is_internal(item.extensions.iter());
+    [
         (HttpMethod::Get, &item.get),
         (HttpMethod::Post, &item.post),
         (HttpMethod::Put, &item.put),
@@ -125,19 +357,10 @@ fn extract_operations(
         (HttpMethod::Patch, &item.patch),
         (HttpMethod::Head, &item.head),
         (HttpMethod::Options, &item.options),
-    ];
-
-    for (method, op_option) in methods {
-        if let Some(op) = op_option {
-            operations.push(transform_operation(
-                path,
-                method,
-                op,
-                &item.parameters,
-                spec,
-            ));
-        }
-    }
+    ]
+    .into_iter()
+    .filter_map(|(method, op)| Some((method, op.as_ref()?)))
+    .filter(move |(_, op)| !hidden && !is_internal(op.extensions.iter()))
 }
 
 /// Transform an operation.
@@ -147,6 +370,8 @@ fn transform_operation(
     op: &Operation,
     path_params: &[ReferenceOr<Parameter>],
     spec: &OpenAPI,
+    security_schemes: &BTreeMap<String, SecurityScheme>,
+    default_security: Option<&[openapiv3::SecurityRequirement]>,
 ) -> ApiOperation {
     // Combine path-level and operation-level parameters
     let mut parameters: Vec<ApiParameter> = path_params
@@ -186,9 +411,209 @@ fn transform_operation(
         request_body,
         responses,
         deprecated: op.deprecated,
+        code_samples: code_samples(op),
+        security: transform_security(
+            op.security.as_deref().or(default_security),
+            security_schemes,
+        ),
+        playground: playground_enabled(op.extensions.get("x-playground")),
+        custom_slug: op.extensions.get("x-slug").and_then(custom_slug),
+    }
+}
+
+/// The `x-slug` extension as a docs path. Each `/`-separated segment is
+/// slugified (lowercase, runs of other characters become one `-`), and
+/// segments left empty, such as `..`, are dropped.
+fn custom_slug(value: &Value) -> Option<String> {
+    let segments: Vec<String> = value
+        .as_str()?
+        .split('/')
+        .map(slugify_segment)
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    (!segments.is_empty()).then(|| segments.join("/"))
+}
+
+fn slugify_segment(segment: &str) -> String {
+    let mut slug = String::new();
+    for ch in segment.chars().flat_map(char::to_lowercase) {
+        if ch.is_alphanumeric() || ch == '_' {
+            slug.push(ch);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Hand-written samples from the `x-codeSamples` (Redoc) or `x-code-samples`
+/// (older Redoc) extension. Entries without `lang` or `source` are skipped.
+fn code_samples(op: &Operation) -> Vec<CodeSample> {
+    let Some(serde_json::Value::Array(entries)) = op
+        .extensions
+        .get("x-codeSamples")
+        .or_else(|| op.extensions.get("x-code-samples"))
+    else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let lang = entry.get("lang")?.as_str()?.to_string();
+            let source = entry.get("source")?.as_str()?.to_string();
+            let label = entry
+                .get("label")
+                .and_then(|l| l.as_str())
+                .map(str::to_string)
+                .or_else(|| SampleLanguage::from_lang(&lang).map(|l| l.label().to_string()))
+                .unwrap_or_else(|| lang.clone());
+            Some(CodeSample {
+                lang,
+                label,
+                source,
+            })
+        })
+        .collect()
+}
+
+/// Transform `components.securitySchemes`, following `$ref`s between them.
+fn transform_security_schemes(spec: &OpenAPI) -> BTreeMap<String, SecurityScheme> {
+    let Some(components) = &spec.components else {
+        return BTreeMap::new();
+    };
+    components
+        .security_schemes
+        .iter()
+        .filter_map(|(name, scheme_ref)| {
+            let scheme = match scheme_ref {
+                ReferenceOr::Item(scheme) => scheme,
+                ReferenceOr::Reference { reference } => {
+                    let target = reference.strip_prefix("#/components/securitySchemes/")?;
+                    components.security_schemes.get(target)?.as_item()?
+                }
+            };
+            Some((name.clone(), transform_security_scheme(scheme)))
+        })
+        .collect()
+}
+
+/// Transform a security scheme.
+fn transform_security_scheme(scheme: &openapiv3::SecurityScheme) -> SecurityScheme {
+    use openapiv3::SecurityScheme as Scheme;
+
+    match scheme {
+        Scheme::APIKey {
+            location,
+            name,
+            description,
+            ..
+        } => SecurityScheme {
+            kind: SecuritySchemeKind::ApiKey {
+                name: name.clone(),
+                location: match location {
+                    APIKeyLocation::Query => ParameterLocation::Query,
+                    APIKeyLocation::Header => ParameterLocation::Header,
+                    APIKeyLocation::Cookie => ParameterLocation::Cookie,
+                },
+            },
+            description: description.clone(),
+        },
+        Scheme::HTTP {
+            scheme,
+            bearer_format,
+            description,
+            ..
+        } => SecurityScheme {
+            kind: SecuritySchemeKind::Http {
+                scheme: scheme.to_ascii_lowercase(),
+                bearer_format: bearer_format.clone(),
+            },
+            description: description.clone(),
+        },
+        Scheme::OAuth2 {
+            flows, description, ..
+        } => SecurityScheme {
+            kind: SecuritySchemeKind::OAuth2 {
+                flows: oauth_flows(flows),
+            },
+            description: description.clone(),
+        },
+        Scheme::OpenIDConnect {
+            open_id_connect_url,
+            description,
+            ..
+        } => SecurityScheme {
+            kind: SecuritySchemeKind::OpenIdConnect {
+                url: open_id_connect_url.clone(),
+            },
+            description: description.clone(),
+        },
     }
 }
 
+/// OAuth 2.0 flows. `openapiv3` keeps the flow fields private, so they are
+/// read back from the serialized form.
+fn oauth_flows(flows: &openapiv3::OAuth2Flows) -> Vec<OAuthFlow> {
+    let Ok(Value::Object(flows)) = serde_json::to_value(flows) else {
+        return Vec::new();
+    };
+    [
+        "authorizationCode",
+        "clientCredentials",
+        "implicit",
+        "password",
+    ]
+    .into_iter()
+    .filter_map(|flow| {
+        let data = flows.get(flow)?;
+        let url = |key: &str| data.get(key).and_then(Value::as_str).map(str::to_string);
+        let scopes = data
+            .get("scopes")
+            .and_then(Value::as_object)
+            .map(|scopes| {
+                scopes
+                    .iter()
+                    .map(|(name, desc)| {
+                        (name.clone(), desc.as_str().unwrap_or_default().to_string())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(OAuthFlow {
+            flow: flow.to_string(),
+            authorization_url: url("authorizationUrl"),
+            token_url: url("tokenUrl"),
+            scopes,
+        })
+    })
+    .collect()
+}
+
+/// Resolve security requirements against the spec's schemes. Requirements
+/// naming an unknown scheme are dropped, since they can't be satisfied.
+fn transform_security(
+    requirements: Option<&[openapiv3::SecurityRequirement]>,
+    security_schemes: &BTreeMap<String, SecurityScheme>,
+) -> Vec<SecurityRequirement> {
+    requirements
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|requirement| {
+            let schemes = requirement
+                .iter()
+                .map(|(name, scopes)| {
+                    Some(RequiredScheme {
+                        name: name.clone(),
+                        scheme: security_schemes.get(name)?.clone(),
+                        scopes: scopes.clone(),
+                    })
+                })
+                .collect::<Option<_>>()?;
+            Some(SecurityRequirement { schemes })
+        })
+        .collect()
+}
+
 /// Transform a parameter.
 fn transform_parameter(param_ref: &ReferenceOr<Parameter>, spec: &OpenAPI) -> Option<ApiParameter> {
     let param = resolve_parameter(param_ref, spec)?;
@@ -256,14 +681,7 @@ fn transform_request_body(
     let content = rb
         .content
         .iter()
-        .map(|(media_type, media)| MediaTypeContent {
-            media_type: media_type.clone(),
-            schema: media
-                .schema
-                .as_ref()
-                .map(|s| resolve_and_transform(s, spec, &mut Vec::new())),
-            example: media.example.as_ref().map(format_json_value),
-        })
+        .map(|(media_type, media)| transform_media_type(media_type, media, spec))
         .collect();
 
     Some(ApiRequestBody {
@@ -307,28 +725,134 @@ fn transform_response(
 
     let resp = resolve_response(resp_ref, spec);
 
-    let (description, content) = if let Some(r) = resp {
-        let content = r
+    let Some(r) = resp else {
+        return ApiResponse {
+            status_code: status_str,
+            description: String::new(),
+            content: Vec::new(),
+            headers: Vec::new(),
+            links: Vec::new(),
+        };
+    };
+
+    ApiResponse {
+        status_code: status_str,
+        description: r.description.clone(),
+        content: r
             .content
             .iter()
-            .map(|(media_type, media)| MediaTypeContent {
-                media_type: media_type.clone(),
-                schema: media
-                    .schema
-                    .as_ref()
-                    .map(|s| resolve_and_transform(s, spec, &mut Vec::new())),
-                example: media.example.as_ref().map(format_json_value),
+            .map(|(media_type, media)| transform_media_type(media_type, media, spec))
+            .collect(),
+        // `Content-Type` is described by `content`; the spec says to ignore it here.
+        headers: r
+            .headers
+            .iter()
+            .filter(|(name, _)| !name.eq_ignore_ascii_case("content-type"))
+            .filter_map(|(name, header)| transform_header(name, header, spec))
+            .collect(),
+        links: r
+            .links
+            .iter()
+            .filter_map(|(name, link)| transform_link(name, link, spec))
+            .collect(),
+    }
+}
+
+/// Transform the content of one media type.
+fn transform_media_type(
+    media_type: &str,
+    media: &openapiv3::MediaType,
+    spec: &OpenAPI,
+) -> MediaTypeContent {
+    MediaTypeContent {
+        media_type: media_type.to_string(),
+        schema: media
+            .schema
+            .as_ref()
+            .map(|s| resolve_and_transform(s, spec, &mut Vec::new())),
+        example: media.example.as_ref().map(format_json_value),
+        examples: media
+            .examples
+            .iter()
+            .filter_map(|(name, example)| {
+                let example =
+                    resolve_component(example, spec, "examples", |c, n| c.examples.get(n))?;
+                Some(NamedExample {
+                    name: name.clone(),
+                    summary: example.summary.clone(),
+                    description: example.description.clone(),
+                    value: example.value.as_ref().map(format_json_value),
+                    external_value: example.external_value.clone(),
+                })
             })
-            .collect();
-        (r.description.clone(), content)
-    } else {
-        (String::new(), Vec::new())
+            .collect(),
+    }
+}
+
+/// Transform a response header into a header parameter.
+fn transform_header(
+    name: &str,
+    header_ref: &ReferenceOr<openapiv3::Header>,
+    spec: &OpenAPI,
+) -> Option<ApiParameter> {
+    let header = resolve_component(header_ref, spec, "headers", |c, n| c.headers.get(n))?;
+    let schema = match &header.format {
+        ParameterSchemaOrContent::Schema(s) => {
+            Some(resolve_and_transform(s, spec, &mut Vec::new()))
+        }
+        ParameterSchemaOrContent::Content(_) => None,
     };
+    Some(ApiParameter {
+        name: name.to_string(),
+        location: ParameterLocation::Header,
+        description: header.description.clone(),
+        required: header.required,
+        deprecated: header.deprecated.unwrap_or(false),
+        schema,
+        example: header.example.as_ref().map(format_json_value),
+    })
+}
 
-    ApiResponse {
-        status_code: status_str,
-        description,
-        content,
+/// Transform a response link.
+fn transform_link(
+    name: &str,
+    link_ref: &ReferenceOr<openapiv3::Link>,
+    spec: &OpenAPI,
+) -> Option<ResponseLink> {
+    let link = resolve_component(link_ref, spec, "links", |c, n| c.links.get(n))?;
+    let (operation_id, operation_ref) = match &link.operation {
+        openapiv3::LinkOperation::OperationId(id) => (Some(id.clone()), None),
+        openapiv3::LinkOperation::OperationRef(r) => (None, Some(r.clone())),
+    };
+    Some(ResponseLink {
+        name: name.to_string(),
+        description: link.description.clone(),
+        operation_id,
+        operation_ref,
+        parameters: link
+            .parameters
+            .iter()
+            .map(|(param, value)| (param.clone(), format_json_value(value)))
+            .collect(),
+    })
+}
+
+/// Resolve a `#/components/<kind>/<name>` reference one level deep.
+fn resolve_component<'a, T>(
+    item_ref: &'a ReferenceOr<T>,
+    spec: &'a OpenAPI,
+    kind: &str,
+    lookup: impl Fn(&'a openapiv3::Components, &str) -> Option<&'a ReferenceOr<T>>,
+) -> Option<&'a T> {
+    match item_ref {
+        ReferenceOr::Item(item) => Some(item),
+        ReferenceOr::Reference { reference } => {
+            let name = reference
+                .strip_prefix("#/components/")?
+                .strip_prefix(kind)?
+                .strip_prefix('/')?;
+            lookup(spec.components.as_ref()?, name)?.as_item()
+        }
     }
 }
 
@@ -447,6 +971,22 @@ fn transform_schema(schema: &Schema, spec: &OpenAPI, seen: &mut Vec<String>) ->
         example: schema.schema_data.example.as_ref().map(format_json_value),
         default: schema.schema_data.default.as_ref().map(format_json_value),
         nullable: schema.schema_data.nullable,
+        discriminator: schema
+            .schema_data
+            .discriminator
+            .as_ref()
+            .map(|d| Discriminator {
+                property_name: d.property_name.clone(),
+                mapping: d
+                    .mapping
+                    .iter()
+                    .map(|(value, target)| {
+                        // Targets are `$ref`s or bare schema names.
+                        let name = target.rsplit('/').next().unwrap_or(target);
+                        (value.clone(), name.to_string())
+                    })
+                    .collect(),
+            }),
         ..Default::default()
     };
 
@@ -460,13 +1000,21 @@ fn transform_schema(schema: &Schema, spec: &OpenAPI, seen: &mut Vec<String>) ->
             Type::Number(n) => {
                 def.schema_type = SchemaType::Number;
                 def.format = extract_format(&n.format);
+                def.enum_values = n.enumeration.iter().flatten().map(f64::to_string).collect();
             }
             Type::Integer(i) => {
                 def.schema_type = SchemaType::Integer;
                 def.format = extract_format(&i.format);
+                def.enum_values = i.enumeration.iter().flatten().map(i64::to_string).collect();
             }
-            Type::Boolean(_) => {
+            Type::Boolean(b) => {
                 def.schema_type = SchemaType::Boolean;
+                def.enum_values = b
+                    .enumeration
+                    .iter()
+                    .flatten()
+                    .map(bool::to_string)
+                    .collect();
             }
             Type::Array(a) => {
                 def.schema_type = SchemaType::Array;
@@ -513,8 +1061,12 @@ fn transform_schema(schema: &Schema, spec: &OpenAPI, seen: &mut Vec<String>) ->
                 .map(|s| resolve_and_transform(s, spec, seen))
                 .collect();
         }
-        SchemaKind::Not { .. } => {
-            // Not supported, treat as any
+        SchemaKind::Not { not } => {
+            def.not = Some(Box::new(resolve_and_transform(not, spec, seen)));
+        }
+        // OpenAPI 3.1 `type: "null"`, which the 3.0 model has no variant for.
+        SchemaKind::Any(any) if any.typ.as_deref() == Some("null") => {
+            def.schema_type = SchemaType::Null;
         }
         SchemaKind::Any(_) => {
             // Already defaults to Any
@@ -525,7 +1077,7 @@ fn transform_schema(schema: &Schema, spec: &OpenAPI, seen: &mut Vec<String>) ->
 }
 
 /// Format a JSON value as a string.
-fn format_json_value(value: &serde_json::Value) -> String {
+pub(super) fn format_json_value(value: &serde_json::Value) -> String {
     match value {
         serde_json::Value::String(s) => s.clone(),
         other => serde_json::to_string_pretty(other).unwrap_or_default(),
@@ -694,6 +1246,167 @@ paths:
         assert_eq!(rb.content[0].media_type, "application/json");
     }
 
+    #[test]
+    fn test_parse_response_headers_links_and_examples() {
+        let yaml = r##"
+openapi: "3.0.0"
+info: {title: Test API, version: "1.0.0"}
+paths:
+  /users:
+    post:
+      operationId: createUser
+      responses:
+        "201":
+          description: Created
+          headers:
+            Location:
+              description: URL of the new user.
+              required: true
+              schema: {type: string, format: uri}
+            X-Rate-Limit:
+              $ref: '#/components/headers/RateLimit'
+            Content-Type:
+              schema: {type: string}
+          links:
+            GetUser:
+              operationId: getUser
+              parameters:
+                id: $response.body#/id
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  id: {type: integer}
+              examples:
+                admin:
+                  summary: An admin
+                  value: {id: 1, role: admin}
+                guest:
+                  $ref: '#/components/examples/Guest'
+components:
+  headers:
+    RateLimit:
+      schema: {type: integer}
+  examples:
+    Guest:
+      value: {id: 2, role: guest}
+"##;
+        let spec = parse_openapi(yaml).unwrap();
+        let response = &spec.operations[0].responses[0];
+
+        let headers: Vec<&str> = response.headers.iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(headers, ["Location", "X-Rate-Limit"]);
+        assert!(response.headers[0].required);
+        assert_eq!(
+            response.headers[1].schema.as_ref().unwrap().schema_type,
+            SchemaType::Integer
+        );
+
+        let link = &response.links[0];
+        assert_eq!(link.name, "GetUser");
+        assert_eq!(link.operation_id.as_deref(), Some("getUser"));
+        assert_eq!(
+            link.parameters,
+            [("id".to_string(), "$response.body#/id".to_string())]
+        );
+
+        let examples = &response.content[0].examples;
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].label(), "An admin");
+        assert_eq!(examples[1].label(), "guest");
+        assert!(examples[1].value.as_deref().unwrap().contains("guest"));
+    }
+
+    #[test]
+    fn test_parse_server_variables() {
+        let yaml = r#"
+openapi: "3.0.0"
+info: {title: Test API, version: "1.0.0"}
+servers:
+  - url: https://{region}.api.example.com/{version}
+    description: Production
+    variables:
+      region:
+        default: eu
+        enum: [eu, us]
+        description: Data residency region.
+      version:
+        default: v1
+paths: {}
+"#;
+        let spec = parse_openapi(yaml).unwrap();
+        let server = &spec.servers[0];
+        let names: Vec<&str> = server.variables.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["region", "version"]);
+        assert_eq!(server.variables[0].enum_values, ["eu", "us"]);
+        assert_eq!(server.label(), "Production");
+
+        assert_eq!(
+            server.resolve_url(&BTreeMap::new()),
+            "https://eu.api.example.com/v1"
+        );
+        let values = [("region".to_string(), "us".to_string())].into();
+        assert_eq!(server.resolve_url(&values), "https://us.api.example.com/v1");
+    }
+
+    #[test]
+    fn test_parse_webhooks_and_callbacks() {
+        let yaml = r##"
+openapi: "3.0.0"
+info: {title: Test API, version: "1.0.0"}
+security:
+  - apiKey: []
+x-webhooks:
+  petAdopted:
+    post:
+      summary: Pet adopted
+      requestBody:
+        content:
+          application/json:
+            schema: {type: object, properties: {id: {type: integer}}}
+      responses:
+        "200": {description: Received}
+paths:
+  /subscriptions:
+    post:
+      operationId: createSubscription
+      callbacks:
+        onEvent:
+          "{$request.body#/callbackUrl}":
+            post:
+              responses:
+                "204": {description: Acknowledged}
+      responses:
+        "201": {description: Created}
+components:
+  securitySchemes:
+    apiKey: {type: apiKey, in: header, name: X-Key}
+"##;
+        let spec = parse_openapi(yaml).unwrap();
+        assert_eq!(spec.operations.len(), 1);
+        assert_eq!(spec.webhooks.len(), 2);
+
+        let hook = &spec.webhooks[0];
+        assert_eq!(hook.kind, WebhookKind::Webhook);
+        assert_eq!(hook.slug(), "pet-adopted");
+        assert_eq!(hook.title(), "Pet adopted");
+        assert!(hook.operations[0].request_body.is_some());
+        // The spec's default security covers requests to the API, not these.
+        assert!(hook.operations[0].security.is_empty());
+
+        let callback = &spec.webhooks[1];
+        assert_eq!(
+            callback.kind,
+            WebhookKind::Callback {
+                operation: "create-subscription".into()
+            }
+        );
+        assert_eq!(callback.slug(), "create-subscription-on-event");
+        assert_eq!(callback.title(), "onEvent");
+        assert_eq!(callback.operations[0].path, "{$request.body#/callbackUrl}");
+    }
+
     #[test]
     fn test_http_method_badge_class() {
         assert_eq!(HttpMethod::Get.badge_class(), "badge-soft badge-success");
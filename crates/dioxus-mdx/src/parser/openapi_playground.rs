@@ -0,0 +1,276 @@
+//! Request building for the interactive "Try it" playground.
+//!
+//! The playground form holds one string per operation parameter plus the raw
+//! body text. [`ApiOperation::build_playground_request`] turns those into the
+//! request the browser sends, so everything but the `fetch` call itself is
+//! plain, testable Rust.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use super::openapi_samples::encode;
+use super::openapi_security::base64;
+use super::openapi_types::{ApiOperation, HttpMethod, ParameterLocation, SecuritySchemeKind};
+
+/// Whether a spec or operation leaves the playground on, given its
+/// `x-playground` extension. Only an explicit `false` turns it off.
+pub(super) fn playground_enabled(flag: Option<&Value>) -> bool {
+    flag.and_then(Value::as_bool).unwrap_or(true)
+}
+
+/// A request ready to hand to `fetch`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaygroundRequest {
+    pub method: HttpMethod,
+    /// Full URL with path parameters substituted and the query string appended.
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// Raw body text, when the operation takes a body and one was given.
+    pub body: Option<String>,
+}
+
+/// Why the playground form can't be sent yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaygroundError {
+    /// A required parameter was left empty.
+    MissingParameter(String),
+    /// A JSON body failed to parse.
+    InvalidBody(String),
+}
+
+impl std::fmt::Display for PlaygroundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingParameter(name) => write!(f, "Missing required parameter `{name}`"),
+            Self::InvalidBody(e) => write!(f, "Request body is not valid JSON: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for PlaygroundError {}
+
+impl ApiOperation {
+    /// Initial form values, one per entry in `parameters`: the example value
+    /// for required parameters, empty for optional ones.
+    pub fn playground_inputs(&self) -> Vec<String> {
+        self.parameters
+            .iter()
+            .map(|param| {
+                if param.required {
+                    param.sample_value().unwrap_or_default()
+                } else {
+                    String::new()
+                }
+            })
+            .collect()
+    }
+
+    /// Initial body text: the pretty-printed example JSON, or empty for
+    /// non-JSON bodies. `None` when the operation takes no body.
+    pub fn playground_body(&self) -> Option<String> {
+        self.request_body.as_ref()?;
+        let body = self
+            .sample_request("")
+            .body
+            .and_then(|body| serde_json::to_string_pretty(&body).ok());
+        Some(body.unwrap_or_default())
+    }
+
+    /// Build the request for the playground form.
+    ///
+    /// `values` are aligned with `parameters` (see [`Self::playground_inputs`]);
+    /// empty values are left out. `credentials` maps names from
+    /// [`Self::auth_schemes`] to a token, API key or, for HTTP Basic,
+    /// `username:password`. Cookie parameters and cookie API keys are
+    /// skipped, browsers don't let scripts set them.
+    pub fn build_playground_request(
+        &self,
+        server_url: &str,
+        values: &[String],
+        credentials: &BTreeMap<String, String>,
+        body: &str,
+    ) -> Result<PlaygroundRequest, PlaygroundError> {
+        let mut path = self.path.clone();
+        let mut query = Vec::new();
+        let mut headers = Vec::new();
+
+        for (param, value) in self.parameters.iter().zip(values) {
+            let value = value.trim();
+            if value.is_empty() {
+                if param.required && param.location != ParameterLocation::Cookie {
+                    return Err(PlaygroundError::MissingParameter(param.name.clone()));
+                }
+                continue;
+            }
+            match param.location {
+                ParameterLocation::Path => {
+                    path = path.replace(&format!("{{{}}}", param.name), &encode(value));
+                }
+                ParameterLocation::Query => {
+                    query.push(format!("{}={}", encode(&param.name), encode(value)));
+                }
+                ParameterLocation::Header => headers.push((param.name.clone(), value.to_string())),
+                ParameterLocation::Cookie => {}
+            }
+        }
+
+        for required in self.auth_schemes() {
+            let Some(value) = credentials
+                .get(&required.name)
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+            else {
+                continue;
+            };
+            let scheme = &required.scheme;
+            let value = match &scheme.kind {
+                SecuritySchemeKind::Http { scheme, .. } if scheme == "basic" => base64(value),
+                _ => value.to_string(),
+            };
+            match scheme.credential(&value) {
+                (ParameterLocation::Query, name, value) => {
+                    query.push(format!("{}={}", encode(&name), encode(&value)));
+                }
+                (ParameterLocation::Header, name, value) => headers.push((name, value)),
+                _ => {}
+            }
+        }
+
+        let mut url = format!("{}{}", server_url.trim().trim_end_matches('/'), path);
+        if !query.is_empty() {
+            url = format!("{url}?{}", query.join("&"));
+        }
+
+        let mut request_body = None;
+        if let Some(spec_body) = &self.request_body {
+            let media_type = spec_body
+                .content
+                .iter()
+                .find(|c| c.media_type.contains("json"))
+                .or(spec_body.content.first())
+                .map(|c| c.media_type.as_str())
+                .unwrap_or("application/json");
+            if !body.trim().is_empty() {
+                if media_type.contains("json") {
+                    serde_json::from_str::<Value>(body)
+                        .map_err(|e| PlaygroundError::InvalidBody(e.to_string()))?;
+                }
+                headers.push(("Content-Type".into(), media_type.to_string()));
+                request_body = Some(body.to_string());
+            }
+        }
+
+        Ok(PlaygroundRequest {
+            method: self.method,
+            url,
+            headers,
+            body: request_body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::openapi_parser::parse_openapi;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: {title: Pets, version: '1'}
+paths:
+  /pets/{petId}:
+    parameters:
+      - {name: petId, in: path, required: true, schema: {type: integer, example: 7}}
+    put:
+      operationId: updatePet
+      parameters:
+        - {name: X-Trace, in: header, required: true, schema: {type: string, example: abc}}
+        - {name: dry run, in: query, schema: {type: boolean}}
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema:
+              type: object
+              properties:
+                name: {type: string, example: Rex}
+      responses:
+        '200': {description: OK}
+    delete:
+      x-playground: false
+      responses:
+        '204': {description: Deleted}
+"##;
+
+    fn update_pet() -> ApiOperation {
+        parse_openapi(SPEC)
+            .unwrap()
+            .operations
+            .into_iter()
+            .find(|op| op.operation_id.as_deref() == Some("updatePet"))
+            .unwrap()
+    }
+
+    #[test]
+    fn defaults_prefill_required_parameters_and_body() {
+        let op = update_pet();
+        assert_eq!(op.playground_inputs(), ["7", "abc", ""]);
+        let body = op.playground_body().unwrap();
+        assert!(body.contains("\"Rex\""), "{body}");
+    }
+
+    #[test]
+    fn builds_url_headers_and_body_from_form_values() {
+        let op = update_pet();
+        let values = ["7".to_string(), "abc".into(), "yes please".into()];
+        let request = op
+            .build_playground_request(
+                "http://localhost:4010/",
+                &values,
+                &BTreeMap::new(),
+                "{\"name\": \"Rex\"}",
+            )
+            .unwrap();
+        assert_eq!(request.method, HttpMethod::Put);
+        assert_eq!(
+            request.url,
+            "http://localhost:4010/pets/7?dry%20run=yes%20please"
+        );
+        assert_eq!(
+            request.headers,
+            [
+                ("X-Trace".to_string(), "abc".to_string()),
+                ("Content-Type".into(), "application/json".into()),
+            ]
+        );
+        assert_eq!(request.body.as_deref(), Some("{\"name\": \"Rex\"}"));
+    }
+
+    #[test]
+    fn missing_parameters_and_bad_json_are_reported() {
+        let op = update_pet();
+        let values = [String::new(), "abc".into(), String::new()];
+        assert_eq!(
+            op.build_playground_request("http://localhost", &values, &BTreeMap::new(), "{}"),
+            Err(PlaygroundError::MissingParameter("petId".into()))
+        );
+
+        let values = ["7".to_string(), "abc".into(), String::new()];
+        assert!(matches!(
+            op.build_playground_request("http://localhost", &values, &BTreeMap::new(), "{nope"),
+            Err(PlaygroundError::InvalidBody(_))
+        ));
+    }
+
+    #[test]
+    fn x_playground_false_disables_specs_and_operations() {
+        let spec = parse_openapi(SPEC).unwrap();
+        assert!(spec.playground);
+        let enabled: Vec<bool> = spec.operations.iter().map(|op| op.playground).collect();
+        assert_eq!(enabled, [true, false]);
+
+        let spec = parse_openapi(&SPEC.replace("info:", "x-playground: false\ninfo:")).unwrap();
+        assert!(!spec.playground);
+    }
+}
@@ -0,0 +1,651 @@
+//! Request code samples for API operations.
+//!
+//! Every language is generated from the same [`SampleRequest`]: the URL with
+//! path and (percent-encoded) query parameters filled in, header
+//! parameters, placeholder credentials for the first security requirement,
+//! and a JSON body built from the request schema. Hand-written samples from
+//! the spec's `x-codeSamples` / `x-code-samples` extension take precedence.
+
+use serde_json::Value;
+
+use super::openapi_types::{
+    ApiOperation, ApiParameter, CodeSample, HttpMethod, ParameterLocation, SchemaType,
+};
+
+/// A language with a built-in request sample generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleLanguage {
+    Curl,
+    Python,
+    JavaScript,
+    TypeScript,
+    Go,
+    Rust,
+}
+
+impl SampleLanguage {
+    /// All generated languages, in switcher order.
+    pub const ALL: [SampleLanguage; 6] = [
+        Self::Curl,
+        Self::Python,
+        Self::JavaScript,
+        Self::TypeScript,
+        Self::Go,
+        Self::Rust,
+    ];
+
+    /// Stable id, used as [`CodeSample::lang`] and for the persisted choice.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Curl => "curl",
+            Self::Python => "python",
+            Self::JavaScript => "javascript",
+            Self::TypeScript => "typescript",
+            Self::Go => "go",
+            Self::Rust => "rust",
+        }
+    }
+
+    /// Display label for the switcher.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Curl => "cURL",
+            Self::Python => "Python",
+            Self::JavaScript => "JavaScript",
+            Self::TypeScript => "TypeScript",
+            Self::Go => "Go",
+            Self::Rust => "Rust",
+        }
+    }
+
+    /// Match an `x-codeSamples` `lang` value (`"Shell"`, `"py"`, `"Node"`, ...).
+    pub fn from_lang(lang: &str) -> Option<Self> {
+        match lang.trim().to_ascii_lowercase().as_str() {
+            "curl" | "shell" | "bash" | "sh" => Some(Self::Curl),
+            "python" | "py" => Some(Self::Python),
+            "javascript" | "js" | "node" | "nodejs" => Some(Self::JavaScript),
+            "typescript" | "ts" => Some(Self::TypeScript),
+            "go" | "golang" => Some(Self::Go),
+            "rust" | "rs" => Some(Self::Rust),
+            _ => None,
+        }
+    }
+}
+
+/// The parts of an example request every generator shares.
+pub(super) struct SampleRequest {
+    pub method: HttpMethod,
+    /// Full URL, including path parameter values and the query string.
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// Example JSON body, when the operation takes one.
+    pub body: Option<Value>,
+}
+
+impl SampleRequest {
+    /// Whether a generator whose JSON helper (`json=`, `.json()`) sets the
+    /// Content-Type itself can leave out this header.
+    fn implied_by_json(&self, name: &str, value: &str) -> bool {
+        self.body.is_some() && name == "Content-Type" && value == "application/json"
+    }
+}
+
+impl ApiParameter {
+    /// Example value used in samples: generated from the schema, falling back
+    /// to the parameter's own `example`.
+    pub fn sample_value(&self) -> Option<String> {
+        self.schema
+            .as_ref()
+            .map(|schema| {
+                let val = schema.generate_example_json(0);
+                val.as_str()
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| val.to_string())
+            })
+            .or_else(|| self.example.clone())
+    }
+}
+
+impl ApiOperation {
+    /// Build the example request the samples are generated from.
+    pub(super) fn sample_request(&self, base_url: &str) -> SampleRequest {
+        let mut url = format!("{}{}", base_url.trim_end_matches('/'), self.path);
+        let mut query_parts = Vec::new();
+        let mut headers = Vec::new();
+
+        for param in &self.parameters {
+            match param.location {
+                ParameterLocation::Path => {
+                    let placeholder = param
+                        .sample_value()
+                        .unwrap_or_else(|| format!("{{{}}}", param.name));
+                    url = url.replace(&format!("{{{}}}", param.name), &placeholder);
+                }
+                ParameterLocation::Query => {
+                    if let Some(value) = param.sample_value() {
+                        query_parts.push(format!("{}={}", encode(&param.name), encode(&value)));
+                    }
+                }
+                ParameterLocation::Header => {
+                    headers.push((param.name.clone(), param.sample_value().unwrap_or_default()));
+                }
+                ParameterLocation::Cookie => {}
+            }
+        }
+
+        if let Some(requirement) = self.security.first() {
+            for required in &requirement.schemes {
+                let scheme = &required.scheme;
+                let (location, name, value) = scheme.credential(scheme.placeholder());
+                match location {
+                    ParameterLocation::Query => {
+                        query_parts.push(format!("{}={}", encode(&name), encode(&value)))
+                    }
+                    ParameterLocation::Cookie => {
+                        headers.push(("Cookie".into(), format!("{name}={value}")))
+                    }
+                    _ => headers.push((name, value)),
+                }
+            }
+        }
+
+        if !query_parts.is_empty() {
+            url = format!("{}?{}", url, query_parts.join("&"));
+        }
+
+        // Prefer a JSON body; other media types (multipart, forms) only get
+        // their Content-Type, as there is no JSON example to send.
+        let mut body = None;
+        let content = self.request_body.as_ref().and_then(|request_body| {
+            request_body
+                .content
+                .iter()
+                .find(|c| c.media_type.contains("json"))
+                .or_else(|| request_body.content.first())
+        });
+        if let Some(content) = content {
+            headers.push(("Content-Type".into(), content.media_type.clone()));
+            if content.media_type.contains("json") {
+                body = content
+                    .schema
+                    .as_ref()
+                    .map(|schema| schema.generate_example_json(0));
+            }
+        }
+
+        SampleRequest {
+            method: self.method,
+            url,
+            headers,
+            body,
+        }
+    }
+
+    /// Generate a curl command for this endpoint.
+    pub fn generate_curl(&self, base_url: &str) -> String {
+        let request = self.sample_request(base_url);
+        let mut parts = vec!["curl".to_string()];
+
+        if !matches!(self.method, HttpMethod::Get) {
+            parts.push(format!("-X {}", self.method.as_str()));
+        }
+        parts.push(format!("\"{}\"", request.url));
+        for (name, value) in &request.headers {
+            parts.push(format!("-H \"{name}: {value}\""));
+        }
+        if let Some(body) = &request.body
+            && let Ok(pretty) = serde_json::to_string_pretty(body)
+        {
+            parts.push(format!("-d '{pretty}'"));
+        }
+
+        parts.join(" \\\n  ")
+    }
+
+    /// Generate a request sample in `language`.
+    pub fn generate_sample(&self, language: SampleLanguage, base_url: &str) -> String {
+        let request = || self.sample_request(base_url);
+        match language {
+            SampleLanguage::Curl => self.generate_curl(base_url),
+            SampleLanguage::Python => python(&request()),
+            SampleLanguage::JavaScript => javascript(&request(), None),
+            SampleLanguage::TypeScript => javascript(&request(), Some(&self.response_type_name())),
+            SampleLanguage::Go => go(&request()),
+            SampleLanguage::Rust => rust(&request()),
+        }
+    }
+
+    /// Samples for the language switcher: one per [`SampleLanguage`], with
+    /// hand-written spec samples replacing generated ones for the same
+    /// language and any other spec languages appended.
+    pub fn request_samples(&self, base_url: &str) -> Vec<CodeSample> {
+        let mut samples: Vec<CodeSample> = SampleLanguage::ALL
+            .iter()
+            .map(|language| CodeSample {
+                lang: language.id().to_string(),
+                label: language.label().to_string(),
+                source: self.generate_sample(*language, base_url),
+            })
+            .collect();
+
+        for custom in &self.code_samples {
+            let id = SampleLanguage::from_lang(&custom.lang)
+                .map(|l| l.id().to_string())
+                .unwrap_or_else(|| custom.lang.to_ascii_lowercase());
+            let sample = CodeSample {
+                lang: id.clone(),
+                ..custom.clone()
+            };
+            match samples.iter_mut().find(|s| s.lang == id) {
+                Some(existing) => *existing = sample,
+                None => samples.push(sample),
+            }
+        }
+        samples
+    }
+
+    /// TypeScript type of the first 2xx JSON response, from its schema name.
+    fn response_type_name(&self) -> String {
+        let schema = self
+            .responses
+            .iter()
+            .filter(|r| r.status_code.starts_with('2'))
+            .flat_map(|r| &r.content)
+            .find_map(|c| c.schema.as_ref());
+        match schema {
+            Some(s) if s.ref_name.is_some() => s.ref_name.clone().unwrap_or_default(),
+            Some(s) if s.schema_type == SchemaType::Array => s
+                .items
+                .as_ref()
+                .and_then(|items| items.ref_name.as_ref())
+                .map(|name| format!("{name}[]"))
+                .unwrap_or_else(|| "unknown[]".into()),
+            _ => "unknown".into(),
+        }
+    }
+}
+
+/// Pretty-printed JSON with every line after the first indented by `indent`,
+/// for embedding in a call.
+fn indented_json(value: &Value, indent: &str) -> String {
+    serde_json::to_string_pretty(value)
+        .unwrap_or_default()
+        .replace('\n', &format!("\n{indent}"))
+}
+
+/// A JSON value as a Python literal.
+fn python_literal(value: &Value, indent: usize) -> String {
+    let pad = "    ".repeat(indent + 1);
+    let close = "    ".repeat(indent);
+    match value {
+        Value::Null => "None".into(),
+        Value::Bool(true) => "True".into(),
+        Value::Bool(false) => "False".into(),
+        Value::Number(_) | Value::String(_) => value.to_string(),
+        Value::Array(items) if items.is_empty() => "[]".into(),
+        Value::Array(items) => {
+            let items: Vec<String> = items
+                .iter()
+                .map(|v| format!("{pad}{},", python_literal(v, indent + 1)))
+                .collect();
+            format!("[\n{}\n{close}]", items.join("\n"))
+        }
+        Value::Object(map) if map.is_empty() => "{}".into(),
+        Value::Object(map) => {
+            let entries: Vec<String> = map
+                .iter()
+                .map(|(k, v)| {
+                    format!(
+                        "{pad}{}: {},",
+                        Value::String(k.clone()),
+                        python_literal(v, indent + 1)
+                    )
+                })
+                .collect();
+            format!("{{\n{}\n{close}}}", entries.join("\n"))
+        }
+    }
+}
+
+fn python(request: &SampleRequest) -> String {
+    let mut out = String::from("import requests\n\n");
+    let method = request.method.as_str().to_ascii_lowercase();
+    out.push_str(&format!("response = requests.{method}(\n"));
+    out.push_str(&format!("    {},\n", Value::String(request.url.clone())));
+
+    let headers: Vec<&(String, String)> = request
+        .headers
+        .iter()
+        .filter(|(name, value)| !request.implied_by_json(name, value))
+        .collect();
+    if !headers.is_empty() {
+        out.push_str("    headers={\n");
+        for (name, value) in headers {
+            out.push_str(&format!(
+                "        {}: {},\n",
+                Value::String(name.clone()),
+                Value::String(value.clone())
+            ));
+        }
+        out.push_str("    },\n");
+    }
+    if let Some(body) = &request.body {
+        out.push_str(&format!("    json={},\n", python_literal(body, 1)));
+    }
+    out.push_str(")\n");
+    out.push_str("print(response.json())");
+    out
+}
+
+/// JavaScript `fetch`, or TypeScript when `response_type` is given.
+fn javascript(request: &SampleRequest, response_type: Option<&str>) -> String {
+    let url = Value::String(request.url.clone());
+    let mut options = Vec::new();
+    if request.method != HttpMethod::Get {
+        options.push(format!("  method: \"{}\",", request.method.as_str()));
+    }
+    if !request.headers.is_empty() {
+        let mut headers = String::from("  headers: {\n");
+        for (name, value) in &request.headers {
+            headers.push_str(&format!(
+                "    {}: {},\n",
+                Value::String(name.clone()),
+                Value::String(value.clone())
+            ));
+        }
+        headers.push_str("  },");
+        options.push(headers);
+    }
+    if let Some(body) = &request.body {
+        options.push(format!(
+            "  body: JSON.stringify({}),",
+            indented_json(body, "  ")
+        ));
+    }
+
+    let annotation = if response_type.is_some() {
+        ": Response"
+    } else {
+        ""
+    };
+    let mut out = if options.is_empty() {
+        format!("const response{annotation} = await fetch({url});\n")
+    } else {
+        format!(
+            "const response{annotation} = await fetch({url}, {{\n{}\n}});\n",
+            options.join("\n")
+        )
+    };
+    match response_type {
+        Some(ty) => out.push_str(&format!("const data = (await response.json()) as {ty};\n")),
+        None => out.push_str("const data = await response.json();\n"),
+    }
+    out.push_str("console.log(data);");
+    out
+}
+
+fn go(request: &SampleRequest) -> String {
+    let mut imports = vec!["\"fmt\"", "\"io\"", "\"net/http\""];
+    let body = request.body.as_ref().map(|body| {
+        let json = serde_json::to_string_pretty(body)
+            .unwrap_or_default()
+            .replace('`', "` + \"`\" + `");
+        format!("\tbody := strings.NewReader(`{json}`)\n")
+    });
+    if body.is_some() {
+        imports.push("\"strings\"");
+    }
+
+    let mut out = String::from("package main\n\nimport (\n");
+    for import in imports {
+        out.push_str(&format!("\t{import}\n"));
+    }
+    out.push_str(")\n\nfunc main() {\n");
+    let body_arg = match &body {
+        Some(body) => {
+            out.push_str(body);
+            "body"
+        }
+        None => "nil",
+    };
+    out.push_str(&format!(
+        "\treq, err := http.NewRequest(\"{}\", {}, {body_arg})\n",
+        request.method.as_str(),
+        Value::String(request.url.clone())
+    ));
+    out.push_str("\tif err != nil {\n\t\tpanic(err)\n\t}\n");
+    for (name, value) in &request.headers {
+        out.push_str(&format!(
+            "\treq.Header.Set({}, {})\n",
+            Value::String(name.clone()),
+            Value::String(value.clone())
+        ));
+    }
+    out.push_str("\n\tres, err := http.DefaultClient.Do(req)\n");
+    out.push_str("\tif err != nil {\n\t\tpanic(err)\n\t}\n");
+    out.push_str("\tdefer res.Body.Close()\n\n");
+    out.push_str("\tdata, _ := io.ReadAll(res.Body)\n");
+    out.push_str("\tfmt.Println(string(data))\n}");
+    out
+}
+
+fn rust(request: &SampleRequest) -> String {
+    let url = Value::String(request.url.clone());
+    let call = match request.method {
+        HttpMethod::Get => format!(".get({url})"),
+        HttpMethod::Post => format!(".post({url})"),
+        HttpMethod::Put => format!(".put({url})"),
+        HttpMethod::Delete => format!(".delete({url})"),
+        HttpMethod::Patch => format!(".patch({url})"),
+        HttpMethod::Head => format!(".head({url})"),
+        HttpMethod::Options => format!(".request(reqwest::Method::OPTIONS, {url})"),
+    };
+
+    let mut out = String::from("let client = reqwest::Client::new();\n");
+    out.push_str("let response = client\n");
+    out.push_str(&format!("    {call}\n"));
+    for (name, value) in &request.headers {
+        if request.implied_by_json(name, value) {
+            continue;
+        }
+        out.push_str(&format!(
+            "    .header({}, {})\n",
+            Value::String(name.clone()),
+            Value::String(value.clone())
+        ));
+    }
+    if let Some(body) = &request.body {
+        out.push_str(&format!(
+            "    .json(&serde_json::json!({}))\n",
+            indented_json(body, "    ")
+        ));
+    }
+    out.push_str("    .send()\n    .await?;\n");
+    out.push_str("println!(\"{}\", response.text().await?);");
+    out
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters.
+pub(super) fn encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::openapi_parser::parse_openapi;
+
+    const SPEC: &str = r##"
+openapi: "3.0.0"
+info: {title: T, version: "1"}
+paths:
+  /pets/{petId}:
+    put:
+      operationId: updatePet
+      parameters:
+        - {name: petId, in: path, required: true, schema: {type: integer, example: 7}}
+        - {name: X-Trace, in: header, schema: {type: string, example: abc}}
+        - {name: note, in: query, schema: {type: string, example: "a&b c"}}
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              properties:
+                name: {type: string, example: Rex}
+                vaccinated: {type: boolean, example: true}
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema: {$ref: "#/components/schemas/Pet"}
+      x-codeSamples:
+        - lang: Shell
+          label: cURL (CLI)
+          source: pets update 7 --name Rex
+        - lang: PHP
+          source: $client->pets->update(7);
+  /health:
+    get:
+      responses:
+        "200": {description: OK}
+components:
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name: {type: string}
+"##;
+
+    fn update_pet() -> ApiOperation {
+        parse_openapi(SPEC)
+            .unwrap()
+            .operations
+            .into_iter()
+            .find(|op| op.path == "/pets/{petId}")
+            .unwrap()
+    }
+
+    #[test]
+    fn every_language_carries_url_headers_and_body() {
+        let op = update_pet();
+        for language in SampleLanguage::ALL {
+            let sample = op.generate_sample(language, "https://api.example.com");
+            assert!(
+                sample.contains("https://api.example.com/pets/7"),
+                "{language:?}: {sample}"
+            );
+            assert!(sample.contains("X-Trace"), "{language:?}: {sample}");
+            assert!(sample.contains("\"Rex\""), "{language:?}: {sample}");
+        }
+    }
+
+    #[test]
+    fn query_values_are_percent_encoded() {
+        let sample = update_pet().generate_sample(SampleLanguage::Curl, "https://api.example.com");
+        assert!(
+            sample.contains("\"https://api.example.com/pets/7?note=a%26b%20c\""),
+            "{sample}"
+        );
+    }
+
+    #[test]
+    fn non_json_bodies_send_their_own_content_type() {
+        let spec = parse_openapi(
+            r##"
+openapi: "3.0.0"
+info: {title: T, version: "1"}
+paths:
+  /uploads:
+    post:
+      requestBody:
+        content:
+          multipart/form-data:
+            schema:
+              type: object
+              properties:
+                file: {type: string, format: binary}
+      responses:
+        "201": {description: Created}
+"##,
+        )
+        .unwrap();
+        let upload = &spec.operations[0];
+        for language in SampleLanguage::ALL {
+            let sample = upload.generate_sample(language, "https://api.example.com");
+            assert!(
+                sample.contains("multipart/form-data"),
+                "{language:?}: {sample}"
+            );
+            assert!(
+                !sample.contains("application/json"),
+                "{language:?}: {sample}"
+            );
+        }
+    }
+
+    #[test]
+    fn python_uses_python_literals() {
+        let sample =
+            update_pet().generate_sample(SampleLanguage::Python, "https://api.example.com");
+        assert!(sample.starts_with("import requests"));
+        assert!(sample.contains("requests.put("));
+        assert!(sample.contains("\"vaccinated\": True,"));
+        // `json=` sets the Content-Type itself.
+        assert!(!sample.contains("Content-Type"));
+    }
+
+    #[test]
+    fn typescript_types_the_response() {
+        let sample =
+            update_pet().generate_sample(SampleLanguage::TypeScript, "https://api.example.com");
+        assert!(sample.contains("method: \"PUT\""));
+        assert!(sample.contains("as Pet;"));
+    }
+
+    #[test]
+    fn go_and_rust_skip_the_body_when_there_is_none() {
+        let spec = parse_openapi(SPEC).unwrap();
+        let health = spec
+            .operations
+            .iter()
+            .find(|op| op.path == "/health")
+            .unwrap();
+        let go = health.generate_sample(SampleLanguage::Go, "https://api.example.com");
+        assert!(go.contains("http.NewRequest(\"GET\", \"https://api.example.com/health\", nil)"));
+        assert!(!go.contains("strings"));
+        let rust = health.generate_sample(SampleLanguage::Rust, "https://api.example.com");
+        assert!(rust.contains(".get(\"https://api.example.com/health\")"));
+        assert!(!rust.contains(".json("));
+    }
+
+    #[test]
+    fn spec_samples_replace_and_extend_generated_ones() {
+        let samples = update_pet().request_samples("https://api.example.com");
+        let langs: Vec<&str> = samples.iter().map(|s| s.lang.as_str()).collect();
+        assert_eq!(
+            langs,
+            [
+                "curl",
+                "python",
+                "javascript",
+                "typescript",
+                "go",
+                "rust",
+                "php"
+            ]
+        );
+        assert_eq!(samples[0].label, "cURL (CLI)");
+        assert_eq!(samples[0].source, "pets update 7 --name Rex");
+        assert_eq!(samples[6].label, "PHP");
+    }
+}
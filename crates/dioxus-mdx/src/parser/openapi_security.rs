@@ -0,0 +1,227 @@
+//! How security schemes turn into request credentials.
+//!
+//! Request samples fill in a placeholder for the first security requirement
+//! of an operation; the playground fills in whatever the user typed.
+
+use super::openapi_types::{
+    ApiOperation, ParameterLocation, RequiredScheme, SecurityScheme, SecuritySchemeKind,
+};
+
+impl SecurityScheme {
+    /// Short human-readable name, e.g. "Bearer token (JWT)" or "API key".
+    pub fn label(&self) -> String {
+        match &self.kind {
+            SecuritySchemeKind::ApiKey { .. } => "API key".into(),
+            SecuritySchemeKind::Http {
+                scheme,
+                bearer_format,
+            } => match (scheme.as_str(), bearer_format) {
+                ("bearer", Some(format)) => format!("Bearer token ({format})"),
+                ("bearer", None) => "Bearer token".into(),
+                ("basic", _) => "HTTP Basic".into(),
+                (other, _) => format!("HTTP {other}"),
+            },
+            SecuritySchemeKind::OAuth2 { .. } => "OAuth 2.0".into(),
+            SecuritySchemeKind::OpenIdConnect { .. } => "OpenID Connect".into(),
+        }
+    }
+
+    /// Placeholder value used in request samples.
+    pub fn placeholder(&self) -> &'static str {
+        match &self.kind {
+            SecuritySchemeKind::ApiKey { .. } => "<api-key>",
+            SecuritySchemeKind::Http { scheme, .. } if scheme == "basic" => "<credentials>",
+            _ => "<token>",
+        }
+    }
+
+    /// Where and how `value` is sent: location, header/parameter name and
+    /// the full value. HTTP schemes and OAuth tokens go in `Authorization`.
+    pub fn credential(&self, value: &str) -> (ParameterLocation, String, String) {
+        match &self.kind {
+            SecuritySchemeKind::ApiKey { name, location } => {
+                (*location, name.clone(), value.to_string())
+            }
+            SecuritySchemeKind::Http { scheme, .. } => (
+                ParameterLocation::Header,
+                "Authorization".into(),
+                format!("{} {value}", capitalize(scheme)),
+            ),
+            SecuritySchemeKind::OAuth2 { .. } | SecuritySchemeKind::OpenIdConnect { .. } => (
+                ParameterLocation::Header,
+                "Authorization".into(),
+                format!("Bearer {value}"),
+            ),
+        }
+    }
+}
+
+impl ApiOperation {
+    /// Every scheme named by any of the operation's security requirements,
+    /// once each, in order of appearance.
+    pub fn auth_schemes(&self) -> Vec<&RequiredScheme> {
+        let mut schemes: Vec<&RequiredScheme> = Vec::new();
+        for required in self.security.iter().flat_map(|r| &r.schemes) {
+            if !schemes.iter().any(|s| s.name == required.name) {
+                schemes.push(required);
+            }
+        }
+        schemes
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Standard base64 with padding, for HTTP Basic credentials.
+pub(super) fn base64(input: &str) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::new();
+    for chunk in input.as_bytes().chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::openapi_parser::parse_openapi;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: {title: Secure, version: '1'}
+security:
+  - bearerAuth: []
+paths:
+  /me:
+    get:
+      operationId: getMe
+      responses:
+        '200': {description: OK}
+  /health:
+    get:
+      operationId: health
+      security: []
+      responses:
+        '200': {description: OK}
+  /pets:
+    post:
+      operationId: createPet
+      security:
+        - apiKey: []
+          oauth: [pets:write]
+        - bearerAuth: []
+      responses:
+        '201': {description: Created}
+components:
+  securitySchemes:
+    bearerAuth: {type: http, scheme: bearer, bearerFormat: JWT}
+    apiKey: {type: apiKey, in: query, name: api_key, description: Issued in the dashboard.}
+    oauth:
+      type: oauth2
+      flows:
+        authorizationCode:
+          authorizationUrl: https://auth.example.com/authorize
+          tokenUrl: https://auth.example.com/token
+          scopes:
+            pets:write: Modify pets
+"##;
+
+    fn op<'a>(spec: &'a crate::parser::OpenApiSpec, id: &str) -> &'a ApiOperation {
+        spec.operations
+            .iter()
+            .find(|op| op.operation_id.as_deref() == Some(id))
+            .unwrap()
+    }
+
+    #[test]
+    fn schemes_and_requirements_are_parsed() {
+        let spec = parse_openapi(SPEC).unwrap();
+        assert_eq!(spec.security_schemes.len(), 3);
+        assert_eq!(
+            spec.security_schemes["bearerAuth"].label(),
+            "Bearer token (JWT)"
+        );
+        let SecuritySchemeKind::OAuth2 { flows } = &spec.security_schemes["oauth"].kind else {
+            panic!("expected oauth2");
+        };
+        assert_eq!(flows[0].flow, "authorizationCode");
+        assert_eq!(
+            flows[0].scopes,
+            [("pets:write".into(), "Modify pets".into())]
+        );
+
+        // Inherited from the root, overridden with `[]`, or declared per operation.
+        assert_eq!(op(&spec, "getMe").security, spec.security);
+        assert!(op(&spec, "health").security.is_empty());
+        let create = op(&spec, "createPet");
+        assert_eq!(create.security.len(), 2);
+        assert_eq!(create.security[0].schemes[1].scopes, ["pets:write"]);
+        let names: Vec<&str> = create
+            .auth_schemes()
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names, ["apiKey", "oauth", "bearerAuth"]);
+    }
+
+    #[test]
+    fn curl_samples_carry_credentials_of_the_first_requirement() {
+        let spec = parse_openapi(SPEC).unwrap();
+        let curl = op(&spec, "getMe").generate_curl("https://api.example.com");
+        assert!(
+            curl.contains("-H \"Authorization: Bearer <token>\""),
+            "{curl}"
+        );
+
+        let curl = op(&spec, "createPet").generate_curl("https://api.example.com");
+        assert!(curl.contains("/pets?api_key=%3Capi-key%3E"), "{curl}");
+        assert!(curl.contains("Authorization: Bearer <token>"), "{curl}");
+
+        let curl = op(&spec, "health").generate_curl("https://api.example.com");
+        assert!(!curl.contains("Authorization"), "{curl}");
+    }
+
+    #[test]
+    fn playground_sends_entered_credentials() {
+        let spec = parse_openapi(SPEC).unwrap();
+        let credentials = [
+            ("apiKey".to_string(), "k 1".to_string()),
+            ("bearerAuth".into(), "tok".into()),
+            ("oauth".into(), "  ".into()),
+        ]
+        .into_iter()
+        .collect();
+        let request = op(&spec, "createPet")
+            .build_playground_request("http://localhost:4010", &[], &credentials, "")
+            .unwrap();
+        assert_eq!(request.url, "http://localhost:4010/pets?api_key=k%201");
+        assert_eq!(
+            request.headers,
+            [("Authorization".to_string(), "Bearer tok".to_string())]
+        );
+    }
+
+    #[test]
+    fn base64_pads_like_the_standard_alphabet() {
+        assert_eq!(base64("user:pass"), "dXNlcjpwYXNz");
+        assert_eq!(base64("ab"), "YWI=");
+        assert_eq!(base64("a"), "YQ==");
+    }
+}
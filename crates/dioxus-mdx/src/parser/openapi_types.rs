@@ -16,8 +16,20 @@ pub struct OpenApiSpec {
     pub operations: Vec<ApiOperation>,
     /// Unique tags in order of appearance.
     pub tags: Vec<ApiTag>,
+    /// Headings that nest tags (`x-tagGroups`), in spec order.
+    pub tag_groups: Vec<ApiTagGroup>,
     /// Reusable schema definitions.
     pub schemas: BTreeMap<String, SchemaDefinition>,
+    /// Security schemes from `components.securitySchemes`.
+    pub security_schemes: BTreeMap<String, SecurityScheme>,
+    /// Default security requirements, used by operations without their own.
+    pub security: Vec<SecurityRequirement>,
+    /// Whether the "Try it" playground may be shown (`x-playground: false`
+    /// at the root turns it off for the whole spec).
+    pub playground: bool,
+    /// Webhooks (`webhooks`, or `x-webhooks` before 3.1) in name order,
+    /// followed by the operations' callbacks.
+    pub webhooks: Vec<ApiWebhook>,
 }
 
 /// API metadata.
@@ -34,10 +46,44 @@ pub struct ApiInfo {
 /// Server configuration.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ApiServer {
-    /// Server URL.
+    /// Server URL, possibly templated with `{variable}`s.
     pub url: String,
     /// Server description.
     pub description: Option<String>,
+    /// Variables substituted into `url`, in spec order.
+    pub variables: Vec<ServerVariable>,
+}
+
+/// A `{variable}` in a server URL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerVariable {
+    /// Variable name, as written between the braces.
+    pub name: String,
+    /// Value used when none is chosen.
+    pub default: String,
+    /// Allowed values; empty when any value is allowed.
+    pub enum_values: Vec<String>,
+    /// Variable description.
+    pub description: Option<String>,
+}
+
+impl ApiServer {
+    /// The URL with each variable replaced by its value from `values`,
+    /// falling back to the variable's default.
+    pub fn resolve_url(&self, values: &BTreeMap<String, String>) -> String {
+        self.variables.iter().fold(self.url.clone(), |url, var| {
+            let value = values
+                .get(&var.name)
+                .filter(|v| !v.is_empty())
+                .unwrap_or(&var.default);
+            url.replace(&format!("{{{}}}", var.name), value)
+        })
+    }
+
+    /// Label for server pickers: the description, else the URL.
+    pub fn label(&self) -> &str {
+        self.description.as_deref().unwrap_or(&self.url)
+    }
 }
 
 /// Tag metadata.
@@ -49,6 +95,15 @@ pub struct ApiTag {
     pub description: Option<String>,
 }
 
+/// A heading that nests tags in navigation (an `x-tagGroups` entry).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiTagGroup {
+    /// Heading.
+    pub name: String,
+    /// Names of the tags under the heading, in display order.
+    pub tags: Vec<String>,
+}
+
 /// HTTP method.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HttpMethod {
@@ -139,94 +194,129 @@ pub struct ApiOperation {
     pub responses: Vec<ApiResponse>,
     /// Whether the endpoint is deprecated.
     pub deprecated: bool,
+    /// Hand-written request samples from `x-codeSamples` / `x-code-samples`.
+    pub code_samples: Vec<CodeSample>,
+    /// Alternative ways to authorize, any one of which is enough. Inherited
+    /// from the spec unless the operation declares its own; empty when the
+    /// endpoint needs no auth.
+    pub security: Vec<SecurityRequirement>,
+    /// Whether the "Try it" playground may be shown (`x-playground: false`
+    /// turns it off for this operation).
+    pub playground: bool,
+    /// Slug set with the `x-slug` extension, which pins the operation's URL
+    /// regardless of its operationId. Each segment is slugified, so
+    /// `Pets/List All` becomes `pets/list-all`.
+    pub custom_slug: Option<String>,
 }
 
-impl ApiOperation {
-    /// Generate a URL-friendly slug for this operation.
-    ///
-    /// Uses `operation_id` if present (camelCase → kebab-case), otherwise
-    /// falls back to `method-path` format.
-    pub fn slug(&self) -> String {
-        if let Some(op_id) = &self.operation_id {
-            slugify_operation_id(op_id)
-        } else {
-            // Fallback: method-path format
-            let path_slug = self
-                .path
-                .trim_matches('/')
-                .replace('/', "-")
-                .replace(['{', '}'], "");
-            format!("{}-{}", self.method.as_str().to_lowercase(), path_slug)
-        }
-    }
+/// A request code sample for one language.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeSample {
+    /// Language id, e.g. `"python"`; also picks the syntax highlighting.
+    pub lang: String,
+    /// Label shown in the language switcher.
+    pub label: String,
+    /// Sample source code.
+    pub source: String,
+}
 
-    /// Generate a curl command for this endpoint.
-    pub fn generate_curl(&self, base_url: &str) -> String {
-        let mut parts = vec!["curl".to_string()];
+/// A security scheme from `components.securitySchemes`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityScheme {
+    /// How credentials are passed.
+    pub kind: SecuritySchemeKind,
+    /// Scheme description.
+    pub description: Option<String>,
+}
 
-        // Method
-        if !matches!(self.method, HttpMethod::Get) {
-            parts.push(format!("-X {}", self.method.as_str()));
-        }
+/// Security scheme type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SecuritySchemeKind {
+    /// API key in a header, query parameter or cookie.
+    ApiKey {
+        name: String,
+        location: ParameterLocation,
+    },
+    /// HTTP authentication, e.g. `bearer` or `basic`.
+    Http {
+        scheme: String,
+        bearer_format: Option<String>,
+    },
+    /// OAuth 2.0 with one or more flows.
+    OAuth2 { flows: Vec<OAuthFlow> },
+    /// OpenID Connect discovery.
+    OpenIdConnect { url: String },
+}
 
-        // Build URL with path params
-        let mut url = format!("{}{}", base_url.trim_end_matches('/'), self.path);
-        let mut query_parts = Vec::new();
-
-        for param in &self.parameters {
-            match param.location {
-                ParameterLocation::Path => {
-                    let placeholder = if let Some(schema) = &param.schema {
-                        let val = schema.generate_example_json(0);
-                        val.as_str()
-                            .map(|s| s.to_string())
-                            .unwrap_or_else(|| val.to_string())
-                    } else {
-                        format!("{{{}}}", param.name)
-                    };
-                    url = url.replace(&format!("{{{}}}", param.name), &placeholder);
-                }
-                ParameterLocation::Query => {
-                    if let Some(schema) = &param.schema {
-                        let val = schema.generate_example_json(0);
-                        let val_str = val
-                            .as_str()
-                            .map(|s| s.to_string())
-                            .unwrap_or_else(|| val.to_string());
-                        query_parts.push(format!("{}={}", param.name, val_str));
-                    }
-                }
-                _ => {}
-            }
-        }
+/// One OAuth 2.0 flow.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuthFlow {
+    /// Flow name as in the spec: `authorizationCode`, `clientCredentials`,
+    /// `implicit` or `password`.
+    pub flow: String,
+    /// Authorization URL (`implicit`, `authorizationCode`).
+    pub authorization_url: Option<String>,
+    /// Token URL (`password`, `clientCredentials`, `authorizationCode`).
+    pub token_url: Option<String>,
+    /// Available scopes and their descriptions.
+    pub scopes: Vec<(String, String)>,
+}
 
-        if !query_parts.is_empty() {
-            url = format!("{}?{}", url, query_parts.join("&"));
-        }
+/// One way to authorize a request: every listed scheme must be satisfied.
+/// No schemes means auth is optional.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityRequirement {
+    pub schemes: Vec<RequiredScheme>,
+}
 
-        parts.push(format!("\"{}\"", url));
+/// A scheme named by a security requirement, resolved against the spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequiredScheme {
+    /// Key in `components.securitySchemes`.
+    pub name: String,
+    /// The resolved scheme.
+    pub scheme: SecurityScheme,
+    /// OAuth 2.0 / OpenID Connect scopes the operation needs.
+    pub scopes: Vec<String>,
+}
 
-        // Content-Type header if there's a request body
-        if self.request_body.is_some() {
-            parts.push("-H \"Content-Type: application/json\"".to_string());
+impl ApiOperation {
+    /// Generate a URL-friendly slug for this operation.
+    ///
+    /// Uses `x-slug` if set, else `operation_id` (camelCase → kebab-case),
+    /// else falls back to `method-path` format.
+    pub fn slug(&self) -> String {
+        match &self.custom_slug {
+            Some(slug) => slug.clone(),
+            None => self.derived_slugs()[0].clone(),
         }
+    }
 
-        // Request body
-        if let Some(body) = &self.request_body {
-            for content in &body.content {
-                if content.media_type.contains("json") {
-                    if let Some(schema) = &content.schema {
-                        let example = schema.generate_example_json(0);
-                        if let Ok(pretty) = serde_json::to_string_pretty(&example) {
-                            parts.push(format!("-d '{}'", pretty));
-                        }
-                    }
-                    break;
-                }
-            }
-        }
+    /// Other slugs the operation is known by: the forms derived from its
+    /// operationId and from its method and path, minus [`Self::slug`].
+    ///
+    /// Links to these can be redirected to the current page when `x-slug` is
+    /// added or an operationId is introduced.
+    pub fn previous_slugs(&self) -> Vec<String> {
+        let slug = self.slug();
+        let mut previous = self.derived_slugs();
+        previous.retain(|s| *s != slug);
+        previous.dedup();
+        previous
+    }
 
-        parts.join(" \\\n  ")
+    /// Slugs derived from the operationId, if any, then from `method-path`.
+    fn derived_slugs(&self) -> Vec<String> {
+        let path_slug = self
+            .path
+            .trim_matches('/')
+            .replace('/', "-")
+            .replace(['{', '}'], "");
+        let method_path = format!("{}-{}", self.method.as_str().to_lowercase(), path_slug);
+        match &self.operation_id {
+            Some(op_id) => vec![slugify_operation_id(op_id), method_path],
+            None => vec![method_path],
+        }
     }
 
     /// Generate a response example from the first 2xx response.
@@ -248,6 +338,105 @@ impl ApiOperation {
         }
         None
     }
+
+    /// Response examples for the first 2xx response with content.
+    ///
+    /// Named `examples` from the spec come first; without them the media
+    /// type's single `example` is used, and only then one generated from the
+    /// schema (as in [`Self::generate_response_example`]).
+    pub fn response_examples(&self) -> Option<ResponseExamples> {
+        self.responses
+            .iter()
+            .filter(|response| response.status_code.starts_with('2'))
+            .flat_map(|response| response.content.iter().map(move |c| (response, c)))
+            .find_map(|(response, content)| {
+                let mut examples: Vec<(String, String)> = content
+                    .examples
+                    .iter()
+                    .filter_map(|e| Some((e.label().to_string(), e.value.clone()?)))
+                    .collect();
+                if examples.is_empty() {
+                    let single = content.example.clone().or_else(|| {
+                        let schema = content.schema.as_ref()?;
+                        serde_json::to_string_pretty(&schema.generate_example_json(0)).ok()
+                    })?;
+                    examples.push(("Example".to_string(), single));
+                }
+                Some(ResponseExamples {
+                    status_code: response.status_code.clone(),
+                    media_type: content.media_type.clone(),
+                    examples,
+                })
+            })
+    }
+}
+
+/// Examples of one response, as picked by [`ApiOperation::response_examples`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseExamples {
+    /// Status code of the response.
+    pub status_code: String,
+    /// Media type the examples are for.
+    pub media_type: String,
+    /// Example labels and values.
+    pub examples: Vec<(String, String)>,
+}
+
+/// A request the API sends to the consumer: a webhook, or a callback of
+/// one of its operations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiWebhook {
+    /// Webhook or callback name, the key it is declared under.
+    pub name: String,
+    /// Where the webhook was declared.
+    pub kind: WebhookKind,
+    /// The requests sent, one per method. For callbacks, `path` holds the
+    /// callback URL expression (e.g. `{$request.body#/callbackUrl}`); for
+    /// webhooks it is the webhook name.
+    pub operations: Vec<ApiOperation>,
+}
+
+/// Whether an [`ApiWebhook`] is a top-level webhook or an operation callback.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookKind {
+    /// Declared under `webhooks` / `x-webhooks`.
+    Webhook,
+    /// Declared under an operation's `callbacks`.
+    Callback {
+        /// Slug of the operation that registers the callback.
+        operation: String,
+    },
+}
+
+impl ApiWebhook {
+    /// URL slug, relative to the spec's `webhooks/` section.
+    ///
+    /// Webhook names are kebab-cased like operation IDs; callbacks are
+    /// prefixed with the slug of their operation, since callback names only
+    /// need to be unique per operation.
+    pub fn slug(&self) -> String {
+        match &self.kind {
+            WebhookKind::Webhook => slugify_operation_id(&self.name),
+            WebhookKind::Callback { operation } => {
+                format!("{operation}-{}", slugify_operation_id(&self.name))
+            }
+        }
+    }
+
+    /// Display title: the first operation's summary, else the name.
+    pub fn title(&self) -> String {
+        self.operations
+            .iter()
+            .find_map(|op| op.summary.clone())
+            .unwrap_or_else(|| self.name.clone())
+    }
+
+    /// Description of the first operation that has one.
+    pub fn description(&self) -> Option<&str> {
+        self.operations
+            .iter()
+            .find_map(|op| op.description.as_deref())
+    }
 }
 
 /// Convert a camelCase operation ID to kebab-case slug.
@@ -343,6 +532,46 @@ pub struct MediaTypeContent {
     pub schema: Option<SchemaDefinition>,
     /// Example value.
     pub example: Option<String>,
+    /// Named examples from the `examples` map, in spec order.
+    pub examples: Vec<NamedExample>,
+}
+
+/// A named entry of a media type's `examples` map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedExample {
+    /// Key in the `examples` map.
+    pub name: String,
+    /// Short summary.
+    pub summary: Option<String>,
+    /// Long description.
+    pub description: Option<String>,
+    /// Example value (pretty JSON, or the raw string); `None` when only an
+    /// `externalValue` is given.
+    pub value: Option<String>,
+    /// URL of an externally hosted example.
+    pub external_value: Option<String>,
+}
+
+impl NamedExample {
+    /// Label for an example picker: the summary, else the name.
+    pub fn label(&self) -> &str {
+        self.summary.as_deref().unwrap_or(&self.name)
+    }
+}
+
+/// A response link to a follow-up operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseLink {
+    /// Key in the `links` map.
+    pub name: String,
+    /// Link description.
+    pub description: Option<String>,
+    /// Target operation by `operationId`.
+    pub operation_id: Option<String>,
+    /// Target operation by reference, when not given by id.
+    pub operation_ref: Option<String>,
+    /// Target parameter names and the runtime expressions (or values) that fill them.
+    pub parameters: Vec<(String, String)>,
 }
 
 /// API response definition.
@@ -354,6 +583,10 @@ pub struct ApiResponse {
     pub description: String,
     /// Content by media type.
     pub content: Vec<MediaTypeContent>,
+    /// Response headers.
+    pub headers: Vec<ApiParameter>,
+    /// Links to follow-up operations.
+    pub links: Vec<ResponseLink>,
 }
 
 impl ApiResponse {
@@ -415,7 +648,7 @@ pub struct SchemaDefinition {
     pub required: Vec<String>,
     /// Reference name (for $ref).
     pub ref_name: Option<String>,
-    /// Enum values.
+    /// Enum values, as text for non-string schemas (`1`, `true`).
     pub enum_values: Vec<String>,
     /// Example value.
     pub example: Option<String>,
@@ -431,6 +664,55 @@ pub struct SchemaDefinition {
     pub any_of: Vec<SchemaDefinition>,
     /// AllOf schemas.
     pub all_of: Vec<SchemaDefinition>,
+    /// Schema the value must not match.
+    pub not: Option<Box<SchemaDefinition>>,
+    /// Property that tells `oneOf`/`anyOf` variants (or `allOf` subtypes) apart.
+    pub discriminator: Option<Discriminator>,
+}
+
+/// Discriminator of a polymorphic schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Discriminator {
+    /// Name of the property holding the variant's value.
+    pub property_name: String,
+    /// Property values and the schema names they select, in spec order.
+    pub mapping: Vec<(String, String)>,
+}
+
+impl Discriminator {
+    /// Discriminator value that selects `schema`: its key in `mapping`, else
+    /// a single `enum` value of the property, else the schema's own name.
+    pub fn value_for(&self, schema: &SchemaDefinition) -> Option<String> {
+        if let Some(name) = &schema.ref_name
+            && let Some((value, _)) = self.mapping.iter().find(|(_, target)| target == name)
+        {
+            return Some(value.clone());
+        }
+        let property = schema
+            .flattened_properties()
+            .into_iter()
+            .find(|p| p.name == self.property_name);
+        if let Some(property) = property
+            && let [value] = property.schema.enum_values.as_slice()
+        {
+            return Some(value.clone());
+        }
+        schema.ref_name.clone()
+    }
+}
+
+/// A property of a schema once `allOf` branches are merged in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlattenedProperty {
+    /// Property name.
+    pub name: String,
+    /// Property schema.
+    pub schema: SchemaDefinition,
+    /// Whether any merged branch requires it.
+    pub required: bool,
+    /// Name of the referenced `allOf` schema it comes from; `None` for the
+    /// schema's own (or inline `allOf`) properties.
+    pub source: Option<String>,
 }
 
 impl Default for SchemaDefinition {
@@ -451,6 +733,8 @@ impl Default for SchemaDefinition {
             one_of: Vec::new(),
             any_of: Vec::new(),
             all_of: Vec::new(),
+            not: None,
+            discriminator: None,
         }
     }
 }
@@ -487,6 +771,83 @@ impl SchemaDefinition {
             || !self.one_of.is_empty()
             || !self.any_of.is_empty()
             || !self.all_of.is_empty()
+            || self.not.is_some()
+    }
+
+    /// Properties of this schema and its `allOf` branches, base schemas first.
+    /// A property defined twice keeps its position but takes the later schema.
+    pub fn flattened_properties(&self) -> Vec<FlattenedProperty> {
+        let mut out = Vec::new();
+        self.collect_properties(None, &mut out);
+        out
+    }
+
+    fn collect_properties(&self, source: Option<&str>, out: &mut Vec<FlattenedProperty>) {
+        for branch in &self.all_of {
+            branch.collect_properties(branch.ref_name.as_deref().or(source), out);
+        }
+        for (name, schema) in &self.properties {
+            let property = FlattenedProperty {
+                name: name.clone(),
+                schema: schema.clone(),
+                required: false,
+                source: source.map(str::to_string),
+            };
+            match out.iter_mut().find(|p| &p.name == name) {
+                Some(existing) => {
+                    let required = existing.required;
+                    *existing = property;
+                    existing.required = required;
+                }
+                None => out.push(property),
+            }
+        }
+        for name in &self.required {
+            if let Some(property) = out.iter_mut().find(|p| &p.name == name) {
+                property.required = true;
+            }
+        }
+    }
+
+    /// This schema with its `allOf` branches merged into one object schema.
+    /// The first discriminator found (own, then branches) is kept.
+    pub fn merge_all_of(&self) -> SchemaDefinition {
+        let properties = self.flattened_properties();
+        let mut branches = std::iter::once(self).chain(&self.all_of);
+        SchemaDefinition {
+            schema_type: SchemaType::Object,
+            description: branches.clone().find_map(|b| b.description.clone()),
+            required: properties
+                .iter()
+                .filter(|p| p.required)
+                .map(|p| p.name.clone())
+                .collect(),
+            properties: properties.into_iter().map(|p| (p.name, p.schema)).collect(),
+            discriminator: branches.find_map(|b| b.discriminator.clone()),
+            all_of: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    /// `oneOf` (or else `anyOf`) branches with a label each: the
+    /// discriminator value, the referenced schema name, or "Option N".
+    pub fn variants(&self) -> Vec<(String, &SchemaDefinition)> {
+        let branches = if self.one_of.is_empty() {
+            &self.any_of
+        } else {
+            &self.one_of
+        };
+        branches
+            .iter()
+            .enumerate()
+            .map(|(i, branch)| {
+                let label = match &self.discriminator {
+                    Some(discriminator) => discriminator.value_for(branch),
+                    None => branch.ref_name.clone(),
+                };
+                (label.unwrap_or_else(|| format!("Option {}", i + 1)), branch)
+            })
+            .collect()
     }
 
     /// Generate example JSON for this schema.
@@ -506,6 +867,29 @@ impl SchemaDefinition {
             return json!(example);
         }
 
+        // Merge `allOf` first, then fill in the discriminator this schema is
+        // selected by, if a base schema declares one.
+        if !self.all_of.is_empty() {
+            let merged = self.merge_all_of();
+            let mut value = merged.generate_example_json(depth);
+            if let Some(discriminator) = &merged.discriminator
+                && self.ref_name.is_some()
+            {
+                set_discriminator(&mut value, discriminator, self);
+            }
+            return value;
+        }
+
+        // Polymorphic: use the first variant, tagged with its discriminator
+        // value so the example matches the variant it shows.
+        if let Some((_, variant)) = self.variants().first() {
+            let mut value = variant.generate_example_json(depth + 1);
+            if let Some(discriminator) = &self.discriminator {
+                set_discriminator(&mut value, discriminator, variant);
+            }
+            return value;
+        }
+
         match &self.schema_type {
             SchemaType::String => {
                 if !self.enum_values.is_empty() {
@@ -521,6 +905,9 @@ impl SchemaDefinition {
                 }
             }
             SchemaType::Integer => {
+                if let Some(n) = self.enum_values.first().and_then(|v| v.parse::<i64>().ok()) {
+                    return json!(n);
+                }
                 if let Some(default) = &self.default
                     && let Ok(n) = default.parse::<i64>()
                 {
@@ -528,8 +915,17 @@ impl SchemaDefinition {
                 }
                 json!(0)
             }
-            SchemaType::Number => json!(0.0),
-            SchemaType::Boolean => json!(true),
+            SchemaType::Number => {
+                let n = self.enum_values.first().and_then(|v| v.parse::<f64>().ok());
+                json!(n.unwrap_or(0.0))
+            }
+            SchemaType::Boolean => {
+                let b = self
+                    .enum_values
+                    .first()
+                    .and_then(|v| v.parse::<bool>().ok());
+                json!(b.unwrap_or(true))
+            }
             SchemaType::Array => {
                 if let Some(items) = &self.items {
                     json!([items.generate_example_json(depth + 1)])
@@ -553,6 +949,18 @@ impl SchemaDefinition {
     }
 }
 
+/// Set `discriminator`'s property in an object example to the value selecting
+/// `schema`.
+fn set_discriminator(
+    value: &mut serde_json::Value,
+    discriminator: &Discriminator,
+    schema: &SchemaDefinition,
+) {
+    if let (Some(object), Some(tag)) = (value.as_object_mut(), discriminator.value_for(schema)) {
+        object.insert(discriminator.property_name.clone(), json!(tag));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -616,6 +1024,43 @@ paths:
         assert_eq!(find_op(&spec, "/health").slug(), "get-health");
     }
 
+    #[test]
+    fn x_slug_overrides_derived_slugs_which_become_previous() {
+        let spec = parse_openapi(&SPEC.replace(
+            "operationId: createUserPost",
+            "operationId: createUserPost\n      x-slug: /create-post",
+        ))
+        .unwrap();
+        let op = find_op(&spec, "/users/{id}/posts");
+        assert_eq!(op.slug(), "create-post");
+        assert_eq!(
+            op.previous_slugs(),
+            ["create-user-post", "post-users-id-posts"]
+        );
+
+        let health = find_op(&spec, "/health");
+        assert_eq!(health.custom_slug, None);
+        assert!(health.previous_slugs().is_empty());
+    }
+
+    #[test]
+    fn x_slug_segments_are_slugified() {
+        let slug_for = |x_slug: &str| {
+            let spec = parse_openapi(&SPEC.replace(
+                "operationId: createUserPost",
+                &format!("operationId: createUserPost\n      x-slug: \"{x_slug}\""),
+            ))
+            .unwrap();
+            find_op(&spec, "/users/{id}/posts").custom_slug.clone()
+        };
+        assert_eq!(
+            slug_for("Posts/ Create A Post!//../v2"),
+            Some("posts/create-a-post/v2".to_string())
+        );
+        assert_eq!(slug_for("../"), None);
+        assert_eq!(slug_for(""), None);
+    }
+
     #[test]
     fn generate_curl_includes_method_url_headers_and_body() {
         let spec = parse_openapi(SPEC).unwrap();
@@ -679,4 +1124,128 @@ paths:
         };
         assert_eq!(schema.generate_example_json(0), json!(42));
     }
+
+    const POLYMORPHIC: &str = r##"
+openapi: "3.0.0"
+info: {title: Pets, version: "1"}
+paths: {}
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - $ref: '#/components/schemas/Cat'
+        - $ref: '#/components/schemas/Dog'
+      discriminator:
+        propertyName: petType
+        mapping:
+          cat: '#/components/schemas/Cat'
+    PetBase:
+      type: object
+      required: [petType, name]
+      properties:
+        petType: {type: string}
+        name: {type: string, example: Tom}
+    Cat:
+      allOf:
+        - $ref: '#/components/schemas/PetBase'
+        - type: object
+          properties:
+            meows: {type: boolean}
+    Dog:
+      type: object
+      properties:
+        petType: {type: string, enum: [dog]}
+        barks: {type: boolean}
+    NotNull:
+      not: {type: string}
+"##;
+
+    #[test]
+    fn discriminator_labels_variants_from_mapping_or_enum() {
+        let spec = parse_openapi(POLYMORPHIC).unwrap();
+        let pet = &spec.schemas["Pet"];
+        let discriminator = pet.discriminator.as_ref().unwrap();
+        assert_eq!(discriminator.property_name, "petType");
+        assert_eq!(discriminator.mapping, [("cat".into(), "Cat".into())]);
+        let labels: Vec<String> = pet.variants().into_iter().map(|(l, _)| l).collect();
+        assert_eq!(labels, ["cat", "dog"]);
+    }
+
+    #[test]
+    fn all_of_flattens_properties_with_their_source() {
+        let spec = parse_openapi(POLYMORPHIC).unwrap();
+        let properties = spec.schemas["Cat"].flattened_properties();
+        let view: Vec<(&str, bool, Option<&str>)> = properties
+            .iter()
+            .map(|p| (p.name.as_str(), p.required, p.source.as_deref()))
+            .collect();
+        assert_eq!(
+            view,
+            [
+                ("name", true, Some("PetBase")),
+                ("petType", true, Some("PetBase")),
+                ("meows", false, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn polymorphic_examples_use_one_coherent_variant() {
+        let spec = parse_openapi(POLYMORPHIC).unwrap();
+        assert_eq!(
+            spec.schemas["Pet"].generate_example_json(0),
+            json!({ "petType": "cat", "name": "Tom", "meows": true })
+        );
+        assert_eq!(
+            spec.schemas["Cat"].generate_example_json(0),
+            json!({ "petType": "string", "name": "Tom", "meows": true })
+        );
+    }
+
+    #[test]
+    fn not_schemas_are_kept() {
+        let spec = parse_openapi(POLYMORPHIC).unwrap();
+        let not = spec.schemas["NotNull"].not.as_ref().unwrap();
+        assert_eq!(not.schema_type, SchemaType::String);
+    }
+
+    #[test]
+    fn response_examples_prefer_named_spec_examples() {
+        let spec = parse_openapi(
+            r#"
+openapi: "3.0.0"
+info: {title: T, version: "1"}
+paths:
+  /a:
+    get:
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema: {type: object, properties: {id: {type: integer}}}
+              examples:
+                one: {value: {id: 1}}
+                two: {summary: Second, value: {id: 2}}
+  /b:
+    get:
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema: {type: object, properties: {id: {type: integer}}}
+"#,
+        )
+        .unwrap();
+
+        let named = find_op(&spec, "/a").response_examples().unwrap();
+        assert_eq!(named.status_code, "200");
+        let labels: Vec<&str> = named.examples.iter().map(|(l, _)| l.as_str()).collect();
+        assert_eq!(labels, ["one", "Second"]);
+
+        let generated = find_op(&spec, "/b").response_examples().unwrap();
+        assert_eq!(generated.examples.len(), 1);
+        assert!(generated.examples[0].1.contains("\"id\": 0"));
+    }
 }
@@ -0,0 +1,486 @@
+//! Checks that a spec's examples, and the examples generated from its
+//! schemas, match those schemas: types, required properties, enums and
+//! string formats.
+//!
+//! Examples are kept as text by the parser (strings raw, other values as
+//! JSON), so a value is read back the way [`SchemaDefinition::generate_example_json`]
+//! reads a schema's own example.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use super::openapi_types::{
+    ApiOperation, MediaTypeContent, OpenApiSpec, SchemaDefinition, SchemaType,
+};
+
+/// Nesting depth after which values aren't checked; the example generator
+/// stops at the same depth with an empty object.
+const MAX_DEPTH: usize = 6;
+
+/// A value that doesn't match its schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+    /// JSON path of the value, e.g. `$.owner.tags[1]`.
+    pub path: String,
+    /// What's wrong with it.
+    pub message: String,
+}
+
+/// An example in a spec that doesn't match its schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleViolation {
+    /// Operation the example belongs to, e.g. `GET /pets`, or `schema Pet`
+    /// for component schemas.
+    pub operation: String,
+    /// Which example, e.g. `200 response example "ok"`.
+    pub example: String,
+    /// JSON path of the offending value within the example.
+    pub path: String,
+    /// What's wrong with it.
+    pub message: String,
+}
+
+impl fmt::Display for ExampleViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} at {}: {}",
+            self.operation, self.example, self.path, self.message
+        )
+    }
+}
+
+impl SchemaDefinition {
+    /// Check `value` against the schema. Properties the schema doesn't
+    /// declare are allowed, as with JSON Schema's default
+    /// `additionalProperties`.
+    pub fn validate(&self, value: &Value) -> Vec<SchemaViolation> {
+        let mut out = Vec::new();
+        check(self, value, "$", 0, &mut out);
+        out
+    }
+}
+
+impl OpenApiSpec {
+    /// Check every example in the spec against its schema: parameter
+    /// examples, request and response body `example`s and named `examples`
+    /// (webhooks included), and the examples generated for bodies and
+    /// component schemas, which show up on pages when the spec has none.
+    pub fn validate_examples(&self) -> Vec<ExampleViolation> {
+        let mut out = Vec::new();
+        let operations = self
+            .operations
+            .iter()
+            .chain(self.webhooks.iter().flat_map(|hook| &hook.operations));
+        for op in operations {
+            validate_operation(op, &mut out);
+        }
+        for (name, schema) in &self.schemas {
+            let subject = format!("schema {name}");
+            let generated = schema.generate_example_json(0);
+            report(&mut out, &subject, "generated example", schema, &generated);
+        }
+        out
+    }
+}
+
+fn validate_operation(op: &ApiOperation, out: &mut Vec<ExampleViolation>) {
+    let subject = format!("{} {}", op.method.as_str(), op.path);
+
+    for param in &op.parameters {
+        if let (Some(schema), Some(example)) = (&param.schema, &param.example) {
+            let label = format!(
+                "{} parameter \"{}\" example",
+                param.location.as_str(),
+                param.name
+            );
+            report(
+                out,
+                &subject,
+                &label,
+                schema,
+                &example_value(example, schema),
+            );
+        }
+    }
+
+    if let Some(body) = &op.request_body {
+        validate_content(&body.content, &subject, "request body", out);
+    }
+    for response in &op.responses {
+        let label = format!("{} response", response.status_code);
+        validate_content(&response.content, &subject, &label, out);
+    }
+}
+
+/// Check the examples of each JSON media type, and the one generated from
+/// its schema.
+fn validate_content(
+    content: &[MediaTypeContent],
+    subject: &str,
+    label: &str,
+    out: &mut Vec<ExampleViolation>,
+) {
+    for media in content.iter().filter(|c| c.media_type.contains("json")) {
+        let Some(schema) = &media.schema else {
+            continue;
+        };
+        if let Some(example) = &media.example {
+            let value = example_value(example, schema);
+            report(out, subject, &format!("{label} example"), schema, &value);
+        }
+        for named in &media.examples {
+            if let Some(example) = &named.value {
+                let value = example_value(example, schema);
+                let which = format!("{label} example \"{}\"", named.name);
+                report(out, subject, &which, schema, &value);
+            }
+        }
+        let generated = schema.generate_example_json(0);
+        report(
+            out,
+            subject,
+            &format!("generated {label} example"),
+            schema,
+            &generated,
+        );
+    }
+}
+
+fn report(
+    out: &mut Vec<ExampleViolation>,
+    subject: &str,
+    example: &str,
+    schema: &SchemaDefinition,
+    value: &Value,
+) {
+    out.extend(
+        schema
+            .validate(value)
+            .into_iter()
+            .map(|violation| ExampleViolation {
+                operation: subject.to_string(),
+                example: example.to_string(),
+                path: violation.path,
+                message: violation.message,
+            }),
+    );
+}
+
+/// Read an example back from the parser's text form. Strings are stored
+/// raw, so text a string schema expects stays a string even if it parses as
+/// a number (`"42"`).
+fn example_value(text: &str, schema: &SchemaDefinition) -> Value {
+    match serde_json::from_str::<Value>(text) {
+        Ok(value) if schema.schema_type != SchemaType::String || value.is_string() => value,
+        _ => Value::String(text.to_string()),
+    }
+}
+
+fn check(
+    schema: &SchemaDefinition,
+    value: &Value,
+    path: &str,
+    depth: usize,
+    out: &mut Vec<SchemaViolation>,
+) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+    let mut violation = |message: String| {
+        out.push(SchemaViolation {
+            path: path.to_string(),
+            message,
+        })
+    };
+
+    if value.is_null() {
+        if !schema.nullable && !matches!(schema.schema_type, SchemaType::Null | SchemaType::Any) {
+            violation(format!("expected {}, found null", schema.display_type()));
+        }
+        return;
+    }
+
+    let variants = if schema.one_of.is_empty() {
+        &schema.any_of
+    } else {
+        &schema.one_of
+    };
+    if !variants.is_empty() {
+        if !variants.iter().any(|v| v.validate(value).is_empty()) {
+            violation(format!("matches none of the {} variants", variants.len()));
+        }
+        return;
+    }
+
+    if !schema.all_of.is_empty() {
+        check(&schema.merge_all_of(), value, path, depth, out);
+        return;
+    }
+
+    let type_ok = match schema.schema_type {
+        SchemaType::String => value.is_string(),
+        SchemaType::Integer => value.as_f64().is_some_and(|n| n.fract() == 0.0),
+        SchemaType::Number => value.is_number(),
+        SchemaType::Boolean => value.is_boolean(),
+        SchemaType::Array => value.is_array(),
+        SchemaType::Object => value.is_object(),
+        SchemaType::Null => false,
+        SchemaType::Any => true,
+    };
+    if !type_ok {
+        violation(format!(
+            "expected {}, found {}",
+            schema.display_type(),
+            json_type(value)
+        ));
+        return;
+    }
+
+    if !schema.enum_values.is_empty()
+        && !schema
+            .enum_values
+            .iter()
+            .any(|allowed| enum_matches(value, allowed))
+    {
+        violation(format!(
+            "{value} is not one of {}",
+            schema.enum_values.join(", ")
+        ));
+    }
+
+    if let Some(text) = value.as_str()
+        && let Some(format) = &schema.format
+        && !matches_format(format, text)
+    {
+        violation(format!("\"{text}\" is not a valid {format}"));
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(item_schema) = &schema.items {
+            for (i, item) in items.iter().enumerate() {
+                check(item_schema, item, &format!("{path}[{i}]"), depth + 1, out);
+            }
+        }
+        return;
+    }
+
+    if let Some(object) = value.as_object() {
+        for name in &schema.required {
+            if !object.contains_key(name) {
+                out.push(SchemaViolation {
+                    path: path.to_string(),
+                    message: format!("missing required property \"{name}\""),
+                });
+            }
+        }
+        for (name, field) in object {
+            let field_schema = schema
+                .properties
+                .get(name)
+                .or(schema.additional_properties.as_deref());
+            if let Some(field_schema) = field_schema {
+                check(
+                    field_schema,
+                    field,
+                    &format!("{path}.{name}"),
+                    depth + 1,
+                    out,
+                );
+            }
+        }
+    }
+}
+
+/// Whether `value` is the enum value `allowed`, which non-string schemas
+/// keep as text: numbers compare numerically, so `2.0` matches `2`.
+fn enum_matches(value: &Value, allowed: &str) -> bool {
+    match value {
+        Value::String(text) => text == allowed,
+        Value::Number(n) => allowed.parse::<f64>().ok() == n.as_f64(),
+        Value::Bool(b) => allowed.parse::<bool>() == Ok(*b),
+        _ => false,
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Whether `text` looks like a valid `format` value. Formats without a check
+/// here always pass.
+fn matches_format(format: &str, text: &str) -> bool {
+    match format {
+        "date" => is_date(text),
+        "date-time" => {
+            text.len() > 11
+                && is_date(&text[..10])
+                && matches!(&text[10..11], "T" | "t" | " ")
+                && text[11..].starts_with(|c: char| c.is_ascii_digit())
+        }
+        "email" => text
+            .split_once('@')
+            .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.')),
+        "uuid" => {
+            let groups: Vec<_> = text.split('-').map(str::len).collect();
+            groups == [8, 4, 4, 4, 12] && text.chars().all(|c| c == '-' || c.is_ascii_hexdigit())
+        }
+        "uri" | "url" => text
+            .split_once(':')
+            .is_some_and(|(scheme, rest)| !scheme.is_empty() && !rest.is_empty()),
+        "ipv4" => {
+            let parts: Vec<_> = text.split('.').collect();
+            parts.len() == 4 && parts.iter().all(|p| p.parse::<u8>().is_ok())
+        }
+        _ => true,
+    }
+}
+
+/// `YYYY-MM-DD`.
+fn is_date(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes
+            .iter()
+            .enumerate()
+            .all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::openapi_parser::parse_openapi;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: {title: Pets, version: '1'}
+paths:
+  /pets:
+    get:
+      parameters:
+        - {name: limit, in: query, schema: {type: integer}, example: ten}
+        - {name: code, in: query, schema: {type: string}, example: '42'}
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items: {$ref: '#/components/schemas/Pet'}
+              examples:
+                ok:
+                  value: [{id: 1, name: Rex, status: sold}]
+                broken:
+                  value: [{name: 7, status: lost, born: 'yesterday'}]
+components:
+  schemas:
+    Pet:
+      type: object
+      required: [id, name]
+      properties:
+        id: {type: integer}
+        name: {type: string}
+        status: {type: string, enum: [available, sold]}
+        born: {type: string, format: date}
+"##;
+
+    fn lines(spec: &str) -> Vec<String> {
+        parse_openapi(spec)
+            .unwrap()
+            .validate_examples()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn reports_each_violation_with_operation_and_path() {
+        assert_eq!(
+            lines(SPEC),
+            [
+                "GET /pets: query parameter \"limit\" example at $: expected integer, found string",
+                "GET /pets: 200 response example \"broken\" at $[0]: missing required property \"id\"",
+                "GET /pets: 200 response example \"broken\" at $[0].born: \"yesterday\" is not a valid date",
+                "GET /pets: 200 response example \"broken\" at $[0].name: expected string, found integer",
+                "GET /pets: 200 response example \"broken\" at $[0].status: \"lost\" is not one of available, sold",
+            ]
+        );
+    }
+
+    #[test]
+    fn generated_examples_match_their_schemas() {
+        let spec = SPEC
+            .replace("example: ten", "example: 10")
+            .replace("[{name: 7, status: lost, born: 'yesterday'}]", "[]");
+        assert!(lines(&spec).is_empty(), "{:?}", lines(&spec));
+    }
+
+    #[test]
+    fn integer_and_boolean_enums_are_checked() {
+        let text = SPEC.replace(
+            "        born: {type: string, format: date}",
+            "        born: {type: string, format: date}
+        level: {type: integer, enum: [1, 2]}
+        weight: {type: number, enum: [0.5, 2]}
+        vaccinated: {type: boolean, enum: [true]}",
+        );
+        let spec = parse_openapi(&text).unwrap();
+        let pet = &spec.schemas["Pet"];
+        let pet_with = |extra: serde_json::Value| {
+            let mut value = serde_json::json!({"id": 1, "name": "Rex"});
+            value
+                .as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            pet.validate(&value)
+                .iter()
+                .map(|v| format!("{}: {}", v.path, v.message))
+                .collect::<Vec<_>>()
+        };
+        assert!(
+            pet_with(serde_json::json!({"level": 2, "weight": 2.0, "vaccinated": true})).is_empty()
+        );
+        assert_eq!(
+            pet_with(serde_json::json!({"level": 3, "weight": 1, "vaccinated": false})),
+            [
+                "$.level: 3 is not one of 1, 2",
+                "$.vaccinated: false is not one of true",
+                "$.weight: 1 is not one of 0.5, 2",
+            ]
+        );
+
+        // Generated examples pick an allowed value.
+        let text = text
+            .replace("example: ten", "example: 10")
+            .replace("[{name: 7, status: lost, born: 'yesterday'}]", "[]");
+        assert!(lines(&text).is_empty(), "{:?}", lines(&text));
+    }
+
+    #[test]
+    fn schemas_accept_nullable_variants_and_extra_properties() {
+        let spec = parse_openapi(SPEC).unwrap();
+        let pet = &spec.schemas["Pet"];
+        assert!(
+            pet.validate(&serde_json::json!({"id": 1, "name": "Rex", "extra": true}))
+                .is_empty()
+        );
+        let missing = pet.validate(&serde_json::json!({"id": 1.5, "name": null}));
+        let paths: Vec<_> = missing.iter().map(|v| v.path.as_str()).collect();
+        assert_eq!(paths, ["$.id", "$.name"]);
+        assert!(!is_date("2024-1-15") && is_date("2024-01-15"));
+        assert!(matches_format("date-time", "2024-01-15T09:30:00Z"));
+        assert!(!matches_format("uuid", "not-a-uuid"));
+    }
+}
@@ -0,0 +1,688 @@
+//! Swagger 2.0 support.
+//!
+//! A 2.0 document is converted into an equivalent OpenAPI 3.0 document before
+//! deserializing, so it lands in the same [`OpenApiSpec`](super::OpenApiSpec)
+//! model as a native 3.0 spec:
+//!
+//! - `host` + `basePath` + `schemes` become `servers`
+//! - `definitions`, `parameters`, `responses` and `securityDefinitions` move
+//!   under `components`, and every `$ref` is rewritten to match
+//! - `in: body` and `in: formData` parameters become a `requestBody`, with one
+//!   media type per `consumes` entry
+//! - response `schema`/`examples` become `content`, one media type per
+//!   `produces` entry
+//! - parameter-level `type`/`format`/`items`/... move into a `schema`
+//! - `type: file` becomes a binary string and `x-nullable` becomes `nullable`
+
+use serde_json::{Map, Value, json};
+
+use super::openapi_parser::OpenApiError;
+
+/// Whether `document` declares Swagger 2.0.
+pub(super) fn is_swagger_2(document: &Value) -> bool {
+    document
+        .get("swagger")
+        .and_then(Value::as_str)
+        .is_some_and(|v| v.starts_with('2'))
+}
+
+/// Convert a Swagger 2.0 document into OpenAPI 3.0 form.
+pub(super) fn convert(mut document: Value) -> Result<Value, OpenApiError> {
+    let Some(root) = document.as_object() else {
+        return Err(OpenApiError::InvalidSpec(
+            "Swagger document is not an object".into(),
+        ));
+    };
+
+    // Names of shared parameters that are request bodies, so `$ref`s to them
+    // can be pointed at `components/requestBodies` instead.
+    let body_params: Vec<String> = root
+        .get("parameters")
+        .and_then(Value::as_object)
+        .map(|params| {
+            params
+                .iter()
+                .filter(|(_, p)| matches!(location(p), "body" | "formData"))
+                .map(|(name, _)| name.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    rewrite_refs(&mut document, &body_params);
+    let Value::Object(source) = document else {
+        unreachable!("checked to be an object above");
+    };
+
+    let produces = strings(source.get("produces"));
+    let consumes = strings(source.get("consumes"));
+
+    let mut out = Map::new();
+    out.insert("openapi".into(), json!("3.0.3"));
+    for key in ["info", "tags", "security", "externalDocs"] {
+        if let Some(value) = source.get(key) {
+            out.insert(key.into(), value.clone());
+        }
+    }
+    for (key, value) in &source {
+        if key.starts_with("x-") {
+            out.insert(key.clone(), value.clone());
+        }
+    }
+    out.insert("servers".into(), servers(&source));
+
+    let mut paths = Map::new();
+    if let Some(source_paths) = source.get("paths").and_then(Value::as_object) {
+        for (path, item) in source_paths {
+            if let Some(item) = item.as_object() {
+                paths.insert(
+                    path.clone(),
+                    Value::Object(convert_path_item(item, &produces, &consumes)),
+                );
+            }
+        }
+    }
+    out.insert("paths".into(), Value::Object(paths));
+
+    let mut components = Map::new();
+    if let Some(definitions) = source.get("definitions").and_then(Value::as_object) {
+        let schemas = definitions
+            .iter()
+            .map(|(name, schema)| (name.clone(), convert_schema(schema)))
+            .collect();
+        components.insert("schemas".into(), Value::Object(schemas));
+    }
+    if let Some(params) = source.get("parameters").and_then(Value::as_object) {
+        let mut parameters = Map::new();
+        let mut bodies = Map::new();
+        for (name, param) in params {
+            if body_params.contains(name) {
+                if let Some(body) = request_body(std::slice::from_ref(param), &consumes) {
+                    bodies.insert(name.clone(), body);
+                }
+            } else {
+                parameters.insert(name.clone(), convert_parameter(param));
+            }
+        }
+        components.insert("parameters".into(), Value::Object(parameters));
+        components.insert("requestBodies".into(), Value::Object(bodies));
+    }
+    if let Some(responses) = source.get("responses").and_then(Value::as_object) {
+        let responses = responses
+            .iter()
+            .map(|(name, r)| (name.clone(), convert_response(r, &produces)))
+            .collect();
+        components.insert("responses".into(), Value::Object(responses));
+    }
+    if let Some(schemes) = source.get("securityDefinitions").and_then(Value::as_object) {
+        let schemes = schemes
+            .iter()
+            .map(|(name, s)| (name.clone(), convert_security_scheme(s)))
+            .collect();
+        components.insert("securitySchemes".into(), Value::Object(schemes));
+    }
+    out.insert("components".into(), Value::Object(components));
+
+    Ok(Value::Object(out))
+}
+
+/// One server per scheme, `https` when none are listed.
+fn servers(source: &Map<String, Value>) -> Value {
+    let base_path = source
+        .get("basePath")
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .trim_end_matches('/');
+    let Some(host) = source.get("host").and_then(Value::as_str) else {
+        // Relative to wherever the spec is served from.
+        let url = if base_path.is_empty() { "/" } else { base_path };
+        return json!([{ "url": url }]);
+    };
+
+    let mut schemes = strings(source.get("schemes"));
+    if schemes.is_empty() {
+        schemes.push("https".into());
+    }
+    schemes
+        .iter()
+        .map(|scheme| json!({ "url": format!("{scheme}://{host}{base_path}") }))
+        .collect()
+}
+
+const METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch"];
+
+fn convert_path_item(
+    item: &Map<String, Value>,
+    produces: &[String],
+    consumes: &[String],
+) -> Map<String, Value> {
+    let shared: Vec<Value> = item
+        .get("parameters")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut out = Map::new();
+    for (key, value) in item {
+        if key.starts_with("x-") || key == "summary" || key == "description" {
+            out.insert(key.clone(), value.clone());
+        }
+    }
+    // Path-level body parameters have no 3.0 equivalent, so every parameter
+    // is pushed down into the operations.
+    for method in METHODS {
+        if let Some(op) = item.get(*method).and_then(Value::as_object) {
+            out.insert(
+                (*method).into(),
+                Value::Object(convert_operation(op, &shared, produces, consumes)),
+            );
+        }
+    }
+    out
+}
+
+fn convert_operation(
+    op: &Map<String, Value>,
+    shared: &[Value],
+    produces: &[String],
+    consumes: &[String],
+) -> Map<String, Value> {
+    let produces = op
+        .get("produces")
+        .map(|p| strings(Some(p)))
+        .unwrap_or_else(|| produces.to_vec());
+    let consumes = op
+        .get("consumes")
+        .map(|c| strings(Some(c)))
+        .unwrap_or_else(|| consumes.to_vec());
+
+    let mut out = Map::new();
+    for (key, value) in op {
+        if !matches!(
+            key.as_str(),
+            "parameters" | "responses" | "produces" | "consumes" | "schemes"
+        ) {
+            out.insert(key.clone(), value.clone());
+        }
+    }
+
+    // Operation parameters override path-level ones with the same name + location.
+    let own: Vec<Value> = op
+        .get("parameters")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let mut params: Vec<Value> = shared
+        .iter()
+        .filter(|s| {
+            !own.iter()
+                .any(|o| o.get("name") == s.get("name") && location(o) == location(s))
+        })
+        .cloned()
+        .collect();
+    params.extend(own);
+
+    let (body, rest): (Vec<Value>, Vec<Value>) = params
+        .into_iter()
+        .partition(|p| matches!(location(p), "body" | "formData") || is_body_ref(p));
+
+    if !rest.is_empty() {
+        out.insert(
+            "parameters".into(),
+            rest.iter().map(convert_parameter).collect(),
+        );
+    }
+    if let Some(body_ref) = body.iter().find(|p| is_body_ref(p)) {
+        out.insert("requestBody".into(), body_ref.clone());
+    } else if let Some(body) = request_body(&body, &consumes) {
+        out.insert("requestBody".into(), body);
+    }
+
+    let responses = op
+        .get("responses")
+        .and_then(Value::as_object)
+        .map(|responses| {
+            responses
+                .iter()
+                .map(|(status, r)| (status.clone(), convert_response(r, &produces)))
+                .collect()
+        })
+        .unwrap_or_default();
+    out.insert("responses".into(), Value::Object(responses));
+
+    out
+}
+
+/// The `in` of a 2.0 parameter.
+fn location(param: &Value) -> &str {
+    param.get("in").and_then(Value::as_str).unwrap_or("")
+}
+
+/// Whether `param` is a `$ref` already rewritten to a shared request body.
+fn is_body_ref(param: &Value) -> bool {
+    param
+        .get("$ref")
+        .and_then(Value::as_str)
+        .is_some_and(|r| r.starts_with("#/components/requestBodies/"))
+}
+
+/// Keywords that sit directly on a 2.0 non-body parameter but belong in its
+/// 3.0 `schema`.
+const SCHEMA_KEYWORDS: &[&str] = &[
+    "type",
+    "format",
+    "items",
+    "default",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "pattern",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "enum",
+    "multipleOf",
+];
+
+fn convert_parameter(param: &Value) -> Value {
+    let Some(param) = param.as_object() else {
+        return param.clone();
+    };
+    if param.contains_key("$ref") {
+        return Value::Object(param.clone());
+    }
+
+    let mut out = Map::new();
+    let mut schema = Map::new();
+    for (key, value) in param {
+        match key.as_str() {
+            "collectionFormat" | "allowEmptyValue" => {}
+            "x-example" => {
+                out.insert("example".into(), value.clone());
+            }
+            k if SCHEMA_KEYWORDS.contains(&k) => {
+                schema.insert(key.clone(), value.clone());
+            }
+            _ => {
+                out.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    out.insert("schema".into(), convert_schema(&Value::Object(schema)));
+    Value::Object(out)
+}
+
+/// Build a 3.0 `requestBody` from the `in: body` / `in: formData` parameters
+/// of an operation.
+fn request_body(params: &[Value], consumes: &[String]) -> Option<Value> {
+    if let Some(body) = params.iter().find(|p| location(p) == "body") {
+        let schema = convert_schema(body.get("schema").unwrap_or(&json!({})));
+        let media_types = if consumes.is_empty() {
+            vec!["application/json".to_string()]
+        } else {
+            consumes.to_vec()
+        };
+        let example = body.get("x-example");
+        let content: Map<String, Value> = media_types
+            .into_iter()
+            .map(|media_type| {
+                let mut entry = json!({ "schema": schema });
+                if let Some(example) = example {
+                    entry["example"] = example.clone();
+                }
+                (media_type, entry)
+            })
+            .collect();
+        let mut out = json!({
+            "content": content,
+            "required": body.get("required").and_then(Value::as_bool).unwrap_or(false),
+        });
+        if let Some(description) = body.get("description") {
+            out["description"] = description.clone();
+        }
+        return Some(out);
+    }
+
+    let fields: Vec<&Value> = params
+        .iter()
+        .filter(|p| location(p) == "formData")
+        .collect();
+    if fields.is_empty() {
+        return None;
+    }
+
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in &fields {
+        let Some(name) = field.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        let mut schema = convert_parameter(field)["schema"].clone();
+        if let Some(description) = field.get("description") {
+            schema["description"] = description.clone();
+        }
+        properties.insert(name.to_string(), schema);
+        if field.get("required").and_then(Value::as_bool) == Some(true) {
+            required.push(json!(name));
+        }
+    }
+
+    let has_file = fields.iter().any(|f| f.get("type") == Some(&json!("file")));
+    let media_type = consumes
+        .iter()
+        .find(|c| c.starts_with("multipart/") || c.as_str() == "application/x-www-form-urlencoded")
+        .cloned()
+        .unwrap_or_else(|| {
+            if has_file {
+                "multipart/form-data".into()
+            } else {
+                "application/x-www-form-urlencoded".into()
+            }
+        });
+
+    let mut schema = json!({ "type": "object", "properties": properties });
+    if !required.is_empty() {
+        schema["required"] = Value::Array(required.clone());
+    }
+    Some(json!({
+        "required": !required.is_empty(),
+        "content": { media_type: { "schema": schema } },
+    }))
+}
+
+fn convert_response(response: &Value, produces: &[String]) -> Value {
+    let Some(response) = response.as_object() else {
+        return response.clone();
+    };
+    if response.contains_key("$ref") {
+        return Value::Object(response.clone());
+    }
+
+    let mut out = Map::new();
+    out.insert(
+        "description".into(),
+        response.get("description").cloned().unwrap_or(json!("")),
+    );
+    let examples = response.get("examples").and_then(Value::as_object);
+
+    if let Some(schema) = response.get("schema") {
+        let schema = convert_schema(schema);
+        let mut media_types = if produces.is_empty() {
+            vec!["application/json".to_string()]
+        } else {
+            produces.to_vec()
+        };
+        // An example for a type the operation doesn't list still documents it.
+        if let Some(examples) = examples {
+            for media_type in examples.keys() {
+                if !media_types.contains(media_type) {
+                    media_types.push(media_type.clone());
+                }
+            }
+        }
+        let content: Map<String, Value> = media_types
+            .into_iter()
+            .map(|media_type| {
+                let mut entry = json!({ "schema": schema });
+                if let Some(example) = examples.and_then(|e| e.get(&media_type)) {
+                    entry["example"] = example.clone();
+                }
+                (media_type, entry)
+            })
+            .collect();
+        out.insert("content".into(), Value::Object(content));
+    }
+
+    if let Some(headers) = response.get("headers").and_then(Value::as_object) {
+        let headers = headers
+            .iter()
+            .map(|(name, header)| {
+                let mut converted = convert_parameter(header);
+                if let Some(h) = converted.as_object_mut() {
+                    h.remove("name");
+                    h.remove("in");
+                }
+                (name.clone(), converted)
+            })
+            .collect();
+        out.insert("headers".into(), Value::Object(headers));
+    }
+
+    for (key, value) in response {
+        if key.starts_with("x-") {
+            out.insert(key.clone(), value.clone());
+        }
+    }
+    Value::Object(out)
+}
+
+/// Convert the 2.0-only schema forms, recursively.
+fn convert_schema(schema: &Value) -> Value {
+    let mut schema = schema.clone();
+    convert_schema_in_place(&mut schema);
+    schema
+}
+
+fn convert_schema_in_place(schema: &mut Value) {
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+
+    if object.get("type") == Some(&json!("file")) {
+        object.insert("type".into(), json!("string"));
+        object.insert("format".into(), json!("binary"));
+    }
+    if let Some(nullable) = object.remove("x-nullable") {
+        object.insert("nullable".into(), nullable);
+    }
+    if let Some(Value::String(property)) = object.get("discriminator").cloned() {
+        object.insert("discriminator".into(), json!({ "propertyName": property }));
+    }
+
+    for key in ["items", "additionalProperties", "not"] {
+        if let Some(child) = object.get_mut(key) {
+            convert_schema_in_place(child);
+        }
+    }
+    for key in ["allOf", "anyOf", "oneOf"] {
+        if let Some(Value::Array(children)) = object.get_mut(key) {
+            children.iter_mut().for_each(convert_schema_in_place);
+        }
+    }
+    if let Some(Value::Object(properties)) = object.get_mut("properties") {
+        properties.values_mut().for_each(convert_schema_in_place);
+    }
+}
+
+fn convert_security_scheme(scheme: &Value) -> Value {
+    let description = scheme.get("description").cloned();
+    let mut out = match scheme.get("type").and_then(Value::as_str) {
+        Some("basic") => json!({ "type": "http", "scheme": "basic" }),
+        Some("apiKey") => json!({
+            "type": "apiKey",
+            "name": scheme.get("name").cloned().unwrap_or(json!("")),
+            "in": scheme.get("in").cloned().unwrap_or(json!("header")),
+        }),
+        Some("oauth2") => {
+            let scopes = scheme.get("scopes").cloned().unwrap_or(json!({}));
+            let mut flow = json!({ "scopes": scopes });
+            for key in ["authorizationUrl", "tokenUrl"] {
+                if let Some(url) = scheme.get(key) {
+                    flow[key] = url.clone();
+                }
+            }
+            let flow_name = match scheme.get("flow").and_then(Value::as_str) {
+                Some("accessCode") => "authorizationCode",
+                Some("application") => "clientCredentials",
+                Some("password") => "password",
+                _ => "implicit",
+            };
+            json!({ "type": "oauth2", "flows": { flow_name: flow } })
+        }
+        _ => scheme.clone(),
+    };
+    if let Some(description) = description {
+        out["description"] = description;
+    }
+    out
+}
+
+/// Point every local `$ref` at its 3.0 `components` location.
+fn rewrite_refs(value: &mut Value, body_params: &[String]) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(target)) = object.get_mut("$ref") {
+                if let Some(name) = target.strip_prefix("#/definitions/") {
+                    *target = format!("#/components/schemas/{name}");
+                } else if let Some(name) = target.strip_prefix("#/parameters/") {
+                    *target = if body_params.iter().any(|b| b == name) {
+                        format!("#/components/requestBodies/{name}")
+                    } else {
+                        format!("#/components/parameters/{name}")
+                    };
+                } else if let Some(name) = target.strip_prefix("#/responses/") {
+                    *target = format!("#/components/responses/{name}");
+                }
+            }
+            for (key, child) in object.iter_mut() {
+                // Literal example data can contain a `$ref` key of its own.
+                if key != "example" && key != "examples" && key != "x-example" {
+                    rewrite_refs(child, body_params);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                rewrite_refs(item, body_params);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A list of strings, e.g. `produces`.
+fn strings(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::openapi_parser::parse_openapi;
+    use crate::parser::openapi_types::*;
+
+    const FIXTURE: &str = include_str!("fixtures/swagger-2.0.yaml");
+
+    #[test]
+    fn servers_come_from_host_base_path_and_schemes() {
+        let spec = parse_openapi(FIXTURE).unwrap();
+        let urls: Vec<&str> = spec.servers.iter().map(|s| s.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://petstore.example.com/v1",
+                "http://petstore.example.com/v1"
+            ]
+        );
+
+        let relative = convert(json!({ "swagger": "2.0", "basePath": "/api" })).unwrap();
+        assert_eq!(relative["servers"], json!([{ "url": "/api" }]));
+    }
+
+    #[test]
+    fn definitions_become_component_schemas() {
+        let spec = parse_openapi(FIXTURE).unwrap();
+        let pet = &spec.schemas["Pet"];
+        assert_eq!(pet.required, ["id", "name"]);
+        assert!(pet.properties["tag"].nullable);
+        assert_eq!(pet.properties["owner"].ref_name.as_deref(), Some("Owner"));
+    }
+
+    #[test]
+    fn security_definitions_become_security_schemes() {
+        let spec = parse_openapi(FIXTURE).unwrap();
+        assert_eq!(
+            spec.security_schemes["api_key"].kind,
+            SecuritySchemeKind::ApiKey {
+                name: "X-API-Key".into(),
+                location: ParameterLocation::Header,
+            }
+        );
+    }
+
+    #[test]
+    fn body_parameters_become_request_bodies() {
+        let spec = parse_openapi(FIXTURE).unwrap();
+        let create = spec
+            .operations
+            .iter()
+            .find(|op| op.operation_id.as_deref() == Some("createPet"))
+            .unwrap();
+        let body = create.request_body.as_ref().unwrap();
+        assert!(body.required);
+        assert_eq!(body.content.len(), 2);
+        assert_eq!(body.content[0].media_type, "application/json");
+        assert_eq!(body.content[1].media_type, "application/xml");
+        assert_eq!(
+            body.content[0].schema.as_ref().unwrap().ref_name.as_deref(),
+            Some("Pet")
+        );
+        // The shared `limit`-style header stays a parameter.
+        assert_eq!(create.parameters.len(), 1);
+        assert_eq!(create.parameters[0].location, ParameterLocation::Header);
+    }
+
+    #[test]
+    fn form_data_and_files_become_multipart_bodies() {
+        let spec = parse_openapi(FIXTURE).unwrap();
+        let upload = spec
+            .operations
+            .iter()
+            .find(|op| op.operation_id.as_deref() == Some("uploadPhoto"))
+            .unwrap();
+        let body = upload.request_body.as_ref().unwrap();
+        assert_eq!(body.content[0].media_type, "multipart/form-data");
+        let schema = body.content[0].schema.as_ref().unwrap();
+        assert_eq!(schema.properties["file"].schema_type, SchemaType::String);
+        assert_eq!(schema.properties["file"].format.as_deref(), Some("binary"));
+        assert_eq!(schema.required, ["file"]);
+        // `petId` is a path parameter declared on the path item.
+        assert_eq!(upload.parameters[0].name, "petId");
+        assert_eq!(upload.parameters[0].location, ParameterLocation::Path);
+    }
+
+    #[test]
+    fn responses_get_content_per_produced_type() {
+        let spec = parse_openapi(FIXTURE).unwrap();
+        let list = spec
+            .operations
+            .iter()
+            .find(|op| op.operation_id.as_deref() == Some("listPets"))
+            .unwrap();
+        let limit = &list.parameters[0];
+        assert_eq!(limit.name, "limit");
+        assert_eq!(
+            limit.schema.as_ref().unwrap().schema_type,
+            SchemaType::Integer
+        );
+
+        let ok = &list.responses[0];
+        assert_eq!(ok.status_code, "200");
+        assert_eq!(ok.content.len(), 1);
+        assert_eq!(ok.content[0].media_type, "application/json");
+        assert!(ok.content[0].example.as_deref().unwrap().contains("Rex"));
+
+        // `#/responses/NotFound` resolves through components.
+        let missing = list.responses.iter().find(|r| r.status_code == "404");
+        assert_eq!(missing.unwrap().description, "Not found");
+    }
+}
@@ -0,0 +1,131 @@
+//! Inline glossary term parser.
+//!
+//! `<Term id="idempotency">idempotent</Term>` (or self-closing
+//! `<Term id="idempotency" />`) may appear anywhere in markdown text. Terms stay
+//! inside their `DocNode::Markdown` node; renderers and hosts locate them with
+//! [`find_terms`].
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static TERM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<Term\s+id="([^"]+)"\s*(?:/>|>([^<]*)</Term>)"#).unwrap());
+
+/// An inline `<Term>` found in markdown text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermRef {
+    /// Byte offset of the opening `<`.
+    pub start: usize,
+    /// Byte offset just past the closing `>`.
+    pub end: usize,
+    /// Glossary entry id.
+    pub id: String,
+    /// Inner text; `None` for the self-closing form.
+    pub label: Option<String>,
+}
+
+/// Find `<Term>` tags in markdown text, skipping inline code spans.
+pub fn find_terms(markdown: &str) -> Vec<TermRef> {
+    let code = code_spans(markdown);
+    TERM_RE
+        .captures_iter(markdown)
+        .filter_map(|caps| {
+            let whole = caps.get(0)?;
+            if code
+                .iter()
+                .any(|&(s, e)| whole.start() >= s && whole.start() < e)
+            {
+                return None;
+            }
+            Some(TermRef {
+                start: whole.start(),
+                end: whole.end(),
+                id: caps[1].to_string(),
+                label: caps.get(2).map(|m| m.as_str().to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Byte ranges of inline code spans (matching backtick runs).
+pub fn code_spans(markdown: &str) -> Vec<(usize, usize)> {
+    let bytes = markdown.as_bytes();
+    let mut spans = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'`' {
+            i += 1;
+            continue;
+        }
+        let run = bytes[i..].iter().take_while(|&&b| b == b'`').count();
+        let fence = &markdown[i..i + run];
+        match markdown[i + run..].find(fence) {
+            Some(offset) => {
+                let end = i + run + offset + run;
+                spans.push((i, end));
+                i = end;
+            }
+            None => i += run,
+        }
+    }
+
+    spans
+}
+
+/// Replace every `<Term>` with its label (the id for the self-closing form).
+pub(super) fn strip_terms(markdown: &str) -> String {
+    let terms = find_terms(markdown);
+    if terms.is_empty() {
+        return markdown.to_string();
+    }
+
+    let mut out = String::with_capacity(markdown.len());
+    let mut last = 0;
+    for term in terms {
+        out.push_str(&markdown[last..term.start]);
+        out.push_str(term.label.as_deref().unwrap_or(&term.id));
+        last = term.end;
+    }
+    out.push_str(&markdown[last..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::content::{get_raw_markdown, parse_mdx};
+
+    #[test]
+    fn finds_paired_and_self_closing_terms() {
+        let md =
+            "Requests are <Term id=\"idempotency\">idempotent</Term>; see <Term id=\"etag\" />.";
+        let terms = find_terms(md);
+        assert_eq!(terms.len(), 2);
+        assert_eq!(terms[0].id, "idempotency");
+        assert_eq!(terms[0].label.as_deref(), Some("idempotent"));
+        assert_eq!(
+            &md[terms[0].start..terms[0].end],
+            "<Term id=\"idempotency\">idempotent</Term>"
+        );
+        assert_eq!(terms[1].id, "etag");
+        assert!(terms[1].label.is_none());
+    }
+
+    #[test]
+    fn skips_terms_inside_code_spans() {
+        let md = "Write `<Term id=\"x\">x</Term>` to get <Term id=\"x\">x</Term>.";
+        let terms = find_terms(md);
+        assert_eq!(terms.len(), 1);
+        assert!(terms[0].start > md.find("to get").unwrap());
+    }
+
+    #[test]
+    fn terms_stay_in_markdown_and_raw_markdown_keeps_labels() {
+        let nodes = parse_mdx("An <Term id=\"idempotency\">idempotent</Term> call.");
+        assert_eq!(nodes.len(), 1);
+        assert!(matches!(&nodes[0], crate::parser::DocNode::Markdown(_)));
+        assert_eq!(get_raw_markdown(&nodes), "An idempotent call.\n\n");
+    }
+}
@@ -0,0 +1,167 @@
+//! Terminal session parser.
+//!
+//! Handles `<Terminal title="..." animate>...</Terminal>` blocks and fenced
+//! ```` ```console ```` (also `shell-session`, `terminal`) blocks. Lines starting
+//! with a prompt (`$ `, `% `, `# `, `> `, `❯ `, `PS> `) are commands, everything
+//! else is output. A command ending in `\` continues on the next line.
+
+use super::utils::{extract_attr, find_closing_tag};
+use crate::parser::types::*;
+
+/// Prompts recognised at the start of a line, longest first.
+const PROMPTS: &[&str] = &["PS> ", "$ ", "% ", "# ", "> ", "❯ "];
+
+/// Fence languages rendered as a terminal session instead of a code block.
+pub(super) fn is_terminal_language(language: &str) -> bool {
+    matches!(
+        language.to_ascii_lowercase().as_str(),
+        "console" | "shell-session" | "terminal"
+    )
+}
+
+/// Try to parse a Terminal component.
+pub(super) fn try_parse_terminal(content: &str) -> Option<(DocNode, &str)> {
+    let after_name = content.strip_prefix("<Terminal")?;
+    if !after_name.starts_with([' ', '\t', '\n', '>']) {
+        return None;
+    }
+
+    let tag_end = after_name.find('>')?;
+    let tag_content = &after_name[..tag_end];
+    let after_open = &after_name[tag_end + 1..];
+    let close_idx = find_closing_tag(after_open, "Terminal")?;
+    let inner = &after_open[..close_idx];
+    let rest = &after_open[close_idx + "</Terminal>".len()..];
+
+    let animate = tag_content.split_whitespace().any(|word| word == "animate");
+
+    Some((
+        DocNode::Terminal(parse_session(
+            &dedent(inner),
+            extract_attr(tag_content, "title"),
+            animate,
+        )),
+        rest,
+    ))
+}
+
+/// Split session text into prompt/command and output lines.
+pub(super) fn parse_session(text: &str, title: Option<String>, animate: bool) -> TerminalNode {
+    let mut lines: Vec<TerminalLine> = Vec::new();
+
+    for line in text.lines() {
+        // Continuation of a command ending in `\`.
+        if let Some(TerminalLine::Command { command, .. }) = lines.last_mut()
+            && command.ends_with('\\')
+        {
+            command.push('\n');
+            command.push_str(line);
+            continue;
+        }
+
+        let prompted = PROMPTS
+            .iter()
+            .find_map(|prompt| line.strip_prefix(prompt).map(|cmd| (prompt, cmd)));
+        lines.push(match prompted {
+            Some((prompt, command)) => TerminalLine::Command {
+                prompt: prompt.trim_end().to_string(),
+                command: command.to_string(),
+            },
+            None => TerminalLine::Output(line.to_string()),
+        });
+    }
+
+    TerminalNode {
+        title,
+        lines,
+        animate,
+    }
+}
+
+/// Strip the indentation shared by all non-blank lines, plus leading and
+/// trailing blank lines.
+fn dedent(content: &str) -> String {
+    let indent = content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    content
+        .trim_matches('\n')
+        .lines()
+        .map(|l| l.get(indent..).unwrap_or_else(|| l.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::content::{get_raw_markdown, parse_mdx};
+
+    fn terminal(content: &str) -> TerminalNode {
+        match parse_mdx(content).into_iter().next() {
+            Some(DocNode::Terminal(t)) => t,
+            other => panic!("expected Terminal, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn splits_prompts_commands_and_output() {
+        let t = terminal(
+            "<Terminal title=\"Install\" animate>\n  $ cargo add dioxus-mdx\n      Adding dioxus-mdx v0.6\n  $ cargo build\n</Terminal>",
+        );
+        assert_eq!(t.title.as_deref(), Some("Install"));
+        assert!(t.animate);
+        assert_eq!(
+            t.lines,
+            vec![
+                TerminalLine::Command {
+                    prompt: "$".to_string(),
+                    command: "cargo add dioxus-mdx".to_string(),
+                },
+                TerminalLine::Output("    Adding dioxus-mdx v0.6".to_string()),
+                TerminalLine::Command {
+                    prompt: "$".to_string(),
+                    command: "cargo build".to_string(),
+                },
+            ]
+        );
+        assert_eq!(t.commands_text(), "cargo add dioxus-mdx\ncargo build");
+    }
+
+    #[test]
+    fn console_fence_becomes_terminal_with_continuations() {
+        let t = terminal("```console\n$ docker run \\\n    -p 8080:80 nginx\nabc123\n```");
+        assert!(!t.animate);
+        assert_eq!(
+            t.commands_text(),
+            "docker run \\\n    -p 8080:80 nginx",
+            "continuation lines belong to the command"
+        );
+        assert_eq!(t.lines.len(), 2);
+        // A bash fence stays a code block.
+        assert!(matches!(
+            parse_mdx("```bash\n$ ls\n```")[0],
+            DocNode::CodeBlock(_)
+        ));
+    }
+
+    #[test]
+    fn raw_markdown_keeps_the_session_as_console_fence() {
+        let nodes = parse_mdx("<Terminal>\n$ echo hi\nhi\n</Terminal>");
+        assert_eq!(
+            get_raw_markdown(&nodes),
+            "```console\n$ echo hi\nhi\n```\n\n"
+        );
+    }
+
+    #[test]
+    fn terminal_tag_requires_boundary() {
+        assert!(try_parse_terminal("<TerminalOutput>x</TerminalOutput>").is_none());
+    }
+}
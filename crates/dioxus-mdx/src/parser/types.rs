@@ -2,7 +2,7 @@
 
 use serde::Deserialize;
 
-use super::openapi_types::OpenApiSpec;
+use super::openapi_types::{ApiOperation, OpenApiSpec, SchemaDefinition};
 
 /// Parsed documentation page with frontmatter and content.
 #[derive(Debug, Clone, PartialEq)]
@@ -31,6 +31,11 @@ pub struct DocFrontmatter {
     /// Icon name (Lucide icon identifier).
     #[serde(default)]
     pub icon: Option<String>,
+    /// Extra schema.org types to derive from the page's components
+    /// (`structuredData: [faq, howto]`).
+    #[serde(rename = "structuredData")]
+    #[serde(default)]
+    pub structured_data: Vec<String>,
 }
 
 /// A node in the parsed documentation tree.
@@ -68,6 +73,58 @@ pub enum DocNode {
     Update(UpdateNode),
     /// OpenAPI specification viewer.
     OpenApi(OpenApiNode),
+    /// Directory layout tree.
+    FileTree(FileTreeNode),
+    /// Inline reference to one operation of a registered OpenAPI spec.
+    ApiEndpoint(ApiEndpointNode),
+    /// Inline reference to one component schema of a registered OpenAPI spec.
+    Schema(SchemaNode),
+    /// Self-hosted video file.
+    Video(VideoNode),
+    /// YouTube video behind a click-to-load facade.
+    YouTube(YouTubeNode),
+    /// Third-party iframe behind a click-to-load facade.
+    Embed(EmbedNode),
+    /// Terminal session with prompts, commands and output.
+    Terminal(TerminalNode),
+    /// Definition list (`Term` followed by `: definition` lines).
+    DefinitionList(DefinitionListNode),
+    /// The page's footnotes, referenced by inline `<FootnoteRef>` tags.
+    Footnotes(FootnotesNode),
+    /// The page's abbreviation definitions (`*[API]: ...`). Renders nothing;
+    /// occurrences carry their expansion as inline `<Abbr>` tags.
+    Abbreviations(Vec<Abbreviation>),
+    /// Sortable, filterable table loaded from a CSV or JSON file.
+    DataTable(DataTableNode),
+}
+
+impl DocNode {
+    /// The node lists nested inside container components (tab panes, steps,
+    /// accordion items, param fields, updates).
+    pub fn children(&self) -> Vec<&Vec<DocNode>> {
+        match self {
+            Self::Tabs(t) => t.tabs.iter().map(|tab| &tab.content).collect(),
+            Self::Steps(s) => s.steps.iter().map(|step| &step.content).collect(),
+            Self::AccordionGroup(ag) => ag.items.iter().map(|item| &item.content).collect(),
+            Self::ParamField(f) => vec![&f.content],
+            Self::Update(u) => vec![&u.content],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Mutable counterpart of [`Self::children`].
+    ///
+    /// Lets a host walk the whole tree, e.g. to resolve API references.
+    pub fn children_mut(&mut self) -> Vec<&mut Vec<DocNode>> {
+        match self {
+            Self::Tabs(t) => t.tabs.iter_mut().map(|tab| &mut tab.content).collect(),
+            Self::Steps(s) => s.steps.iter_mut().map(|step| &mut step.content).collect(),
+            Self::AccordionGroup(ag) => ag.items.iter_mut().map(|item| &mut item.content).collect(),
+            Self::ParamField(f) => vec![&mut f.content],
+            Self::Update(u) => vec![&mut u.content],
+            _ => Vec::new(),
+        }
+    }
 }
 
 /// Callout variant type.
@@ -316,3 +373,323 @@ pub struct OpenApiNode {
     /// Whether to show schema definitions section.
     pub show_schemas: bool,
 }
+
+/// Inline API operation reference (`<ApiEndpoint operationId="listPets" />`).
+///
+/// The parser only records the reference; the host that owns the specs (e.g.
+/// `DocsRegistry`) fills in `operation` and `href`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiEndpointNode {
+    /// `operationId` of the referenced operation.
+    pub operation_id: String,
+    /// Only look in the spec registered under this prefix (`spec="..."`).
+    pub spec: Option<String>,
+    /// The resolved operation.
+    pub operation: Option<ApiOperation>,
+    /// Doc path of the operation's endpoint page (e.g. "api-reference/list-pets").
+    pub href: Option<String>,
+}
+
+/// Inline component schema reference (`<Schema name="Pet" />`).
+///
+/// Resolved by the host like [`ApiEndpointNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaNode {
+    /// Name of the schema under `components.schemas`.
+    pub name: String,
+    /// Only look in the spec registered under this prefix (`spec="..."`).
+    pub spec: Option<String>,
+    /// The resolved schema.
+    pub schema: Option<SchemaDefinition>,
+}
+
+/// Self-hosted video (`<Video src="..." />`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoNode {
+    /// Video file URL.
+    pub src: String,
+    /// Accessible title, also used as the link text in raw markdown.
+    pub title: Option<String>,
+    /// Poster image shown before playback.
+    pub poster: Option<String>,
+    /// WebVTT captions track URL.
+    pub captions: Option<String>,
+    /// Language of the captions track (`captionsLang`, default "en").
+    pub captions_lang: String,
+}
+
+/// YouTube video (`<YouTube id="..." />`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct YouTubeNode {
+    /// YouTube video ID.
+    pub id: String,
+    /// Accessible title, also used as the link text in raw markdown.
+    pub title: Option<String>,
+    /// Start offset in seconds.
+    pub start: Option<u32>,
+}
+
+impl YouTubeNode {
+    /// Link to the video on youtube.com.
+    pub fn watch_url(&self) -> String {
+        match self.start {
+            Some(start) => format!("https://www.youtube.com/watch?v={}&t={start}s", self.id),
+            None => format!("https://www.youtube.com/watch?v={}", self.id),
+        }
+    }
+
+    /// Privacy-enhanced player URL, autoplaying since it only loads on click.
+    pub fn embed_url(&self) -> String {
+        let mut url = format!(
+            "https://www.youtube-nocookie.com/embed/{}?autoplay=1",
+            self.id
+        );
+        if let Some(start) = self.start {
+            url.push_str(&format!("&start={start}"));
+        }
+        url
+    }
+}
+
+/// Generic iframe embed (`<Embed src="..." />`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbedNode {
+    /// Iframe URL.
+    pub src: String,
+    /// Accessible title, also used as the link text in raw markdown.
+    pub title: Option<String>,
+    /// Iframe height in pixels.
+    pub height: Option<u32>,
+}
+
+impl EmbedNode {
+    /// Host the iframe would load from, shown on the facade.
+    pub fn host(&self) -> &str {
+        let rest = self
+            .src
+            .split_once("://")
+            .map_or(self.src.as_str(), |(_, rest)| rest);
+        rest.split(['/', '?', '#']).next().unwrap_or(rest)
+    }
+}
+
+/// Terminal session (`<Terminal>` or a ```` ```console ```` fence).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerminalNode {
+    /// Optional window title.
+    pub title: Option<String>,
+    /// Session lines in order.
+    pub lines: Vec<TerminalLine>,
+    /// Type the commands out when the block is first shown (`animate`).
+    pub animate: bool,
+}
+
+/// One line of a [`TerminalNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TerminalLine {
+    /// A command after a prompt. Continued commands keep their line breaks.
+    Command {
+        /// The prompt, e.g. `$`.
+        prompt: String,
+        /// The command without its prompt.
+        command: String,
+    },
+    /// Program output.
+    Output(String),
+}
+
+impl TerminalNode {
+    /// The commands only, one per line: what the copy button copies.
+    pub fn commands_text(&self) -> String {
+        self.lines
+            .iter()
+            .filter_map(|line| match line {
+                TerminalLine::Command { command, .. } => Some(command.as_str()),
+                TerminalLine::Output(_) => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The session as written, prompts included.
+    pub fn to_text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| match line {
+                TerminalLine::Command { prompt, command } => format!("{prompt} {command}"),
+                TerminalLine::Output(output) => output.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Directory layout tree (`<FileTree>`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileTreeNode {
+    /// Top-level entries, in source order.
+    pub entries: Vec<FileTreeEntry>,
+}
+
+/// A file or folder in a [`FileTreeNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileTreeEntry {
+    /// File or folder name, without a trailing `/`.
+    pub name: String,
+    /// Whether this entry is a folder (trailing `/` or has children).
+    pub is_dir: bool,
+    /// Whether the entry was wrapped in `**...**` to draw attention to it.
+    pub highlighted: bool,
+    /// Nested entries (folders only).
+    pub children: Vec<FileTreeEntry>,
+}
+
+impl FileTreeEntry {
+    /// Icon name (see `MdxIcon`) for the entry.
+    pub fn icon_name(&self, open: bool) -> &'static str {
+        if self.is_dir {
+            return if open { "folder-open" } else { "folder" };
+        }
+        let ext = self.name.rsplit_once('.').map(|(_, ext)| ext);
+        match ext.map(str::to_ascii_lowercase).as_deref() {
+            Some(
+                "rs" | "js" | "mjs" | "cjs" | "jsx" | "ts" | "tsx" | "py" | "go" | "c" | "h"
+                | "cpp" | "cs" | "java" | "kt" | "swift" | "rb" | "php" | "sh" | "html" | "css",
+            ) => "file-code",
+            Some("md" | "mdx" | "txt" | "rst") => "file-text",
+            Some("json" | "toml" | "yaml" | "yml" | "lock" | "ini" | "env") => "file-cog",
+            _ => "file",
+        }
+    }
+}
+
+/// One entry of a glossary (`_glossary.json`).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GlossaryEntry {
+    /// Stable id referenced by `<Term id="...">`.
+    pub id: String,
+    /// Display name, also the heading on the glossary page.
+    pub term: String,
+    /// Definition shown in tooltips and on the glossary page (markdown).
+    pub definition: String,
+    /// Other spellings matched by automatic term linking.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// Definition list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefinitionListNode {
+    /// Terms in source order.
+    pub items: Vec<DefinitionItem>,
+}
+
+/// One term of a definition list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefinitionItem {
+    /// The term (inline markdown).
+    pub term: String,
+    /// Its definitions (markdown), one per `: ` line.
+    pub definitions: Vec<String>,
+}
+
+/// Footnotes collected from a page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FootnotesNode {
+    /// Page-unique prefix for footnote ids, so several rendered documents on
+    /// one page don't collide.
+    pub namespace: String,
+    /// Footnotes in numbering order.
+    pub items: Vec<FootnoteItem>,
+}
+
+/// A single footnote.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FootnoteItem {
+    /// Label from the source (`[^label]`).
+    pub label: String,
+    /// Display number, in order of first reference.
+    pub number: usize,
+    /// Footnote text (markdown).
+    pub content: String,
+}
+
+impl FootnotesNode {
+    /// Id suffix for footnote `label`: the footnote itself is `fn-<anchor>`,
+    /// its first reference `fnref-<anchor>`.
+    pub fn anchor(namespace: &str, label: &str) -> String {
+        let label: String = label
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        format!("{namespace}-{label}")
+    }
+}
+
+/// An abbreviation and its expansion (`*[API]: Application Programming Interface`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Abbreviation {
+    /// The abbreviation as written in the text.
+    pub abbr: String,
+    /// Its expansion, shown as a tooltip.
+    pub title: String,
+}
+
+/// Table loaded from a CSV or JSON file (`<DataTable src="data/errors.csv" />`).
+///
+/// Resolved by the host like [`ApiEndpointNode`]: the parser only records the
+/// source path, and the host fills in `data` with [`DataTableNode::load`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTableNode {
+    /// Path of the data file, relative to the docs directory.
+    pub src: String,
+    /// Column type overrides, e.g. `"code:code,docs:link"`.
+    pub types: String,
+    /// Show the filter box (`filter="false"` hides it).
+    pub filterable: bool,
+    /// Sort by clicking column headers (`sortable="false"` disables it).
+    pub sortable: bool,
+    /// The loaded table.
+    pub data: Option<TableData>,
+}
+
+/// Columns and rows of a [`DataTableNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableData {
+    /// Columns in source order.
+    pub columns: Vec<DataColumn>,
+    /// Rows of cell text, one cell per column.
+    pub rows: Vec<Vec<String>>,
+}
+
+/// A data table column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataColumn {
+    /// Header text.
+    pub name: String,
+    /// How cells are formatted and sorted.
+    pub column_type: ColumnType,
+}
+
+/// Formatting of a data table column. Inferred from the values unless set
+/// with `types="column:type"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Text,
+    /// Right-aligned and sorted numerically.
+    Number,
+    /// `true`/`false` or `yes`/`no`, shown as a check mark.
+    Boolean,
+    /// ISO 8601 date.
+    Date,
+    /// URL, shown as a link.
+    Link,
+    /// Monospace identifier, e.g. an error code. Never inferred.
+    Code,
+}
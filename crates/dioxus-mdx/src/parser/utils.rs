@@ -204,6 +204,19 @@ pub(super) fn extract_attr(tag_content: &str, attr_name: &str) -> Option<String>
         .map(|caps| caps[2].to_string())
 }
 
+/// Split a self-closing `<Name ... />` tag into its attribute text and the rest.
+///
+/// The name must be followed by whitespace or `/`, so `<SchemaViewer ... />`
+/// is not taken for `<Schema ... />`.
+pub(super) fn self_closing_tag<'a>(content: &'a str, name: &str) -> Option<(&'a str, &'a str)> {
+    let after_name = content.strip_prefix('<')?.strip_prefix(name)?;
+    if !after_name.starts_with(|c: char| c.is_whitespace() || c == '/') {
+        return None;
+    }
+    let end = after_name.find("/>")?;
+    Some((&after_name[..end], &after_name[end + 2..]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
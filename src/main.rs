@@ -28,6 +28,13 @@ static DOCS: LazyLock<DocsRegistry> = LazyLock::new(|| {
             "api-reference",
             include_str!("../docs/api-reference/petstore.yaml"),
         )
+        .with_asyncapi(
+            "events",
+            include_str!("../docs/api-reference/petstore-events.yaml"),
+        )
+        .with_api_playground(true)
+        .with_glossary(include_str!("../docs/_glossary.json"))
+        .with_language_alias("rsx", Language::Rust)
         .with_default_path("getting-started/introduction")
         .with_theme_toggle("light", "dark", "dark")
         .build()